@@ -3,11 +3,18 @@ use std::path::Path;
 fn main() {
     let proto_out = "src/proto";
     let proto_root = Path::new(".").canonicalize().unwrap();
-    let proto_file = Path::new("./accounting.proto").canonicalize().unwrap();
+    let proto_files = [
+        "./accounting.proto",
+        "./google/rpc/status.proto",
+        "./google/rpc/error_details.proto",
+    ];
+    let proto_files: Vec<_> =
+        proto_files.iter().map(|file| Path::new(file).canonicalize().unwrap()).collect();
 
     protoc_grpcio::compile_grpc_protos(
-        &[proto_file],
+        &proto_files,
         &[proto_root],
-        &proto_out
+        &proto_out,
+        None
     ).expect("Failed to compile gRPC definitions!");
 }
@@ -0,0 +1,364 @@
+use std::process;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use clap::{App, Arg, ArgMatches};
+use futures::future::{self, BoxFuture};
+use futures::{executor, FutureExt, TryFutureExt};
+use grpcio::{CallOption, ChannelBuilder, EnvBuilder, RpcStatusCode};
+use rand::Rng;
+
+use accountant::proto::accounting::{GetBalanceRequest, TransferComponent, TransferRequest};
+use accountant::proto::accounting_grpc::AccountingServiceClient;
+use accountant::sdk::{new_req_id, AccountingClient, ClientConfig, ClientError};
+
+// Shape of the generated traffic
+#[derive(Clone, Debug)]
+struct Workload {
+    accounts: u32,
+    initial_balance: i64,
+    // the first `hot_accounts` accounts receive `hot_fraction` of all picks
+    hot_accounts: u32,
+    hot_fraction: f64,
+    // legs per transfer, one debit and fan_out - 1 credits
+    fan_out: usize,
+    read_ratio: f64,
+    concurrency: usize,
+    duration: Duration,
+    max_retries: u32,
+    timeout: Duration,
+}
+
+impl Workload {
+    fn from_args(args: &ArgMatches) -> Result<Workload, String> {
+        let workload = Workload {
+            accounts: parse(args, "accounts")?,
+            initial_balance: parse(args, "initial-balance")?,
+            hot_accounts: parse(args, "hot-accounts")?,
+            hot_fraction: parse(args, "hot-fraction")?,
+            fan_out: parse(args, "fan-out")?,
+            read_ratio: parse(args, "read-ratio")?,
+            concurrency: parse(args, "concurrency")?,
+            duration: Duration::from_secs(parse(args, "duration")?),
+            max_retries: parse(args, "max-retries")?,
+            timeout: Duration::from_millis(parse(args, "timeout-ms")?),
+        };
+        if workload.fan_out < 2 || workload.fan_out > workload.accounts as usize {
+            return Err("fan-out Must Be Between 2 And The Number Of Accounts".to_string());
+        }
+        if workload.hot_accounts > workload.accounts {
+            return Err("hot-accounts Must Not Exceed accounts".to_string());
+        }
+        if !(0.0..=1.0).contains(&workload.hot_fraction)
+            || !(0.0..=1.0).contains(&workload.read_ratio)
+        {
+            return Err("hot-fraction And read-ratio Must Be Between 0 And 1".to_string());
+        }
+        if workload.concurrency == 0 {
+            return Err("concurrency Must Be At Least 1".to_string());
+        }
+        Ok(workload)
+    }
+
+    // accounts are numbered from 1, account 0 is reserved
+    fn pick_account<R: Rng>(&self, rng: &mut R) -> u32 {
+        if self.hot_accounts > 0 && rng.gen_bool(self.hot_fraction) {
+            rng.gen_range(1, self.hot_accounts + 1)
+        } else {
+            rng.gen_range(1, self.accounts + 1)
+        }
+    }
+
+    fn next_op<R: Rng>(&self, rng: &mut R) -> Op {
+        if rng.gen_bool(self.read_ratio) {
+            let mut req = GetBalanceRequest::new();
+            req.set_req_id(new_req_id());
+            req.set_account_id(self.pick_account(rng));
+            return Op::Read(req);
+        }
+
+        let mut accounts = Vec::with_capacity(self.fan_out);
+        while accounts.len() < self.fan_out {
+            let account = self.pick_account(rng);
+            if !accounts.contains(&account) {
+                accounts.push(account);
+            }
+        }
+        let amount = rng.gen_range(1, 101);
+        let mut req = TransferRequest::new();
+        req.set_req_id(new_req_id());
+        for (i, &account) in accounts.iter().enumerate() {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(account);
+            if i == 0 {
+                comp.set_money_delta(-amount * (self.fan_out as i64 - 1));
+            } else {
+                comp.set_money_delta(amount);
+            }
+            req.mut_components().push(comp);
+        }
+        Op::Transfer(req)
+    }
+}
+
+#[derive(Clone)]
+enum Op {
+    Read(GetBalanceRequest),
+    Transfer(TransferRequest),
+}
+
+type Attempt = BoxFuture<'static, grpcio::Result<()>>;
+
+impl Op {
+    fn send(&self, client: &AccountingServiceClient, opt: CallOption) -> Attempt {
+        let res = match self {
+            Op::Read(req) => client.get_balance_async_opt(req, opt).map(|f| {
+                f.map_ok(|_| ()).boxed()
+            }),
+            Op::Transfer(req) => client.transfer_async_opt(req, opt).map(|f| {
+                f.map_ok(|_| ()).boxed()
+            }),
+        };
+        res.unwrap_or_else(|e| future::err(e).boxed())
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    // latencies in microseconds, including retries
+    reads: Vec<u64>,
+    transfers: Vec<u64>,
+    retries: u64,
+    aborted: u64,
+    // transfers refused by the server, e.g. for insufficient funds
+    rejected: u64,
+    // any other error, including retryable ones that outlasted max_retries
+    failed: u64,
+}
+
+impl Stats {
+    fn merge(&mut self, other: Stats) {
+        self.reads.extend(other.reads);
+        self.transfers.extend(other.transfers);
+        self.retries += other.retries;
+        self.aborted += other.aborted;
+        self.rejected += other.rejected;
+        self.failed += other.failed;
+    }
+}
+
+// Outcome of one operation and every attempt it took
+struct Outcome {
+    result: Result<(), ClientError>,
+    retries: u64,
+    aborted: u64,
+}
+
+// Sends `op`, resending it with the same req_id on retryable errors
+async fn execute(client: &AccountingServiceClient, op: Op, workload: &Workload) -> Outcome {
+    let mut outcome = Outcome { result: Ok(()), retries: 0, aborted: 0 };
+    let max_retries = u64::from(workload.max_retries);
+    loop {
+        let opt = CallOption::default().timeout(workload.timeout);
+        let err = match op.send(client, opt).await {
+            Ok(()) => return outcome,
+            Err(err) => ClientError::from(err),
+        };
+        if err.code() == RpcStatusCode::ABORTED {
+            outcome.aborted += 1;
+        }
+        if err.is_retryable() && outcome.retries < max_retries {
+            outcome.retries += 1;
+            continue;
+        }
+        outcome.result = Err(err);
+        return outcome;
+    }
+}
+
+// Keeps one operation in flight until the deadline
+async fn worker(
+    client: AccountingServiceClient,
+    workload: Arc<Workload>,
+    deadline: Instant,
+) -> Stats {
+    let mut stats = Stats::default();
+    while Instant::now() < deadline {
+        let op = workload.next_op(&mut rand::thread_rng());
+        let is_read = match op {
+            Op::Read(_) => true,
+            Op::Transfer(_) => false,
+        };
+        let start = Instant::now();
+        let outcome = execute(&client, op, &workload).await;
+        let latency = micros(start.elapsed());
+        stats.retries += outcome.retries;
+        stats.aborted += outcome.aborted;
+        match outcome.result {
+            Ok(()) if is_read => stats.reads.push(latency),
+            Ok(()) => stats.transfers.push(latency),
+            Err(ClientError::FailedPrecondition(_)) => stats.rejected += 1,
+            Err(_) => stats.failed += 1,
+        }
+    }
+    stats
+}
+
+fn micros(duration: Duration) -> u64 {
+    duration.as_secs() * 1_000_000 + u64::from(duration.subsec_micros())
+}
+
+fn percentile(sorted: &[u64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (sorted.len() as f64 * p).ceil() as usize;
+    sorted[rank.max(1).min(sorted.len()) - 1] as f64 / 1_000.0
+}
+
+fn report_latencies(name: &str, latencies: &mut [u64]) {
+    latencies.sort();
+    println!(
+        "{:<10} {:>9} ops  p50 {:>8.2}ms  p99 {:>8.2}ms  p999 {:>8.2}ms",
+        name,
+        latencies.len(),
+        percentile(latencies, 0.5),
+        percentile(latencies, 0.99),
+        percentile(latencies, 0.999)
+    );
+}
+
+fn total_balance(client: &AccountingClient, accounts: u32) -> Result<i64, ClientError> {
+    let mut total = 0;
+    for account in 1..=accounts {
+        total += client.balance(account)?.ledger;
+    }
+    Ok(total)
+}
+
+fn parse<T: std::str::FromStr>(args: &ArgMatches, name: &str) -> Result<T, String> {
+    let value = args.value_of(name).unwrap();
+    value
+        .parse()
+        .map_err(|_| format!("Invalid Value {:?} For {}", value, name))
+}
+
+fn main() {
+    let matches = App::new("bench")
+        .about("Drives a transfer and balance workload against a running server")
+        .arg(
+            Arg::with_name("server")
+                .long("server")
+                .takes_value(true)
+                .default_value("localhost:3000"),
+        )
+        .arg(Arg::with_name("accounts").long("accounts").takes_value(true).default_value("1000"))
+        .arg(
+            Arg::with_name("initial-balance")
+                .long("initial-balance")
+                .takes_value(true)
+                .default_value("1000000"),
+        )
+        .arg(
+            Arg::with_name("hot-accounts")
+                .long("hot-accounts")
+                .takes_value(true)
+                .default_value("0")
+                .help("Number of accounts that receive --hot-fraction of the traffic"),
+        )
+        .arg(
+            Arg::with_name("hot-fraction")
+                .long("hot-fraction")
+                .takes_value(true)
+                .default_value("0.9"),
+        )
+        .arg(
+            Arg::with_name("fan-out")
+                .long("fan-out")
+                .takes_value(true)
+                .default_value("2")
+                .help("Accounts touched by each transfer"),
+        )
+        .arg(Arg::with_name("read-ratio").long("read-ratio").takes_value(true).default_value("0.5"))
+        .arg(
+            Arg::with_name("concurrency")
+                .long("concurrency")
+                .takes_value(true)
+                .default_value("32")
+                .help("Requests kept in flight"),
+        )
+        .arg(
+            Arg::with_name("duration")
+                .long("duration")
+                .takes_value(true)
+                .default_value("10")
+                .help("Seconds to run for"),
+        )
+        .arg(Arg::with_name("max-retries").long("max-retries").takes_value(true).default_value("3"))
+        .arg(
+            Arg::with_name("timeout-ms")
+                .long("timeout-ms")
+                .takes_value(true)
+                .default_value("5000"),
+        )
+        .arg(
+            Arg::with_name("skip-setup")
+                .long("skip-setup")
+                .help("Use the existing accounts instead of resetting the server"),
+        )
+        .get_matches();
+
+    let workload = match Workload::from_args(&matches) {
+        Ok(workload) => Arc::new(workload),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    let env = Arc::new(EnvBuilder::new().build());
+    let channel = ChannelBuilder::new(env).connect(matches.value_of("server").unwrap());
+    let setup = AccountingClient::new(channel.clone(), ClientConfig::default());
+    let client = AccountingServiceClient::new(channel);
+
+    if !matches.is_present("skip-setup") {
+        setup.reset().expect("Database Reset Failed");
+        for account in 1..=workload.accounts {
+            setup
+                .create_account(account, workload.initial_balance)
+                .expect("Account Creation Failed");
+        }
+    }
+    let expected = total_balance(&setup, workload.accounts).expect("Reading Balances Failed");
+
+    println!("{:?}", workload);
+    let start = Instant::now();
+    let deadline = start + workload.duration;
+    let workers: Vec<_> = (0..workload.concurrency)
+        .map(|_| worker(client.clone(), workload.clone(), deadline))
+        .collect();
+    let results = executor::block_on(future::join_all(workers));
+    let elapsed = start.elapsed();
+
+    let mut stats = Stats::default();
+    for result in results {
+        stats.merge(result);
+    }
+    let ops = stats.reads.len() + stats.transfers.len();
+    let secs = micros(elapsed) as f64 / 1_000_000.0;
+    println!("{} ops in {:.1}s, {:.1} ops/s", ops, secs, ops as f64 / secs);
+    report_latencies("reads", &mut stats.reads);
+    report_latencies("transfers", &mut stats.transfers);
+    println!(
+        "retries {}  aborted {}  rejected {}  failed {}",
+        stats.retries, stats.aborted, stats.rejected, stats.failed
+    );
+
+    // every transfer sums to zero, so the total must not have moved
+    let total = total_balance(&setup, workload.accounts).expect("Reading Balances Failed");
+    if total != expected {
+        println!("conservation FAILED: expected total {}, found {}", expected, total);
+        process::exit(1);
+    }
+    println!("conservation OK: total {}", total);
+}
@@ -1,25 +1,447 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::{Arc, Barrier};
-use threadpool::ThreadPool;
+use std::fmt::Display;
+use std::process;
+use std::sync::Arc;
 
-use grpcio::{ChannelBuilder, EnvBuilder, Result};
+use clap::{App, AppSettings, Arg, ArgMatches, ErrorKind, SubCommand};
+use grpcio::{ChannelBuilder, EnvBuilder, Error, RpcStatusCode};
+use serde_json::{json, Value};
 
 use accountant::proto::accounting::{
-    CreateAccountRequest, GetBalanceRequest, TransferComponent, TransferRequest,
-    ResetRequest, ResetResponse, CreateAccountResponse, TransferResponse,
-    GetBalanceResponse,
+    CreateAccountRequest, GetBalanceRequest, ListTransactionsRequest, ResetRequest,
+    TransactionRecord, TransferComponent, TransferRequest, VerifyLedgerRequest,
 };
 use accountant::proto::accounting_grpc::AccountingServiceClient;
+use accountant::sdk::new_req_id;
+use accountant::status::{code_name, ErrorDetails};
+
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(test)]
+use std::sync::Barrier;
+
+#[cfg(test)]
+use futures::executor::block_on_stream;
+#[cfg(test)]
+use grpcio::{ClientSStreamReceiver, Result};
+
+#[cfg(test)]
+use accountant::proto::accounting::{
+    ResetResponse, CreateAccountResponse, TransferResponse,
+    GetBalanceResponse, ListTransactionsResponse, WatchBalanceRequest,
+    AuthorizeTransferRequest, AuthorizeTransferResponse, CaptureTransferRequest,
+    CaptureTransferResponse, VoidTransferRequest, VoidTransferResponse,
+    ReverseTransferRequest, ReverseTransferResponse, VerifyLedgerResponse,
+};
+
+// exit code for bad arguments, the gRPC status code is used for failed calls
+const EXIT_USAGE: i32 = 64;
+// exit code of verify-ledger when balances disagree with the ledger and weren't rebuilt
+const EXIT_MISMATCH: i32 = 65;
+
+fn main() {
+    let app = App::new("client")
+        .about("Command line client for the accounting service")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("server")
+                .long("server")
+                .takes_value(true)
+                .env("ACCOUNTANT_SERVER")
+                .default_value("localhost:3000")
+                .help("Address of the accounting server"),
+        )
+        .arg(Arg::with_name("json").long("json").global(true).help("Print JSON instead of text"))
+        .arg(
+            Arg::with_name("req-id")
+                .long("req-id")
+                .takes_value(true)
+                .global(true)
+                .help("Request id to send, reuse it to safely retry a request"),
+        )
+        .subcommand(
+            SubCommand::with_name("create-account")
+                .about("Create an account")
+                .arg(Arg::with_name("account").required(true))
+                .arg(
+                    Arg::with_name("balance")
+                        .long("balance")
+                        .takes_value(true)
+                        .default_value("0"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("balance")
+                .about("Show the ledger and available balance of accounts")
+                .arg(Arg::with_name("account").required(true).multiple(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("transfer")
+                .about("Move money between accounts, e.g. transfer 1:-50 2:+50")
+                .setting(AppSettings::AllowLeadingHyphen)
+                .arg(Arg::with_name("leg").required(true).multiple(true).help("ACCOUNT:AMOUNT")),
+        )
+        .subcommand(
+            SubCommand::with_name("history")
+                .about("List recorded transfer legs")
+                .arg(
+                    Arg::with_name("account")
+                        .long("account")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .arg(
+                    Arg::with_name("transfer")
+                        .long("transfer")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help("req_id of a transfer"),
+                )
+                .arg(Arg::with_name("limit").long("limit").takes_value(true))
+                .arg(Arg::with_name("page-token").long("page-token").takes_value(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("reset")
+                .about("Delete every account and transaction")
+                .arg(Arg::with_name("yes").long("yes").help("Confirm the reset")),
+        )
+        .subcommand(
+            SubCommand::with_name("verify-ledger")
+                .about("Check every balance against the sum of its ledger entries")
+                .arg(
+                    Arg::with_name("rebuild")
+                        .long("rebuild")
+                        .help("Replace the balances that disagree with the ledger's"),
+                ),
+        );
+
+    let matches = app.get_matches_safe().unwrap_or_else(|e| match e.kind {
+        ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => e.exit(),
+        _ => {
+            eprintln!("{}", e.message);
+            process::exit(EXIT_USAGE)
+        }
+    });
+
+    let env = Arc::new(EnvBuilder::new().build());
+    let ch = ChannelBuilder::new(env).connect(matches.value_of("server").unwrap());
+    let cli = Cli {
+        client: AccountingServiceClient::new(ch),
+        json: matches.is_present("json"),
+    };
+
+    let res = match matches.subcommand() {
+        ("create-account", Some(args)) => cli.create_account(args),
+        ("balance", Some(args)) => cli.balance(args),
+        ("transfer", Some(args)) => cli.transfer(args),
+        ("history", Some(args)) => cli.history(args),
+        ("reset", Some(args)) => cli.reset(args),
+        ("verify-ledger", Some(args)) => cli.verify_ledger(args),
+        _ => Err(CliError::Usage("Unknown Command".to_string())),
+    };
+    if let Err(err) = res {
+        process::exit(cli.report(err));
+    }
+}
+
+enum CliError {
+    Usage(String),
+    Rpc(Error),
+}
+
+impl From<Error> for CliError {
+    fn from(err: Error) -> CliError {
+        CliError::Rpc(err)
+    }
+}
+
+type CliResult = std::result::Result<(), CliError>;
+
+struct Cli {
+    client: AccountingServiceClient,
+    json: bool,
+}
+
+impl Cli {
+    fn create_account(&self, args: &ArgMatches) -> CliResult {
+        let account = parse_arg(args, "account")?;
+        let balance = parse_arg(args, "balance")?;
+        let mut req = CreateAccountRequest::new();
+        req.set_req_id(req_id(args)?);
+        req.set_account_id(account);
+        req.set_balance(balance);
+        let reply = self.client.create_account(&req)?;
+
+        self.print(
+            json!({"req_id": reply.get_req_id(), "account_id": reply.get_account_id()}),
+            format!("Created account {}", reply.get_account_id()),
+        );
+        Ok(())
+    }
+
+    fn balance(&self, args: &ArgMatches) -> CliResult {
+        for account in args.values_of("account").unwrap() {
+            let account = parse(account, "account")?;
+            let mut req = GetBalanceRequest::new();
+            req.set_req_id(req_id(args)?);
+            req.set_account_id(account);
+            let reply = self.client.get_balance(&req)?;
+
+            self.print(
+                json!({
+                    "account_id": reply.get_account_id(),
+                    "balance": reply.get_balance(),
+                    "available_balance": reply.get_available_balance(),
+                }),
+                format!(
+                    "Account {}: balance {}, available {}",
+                    reply.get_account_id(),
+                    reply.get_balance(),
+                    reply.get_available_balance()
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    fn transfer(&self, args: &ArgMatches) -> CliResult {
+        let mut req = TransferRequest::new();
+        req.set_req_id(req_id(args)?);
+        for leg in args.values_of("leg").unwrap() {
+            let mut parts = leg.splitn(2, ':');
+            let (account, amount) = match (parts.next(), parts.next()) {
+                (Some(account), Some(amount)) => (account, amount),
+                _ => {
+                    let message = format!("Transfer Leg {:?} Is Not ACCOUNT:AMOUNT", leg);
+                    return Err(CliError::Usage(message));
+                }
+            };
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(parse(account, "account")?);
+            comp.set_money_delta(parse(amount, "amount")?);
+            req.mut_components().push(comp);
+        }
+        let reply = self.client.transfer(&req)?;
+
+        self.print(
+            json!({"req_id": reply.get_req_id()}),
+            format!("Transfer {} applied", reply.get_req_id()),
+        );
+        Ok(())
+    }
+
+    fn history(&self, args: &ArgMatches) -> CliResult {
+        let mut req = ListTransactionsRequest::new();
+        req.set_req_id(req_id(args)?);
+        if let Some(accounts) = args.values_of("account") {
+            for account in accounts {
+                req.mut_account_ids().push(parse(account, "account")?);
+            }
+        }
+        if let Some(transfers) = args.values_of("transfer") {
+            for transfer in transfers {
+                req.mut_transfer_req_ids().push(parse(transfer, "transfer")?);
+            }
+        }
+        if args.is_present("limit") {
+            req.set_page_size(parse_arg(args, "limit")?);
+        }
+        if let Some(token) = args.value_of("page-token") {
+            req.set_page_token(token.to_string());
+        }
+        let reply = self.client.list_transactions(&req)?;
+
+        if self.json {
+            let records: Vec<Value> = reply.get_transactions().iter().map(record_json).collect();
+            let next = reply.get_next_page_token();
+            println!("{}", json!({"transactions": records, "next_page_token": next}));
+            return Ok(());
+        }
+        println!(
+            "{:>20} {:>20} {:>10} {:>12} {:>5} {:>15}",
+            "id", "req_id", "account", "amount", "leg", "created_at"
+        );
+        for record in reply.get_transactions() {
+            println!(
+                "{:>20} {:>20} {:>10} {:>12} {:>5} {:>15}",
+                record.get_id(),
+                record.get_req_id(),
+                record.get_account_id(),
+                record.get_amount(),
+                record.get_transaction_index(),
+                record.get_created_at()
+            );
+        }
+        if !reply.get_next_page_token().is_empty() {
+            println!("More results: --page-token {}", reply.get_next_page_token());
+        }
+        Ok(())
+    }
 
+    fn reset(&self, args: &ArgMatches) -> CliResult {
+        if !args.is_present("yes") {
+            return Err(CliError::Usage(
+                "Reset Deletes Every Account, Pass --yes To Confirm".to_string(),
+            ));
+        }
+        self.client.reset(&ResetRequest::new())?;
+        self.print(json!({"reset": true}), "Reset complete".to_string());
+        Ok(())
+    }
+
+    fn verify_ledger(&self, args: &ArgMatches) -> CliResult {
+        let mut req = VerifyLedgerRequest::new();
+        req.set_rebuild(args.is_present("rebuild"));
+        let reply = self.client.verify_ledger(&req)?;
+
+        let mismatches = reply.get_mismatches();
+        let mut text = format!(
+            "Checked {} accounts, {} disagree with the ledger",
+            reply.get_accounts_checked(),
+            mismatches.len()
+        );
+        for mismatch in mismatches {
+            text.push_str(&format!(
+                "\nAccount {}: balance {}, ledger {}",
+                mismatch.get_account_id(),
+                mismatch.get_stored_balance(),
+                mismatch.get_ledger_balance()
+            ));
+        }
+        if reply.get_rebuilt() {
+            text.push_str("\nRebuilt their balances from the ledger");
+        }
+        let backfilled = reply.get_backfilled();
+        for backfill in backfilled {
+            text.push_str(&format!(
+                "\nAccount {}: opening balance {} was backfilled, check it against your records",
+                backfill.get_account_id(),
+                backfill.get_amount()
+            ));
+        }
+        let json_mismatches: Vec<Value> = mismatches
+            .iter()
+            .map(|mismatch| {
+                json!({
+                    "account_id": mismatch.get_account_id(),
+                    "stored_balance": mismatch.get_stored_balance(),
+                    "ledger_balance": mismatch.get_ledger_balance(),
+                })
+            })
+            .collect();
+        let json_backfilled: Vec<Value> = backfilled
+            .iter()
+            .map(|backfill| {
+                json!({"account_id": backfill.get_account_id(), "amount": backfill.get_amount()})
+            })
+            .collect();
+        self.print(
+            json!({
+                "accounts_checked": reply.get_accounts_checked(),
+                "mismatches": json_mismatches,
+                "rebuilt": reply.get_rebuilt(),
+                "backfilled": json_backfilled,
+            }),
+            text,
+        );
+        if !mismatches.is_empty() && !reply.get_rebuilt() {
+            process::exit(EXIT_MISMATCH);
+        }
+        Ok(())
+    }
+
+    fn print(&self, json: Value, text: String) {
+        if self.json {
+            println!("{}", json);
+        } else {
+            println!("{}", text);
+        }
+    }
+
+    // Prints the error and returns the exit code
+    fn report(&self, err: CliError) -> i32 {
+        let (code, status, message, details) = match err {
+            CliError::Usage(message) => {
+                (EXIT_USAGE, "Usage".to_string(), message, ErrorDetails::default())
+            }
+            CliError::Rpc(Error::RpcFailure(status)) => {
+                let details = ErrorDetails::decode(status.details());
+                let message = status.message().to_string();
+                (status.code().into(), code_name(status.code()).to_string(), message, details)
+            }
+            // the call never produced a status, e.g. the server could not be reached
+            CliError::Rpc(err) => (
+                RpcStatusCode::UNAVAILABLE.into(),
+                "Unavailable".to_string(),
+                err.to_string(),
+                ErrorDetails::default(),
+            ),
+        };
+        if self.json {
+            let err = json!({
+                "code": code,
+                "status": status,
+                "message": message,
+                "details": details.to_json(),
+            });
+            eprintln!("{}", json!({ "error": err }));
+        } else {
+            eprintln!("{}: {}", status, message);
+        }
+        code
+    }
+}
+
+fn record_json(record: &TransactionRecord) -> Value {
+    json!({
+        "id": record.get_id(),
+        "req_id": record.get_req_id(),
+        "account_id": record.get_account_id(),
+        "amount": record.get_amount(),
+        "transaction_index": record.get_transaction_index(),
+        "created_at": record.get_created_at(),
+    })
+}
+
+fn parse<T>(value: &str, name: &str) -> std::result::Result<T, CliError>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| CliError::Usage(format!("Invalid {} {:?}: {}", name, value, e)))
+}
+
+fn parse_arg<T>(args: &ArgMatches, name: &str) -> std::result::Result<T, CliError>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    parse(args.value_of(name).unwrap(), name)
+}
+
+// --req-id if given, otherwise a new random id
+fn req_id(args: &ArgMatches) -> std::result::Result<u64, CliError> {
+    if args.is_present("req-id") {
+        return parse_arg(args, "req-id");
+    }
+    Ok(new_req_id())
+}
+
+#[cfg(test)]
 #[derive(Clone)]
 pub struct ReqCounter {
     req_id: Arc<AtomicUsize>,
 }
 
+#[cfg(test)]
 impl ReqCounter {
     pub fn new() -> ReqCounter {
         ReqCounter {
-            req_id: Arc::new(AtomicUsize::new(0)),
+            // 0 is not a valid req_id
+            req_id: Arc::new(AtomicUsize::new(1)),
         }
     }
     pub fn incr(&self) -> u64 {
@@ -27,12 +449,14 @@ impl ReqCounter {
     }
 }
 
+#[cfg(test)]
 #[derive(Clone)]
 struct AccountTestingClient {
     client: AccountingServiceClient,
     counter: ReqCounter,
 }
 
+#[cfg(test)]
 impl AccountTestingClient {
     pub fn new() -> AccountTestingClient {
         let env = Arc::new(EnvBuilder::new().build());
@@ -50,6 +474,12 @@ impl AccountTestingClient {
         self.client.reset(&req)
     }
 
+    pub fn verify_ledger(&self, rebuild: bool) -> Result<VerifyLedgerResponse> {
+        let mut req = VerifyLedgerRequest::new();
+        req.set_rebuild(rebuild);
+        self.client.verify_ledger(&req)
+    }
+
     pub fn create_account(&mut self, account: u32, balance: i64) -> Result<CreateAccountResponse> {
         let mut req = CreateAccountRequest::new();
         req.set_req_id(self.counter.incr());
@@ -66,8 +496,13 @@ impl AccountTestingClient {
     }
 
     pub fn transfer(&self, transactions: &[(u32, i64)]) -> Result<TransferResponse> {
+        self.transfer_with_id(self.counter.incr(), transactions)
+    }
+
+    pub fn transfer_with_id(&self, req_id: u64, transactions: &[(u32, i64)])
+        -> Result<TransferResponse> {
         let mut req = TransferRequest::new();
-        req.set_req_id(self.counter.incr());
+        req.set_req_id(req_id);
 
         for transaction in transactions {
             let (account_id, amount) = transaction;
@@ -79,6 +514,55 @@ impl AccountTestingClient {
         self.client.transfer(&req)
     }
 
+    pub fn list_transactions(&self, account: u32, page_size: u32, page_token: &str)
+        -> Result<ListTransactionsResponse> {
+        let mut req = ListTransactionsRequest::new();
+        req.set_req_id(self.counter.incr());
+        req.set_account_ids(vec![account]);
+        req.set_page_size(page_size);
+        req.set_page_token(page_token.to_string());
+        self.client.list_transactions(&req)
+    }
+
+    pub fn watch_balance(&self, accounts: &[u32])
+        -> Result<ClientSStreamReceiver<GetBalanceResponse>> {
+        let mut req = WatchBalanceRequest::new();
+        req.set_req_id(self.counter.incr());
+        req.set_account_ids(accounts.to_vec());
+        self.client.watch_balance(&req)
+    }
+
+    pub fn authorize(&self, from: u32, to: u32, amount: i64) -> Result<AuthorizeTransferResponse> {
+        let mut req = AuthorizeTransferRequest::new();
+        req.set_req_id(self.counter.incr());
+        req.set_from_account_id(from);
+        req.set_to_account_id(to);
+        req.set_amount(amount);
+        self.client.authorize_transfer(&req)
+    }
+
+    pub fn capture(&self, hold_id: u64, amount: i64) -> Result<CaptureTransferResponse> {
+        let mut req = CaptureTransferRequest::new();
+        req.set_req_id(self.counter.incr());
+        req.set_hold_id(hold_id);
+        req.set_amount(amount);
+        self.client.capture_transfer(&req)
+    }
+
+    pub fn void(&self, hold_id: u64) -> Result<VoidTransferResponse> {
+        let mut req = VoidTransferRequest::new();
+        req.set_req_id(self.counter.incr());
+        req.set_hold_id(hold_id);
+        self.client.void_transfer(&req)
+    }
+
+    pub fn reverse(&self, original_req_id: u64) -> Result<ReverseTransferResponse> {
+        let mut req = ReverseTransferRequest::new();
+        req.set_req_id(self.counter.incr());
+        req.set_original_req_id(original_req_id);
+        self.client.reverse_transfer(&req)
+    }
+
     pub fn create_test_transfers(&mut self) -> Vec<Vec<(u32, i64)>> {
         let mut transfers: Vec<Vec<(u32, i64)>> = Vec::new();
         for _ in 0..500 {
@@ -94,8 +578,28 @@ impl AccountTestingClient {
 #[cfg(test)]
 mod tests {
     use rayon::prelude::*;
+    use threadpool::ThreadPool;
+    use accountant::sdk::{AccountingClient, ClientError};
+    use accountant::AccountBalance;
     use super::*;
 
+    // A reset server with account 1 holding 100 and account 2 empty
+    fn two_accounts() -> AccountTestingClient {
+        let mut client = AccountTestingClient::new();
+        client.reset().expect("Database Reset Failed");
+        assert!(client.create_account(1, 100).is_ok());
+        assert!(client.create_account(2, 0).is_ok());
+        client
+    }
+
+    fn two_sdk_accounts() -> AccountingClient {
+        let client = AccountingClient::connect("localhost:3000");
+        client.reset().expect("Database Reset Failed");
+        assert!(client.create_account(1, 100).is_ok());
+        assert!(client.create_account(2, 0).is_ok());
+        client
+    }
+
     #[test]
     fn test_create_account() {
         let mut client = AccountTestingClient::new();
@@ -107,17 +611,18 @@ mod tests {
             let barrier = barrier.clone();
             let mut client = client.clone();
             pool.execute(move || {
-                for account_id in (100 * i)..(100 * (i + 1)) {
-                    let reply = client.create_account(account_id, 1_000);
-                    assert!(reply.is_ok());
-                }
+                // account 0 is reserved; a failure is asserted after the barrier so the
+                // test fails instead of waiting forever
+                let created = ((100 * i + 1)..=(100 * (i + 1)))
+                    .all(|account_id| client.create_account(account_id, 1_000).is_ok());
                 barrier.wait();
+                assert!(created);
             });
         }
         barrier.wait();
 
         // validate balance for each account
-        for account in 0..2000 {
+        for account in 1..=2000 {
             let reply = client.get_balance(account);
             assert!(reply.is_ok());
             assert_eq!(reply.unwrap().get_balance(), 1_000);
@@ -145,6 +650,13 @@ mod tests {
         // get balance of invalid account
         let reply = client.get_balance(2);
         assert!(reply.is_err());
+
+        // an empty account is found with a zero balance
+        let reply = client.create_account(3, 0);
+        assert!(reply.is_ok());
+        let reply = client.get_balance(3);
+        assert!(reply.is_ok());
+        assert_eq!(reply.unwrap().get_balance(), 0);
     }
 
     #[test]
@@ -174,6 +686,11 @@ mod tests {
         let reply = client.transfer(&[(1, -50), (2, 55)]);
         assert!(reply.is_err());
 
+        // test transfer whose sum only wraps around to zero
+        let max = i64::MAX;
+        let reply = client.transfer(&[(2, max), (3, max), (1, 2)]);
+        assert!(reply.is_err());
+
         // test transfer with negative balance
         let reply = client.transfer(&[(1, -10_000), (2, 10_000)]);
         assert!(reply.is_err());
@@ -204,4 +721,217 @@ mod tests {
         assert!(reply.is_ok());
         assert_eq!(reply.unwrap().get_balance(), 500);
     }
+
+    #[test]
+    fn test_wide_transfer() {
+        let mut client = AccountTestingClient::new();
+        client.reset().expect("Database Reset Failed");
+
+        let reply = client.create_account(1, 5_000);
+        assert!(reply.is_ok());
+        for account in 2..52 {
+            let reply = client.create_account(account, 0);
+            assert!(reply.is_ok());
+        }
+
+        // one debit and 50 credits
+        let mut transfer: Vec<(u32, i64)> = (2..52).map(|account| (account, 10)).collect();
+        transfer.push((1, -500));
+        let reply = client.transfer(&transfer);
+        assert!(reply.is_ok());
+        assert_eq!(client.get_balance(1).unwrap().get_balance(), 4_500);
+        assert_eq!(client.get_balance(51).unwrap().get_balance(), 10);
+
+        // a missing account among many legs fails the whole transfer
+        let mut transfer: Vec<(u32, i64)> = (2..52).map(|account| (account, -10)).collect();
+        transfer.push((60, 500));
+        let reply = client.transfer(&transfer);
+        assert!(reply.is_err());
+        assert_eq!(client.get_balance(2).unwrap().get_balance(), 10);
+
+        // as does one overdrawn leg
+        let mut transfer: Vec<(u32, i64)> = (2..52).map(|account| (account, -10)).collect();
+        transfer[25].1 = -11;
+        transfer.push((1, 501));
+        let reply = client.transfer(&transfer);
+        assert!(reply.is_err());
+        assert_eq!(client.get_balance(1).unwrap().get_balance(), 4_500);
+    }
+
+    #[test]
+    fn test_idempotent_transfer() {
+        let mut client = two_accounts();
+
+        // a retried request is only applied once
+        let req_id = client.counter.incr();
+        let reply = client.transfer_with_id(req_id, &[(1, -10), (2, 10)]);
+        assert!(reply.is_ok());
+        let reply = client.transfer_with_id(req_id, &[(1, -10), (2, 10)]);
+        assert_eq!(reply.unwrap().get_req_id(), req_id);
+
+        // reusing the req_id for a different transfer is rejected
+        let reply = client.transfer_with_id(req_id, &[(1, -20), (2, 20)]);
+        assert!(reply.is_err());
+
+        let reply = client.get_balance(1);
+        assert_eq!(reply.unwrap().get_balance(), 90);
+    }
+
+    #[test]
+    fn test_list_transactions() {
+        let client = two_accounts();
+
+        for _ in 0..3 {
+            let reply = client.transfer(&[(1, -10), (2, 10)]);
+            assert!(reply.is_ok());
+        }
+
+        let reply = client.list_transactions(1, 2, "").unwrap();
+        assert_eq!(reply.get_transactions().len(), 2);
+        assert!(reply.get_transactions().iter().all(|t| t.get_amount() == -10));
+        assert!(!reply.get_next_page_token().is_empty());
+
+        let reply = client.list_transactions(1, 2, reply.get_next_page_token()).unwrap();
+        assert_eq!(reply.get_transactions().len(), 1);
+        assert!(reply.get_next_page_token().is_empty());
+
+        let reply = client.list_transactions(1, 2, "not a token");
+        assert!(reply.is_err());
+    }
+
+    #[test]
+    fn test_watch_balance() {
+        let client = two_accounts();
+
+        // the current balance is sent first, then one event per committed transfer
+        let mut events = block_on_stream(client.watch_balance(&[2]).unwrap());
+        let event = events.next().unwrap().unwrap();
+        assert_eq!((event.get_account_id(), event.get_balance()), (2, 0));
+
+        let reply = client.transfer(&[(1, -10), (2, 10)]);
+        assert!(reply.is_ok());
+        let event = events.next().unwrap().unwrap();
+        assert_eq!((event.get_account_id(), event.get_balance()), (2, 10));
+
+        // watching an unknown account fails the stream
+        let mut events = block_on_stream(client.watch_balance(&[5]).unwrap());
+        assert!(events.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_holds() {
+        let mut client = two_accounts();
+
+        // held funds are only removed from the available balance
+        let hold_id = client.authorize(1, 2, 60).unwrap().get_hold_id();
+        let reply = client.get_balance(1).unwrap();
+        assert_eq!((reply.get_balance(), reply.get_available_balance()), (100, 40));
+
+        let reply = client.transfer(&[(1, -50), (2, 50)]);
+        assert!(reply.is_err());
+        let reply = client.authorize(1, 2, 50);
+        assert!(reply.is_err());
+
+        // capture part of the hold, the rest becomes available again
+        let reply = client.capture(hold_id, 25).unwrap();
+        assert_eq!(reply.get_captured_amount(), 25);
+        let reply = client.get_balance(1).unwrap();
+        assert_eq!((reply.get_balance(), reply.get_available_balance()), (75, 75));
+        let reply = client.capture(hold_id, 0);
+        assert!(reply.is_err());
+
+        let hold_id = client.authorize(1, 2, 75).unwrap().get_hold_id();
+        let reply = client.void(hold_id);
+        assert!(reply.is_ok());
+        let reply = client.get_balance(1).unwrap();
+        assert_eq!((reply.get_balance(), reply.get_available_balance()), (75, 75));
+        let reply = client.get_balance(2).unwrap();
+        assert_eq!(reply.get_balance(), 25);
+    }
+
+    #[test]
+    fn test_reverse_transfer() {
+        let mut client = two_accounts();
+
+        let req_id = client.transfer(&[(1, -40), (2, 40)]).unwrap().get_req_id();
+        let reply = client.reverse(req_id);
+        assert!(reply.is_ok());
+        assert_eq!(client.get_balance(1).unwrap().get_balance(), 100);
+        assert_eq!(client.get_balance(2).unwrap().get_balance(), 0);
+
+        // a transfer is only reversed once
+        let reply = client.reverse(req_id);
+        assert!(reply.is_err());
+
+        // unknown transfer
+        let reply = client.reverse(10_000);
+        assert!(reply.is_err());
+    }
+
+    #[test]
+    fn test_verify_ledger() {
+        let mut client = two_accounts();
+        let req_id = client.transfer(&[(1, -40), (2, 40)]).unwrap().get_req_id();
+        assert!(client.reverse(req_id).is_ok());
+        assert!(client.transfer(&[(1, -25), (2, 25)]).is_ok());
+
+        // opening balances are ledger entries, so every balance adds up
+        let reply = client.verify_ledger(false).unwrap();
+        assert_eq!(reply.get_accounts_checked(), 2);
+        assert!(reply.get_mismatches().is_empty());
+        let reply = client.verify_ledger(true).unwrap();
+        assert!(!reply.get_rebuilt());
+        assert_eq!(client.get_balance(1).unwrap().get_balance(), 75);
+        // but aren't transfers
+        let reply = client.list_transactions(1, 10, "").unwrap();
+        let amounts: Vec<i64> = reply.get_transactions().iter().map(|t| t.get_amount()).collect();
+        assert_eq!(amounts, vec![-40, 40, -25]);
+    }
+
+    #[test]
+    fn test_sdk_client() {
+        let client = two_sdk_accounts();
+        match client.create_account(1, 100) {
+            Err(ClientError::AlreadyExists(_)) => (),
+            other => panic!("expected AlreadyExists, got {:?}", other),
+        }
+
+        let req_id = client.transfer(&[(1, -30), (2, 30)]).unwrap();
+        assert_eq!(client.balance(1).unwrap(), AccountBalance { ledger: 70, available: 70 });
+        match client.transfer(&[(1, -100), (2, 100)]) {
+            Err(ClientError::FailedPrecondition(_)) => (),
+            other => panic!("expected FailedPrecondition, got {:?}", other),
+        }
+
+        assert!(client.reverse(req_id).is_ok());
+        assert_eq!(client.balance(2).unwrap().ledger, 0);
+        match client.balance(3) {
+            Err(err) => assert_eq!(err.code(), RpcStatusCode::NOT_FOUND),
+            Ok(balance) => panic!("expected NotFound, got {:?}", balance),
+        }
+    }
+
+    #[test]
+    fn test_error_details() {
+        let client = two_sdk_accounts();
+        assert!(client.authorize(1, 2, 30).is_ok());
+
+        // the overdrawn account, where it was in the request, and what it can still spend
+        let err = client.transfer(&[(2, 80), (1, -80)]).unwrap_err();
+        assert_eq!(err.reason(), Some("INSUFFICIENT_FUNDS"));
+        let details = err.details().unwrap();
+        assert_eq!(details.account_id(), Some(1));
+        assert_eq!(details.get_i64("component_index"), Some(1));
+        assert_eq!(details.get_i64("available_balance"), Some(70));
+        assert_eq!(err.message(), "Insufficient Funds In Account 1");
+
+        let err = client.transfer(&[(1, -5), (0, 5)]).unwrap_err();
+        assert_eq!(err.reason(), Some("INVALID_REQUEST"));
+        let violations = &err.details().unwrap().field_violations;
+        assert_eq!(violations[0].field, "components[1].account_id");
+
+        let err = client.balance(3).unwrap_err();
+        assert_eq!(err.reason(), Some("ACCOUNT_NOT_FOUND"));
+        assert_eq!(err.details().unwrap().account_id(), Some(3));
+    }
 }
@@ -0,0 +1,751 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::{App, Arg, ErrorKind, SubCommand};
+use serde_derive::Deserialize;
+
+use crate::db::file::Durability;
+use crate::db::postgres::PoolConfig;
+use crate::db::retry::RetryPolicy;
+use crate::idempotency::IdempotencyConfig;
+use crate::ServiceConfig;
+use crate::transfer::AmountLimits;
+use crate::validation::RequestLimits;
+
+// Prefix of the environment variable for each setting, e.g. ACCOUNTANT_PORT
+pub const ENV_PREFIX: &str = "ACCOUNTANT_";
+
+// (key, help) for every setting; the CLI flag is the key with dashes
+const SETTINGS: &[(&str, &str)] = &[
+    ("host", "Address to bind the gRPC server to"),
+    ("port", "Port to bind the gRPC server to"),
+    ("database_url", "Postgres or CockroachDB connection string, sqlite:<file> or file:<file>"),
+    ("pool_size", "Maximum number of database connections"),
+    ("pool_connection_timeout_secs", "Seconds to wait for a database connection"),
+    ("pool_idle_timeout_secs", "Seconds before an idle connection is closed, 0 to keep them"),
+    ("grpc_threads", "Number of grpcio completion queue threads"),
+    ("max_send_message_len", "Largest message the server sends, in bytes"),
+    ("max_receive_message_len", "Largest message the server accepts, in bytes"),
+    ("stream_window_size", "Initial HTTP/2 stream window size, in bytes"),
+    ("max_concurrent_streams", "Maximum concurrent streams per connection"),
+    ("shutdown_grace_secs", "Seconds to let in-flight requests finish on SIGTERM or SIGINT"),
+    ("record_requests", "JSONL file to record CreateAccount, Transfer and GetBalance calls to"),
+    ("txn_max_attempts", "Attempts of a transaction that keeps hitting serialization conflicts"),
+    ("txn_initial_backoff_ms", "Backoff before the first transaction retry, doubled per retry"),
+    ("txn_max_backoff_ms", "Longest backoff between transaction retries"),
+    ("txn_max_elapsed_ms", "Longest a transaction may spend retrying"),
+    ("max_components", "Most components one transfer may have"),
+    ("max_ids", "Most account or transfer ids one ListTransactions or WatchBalance may name"),
+    ("max_leg_amount", "Largest amount a transfer component or authorization may move"),
+    ("max_balance", "Largest balance an account may hold"),
+    ("batch_transfers", "Apply a transfer in one UPDATE and one INSERT, false for one per leg"),
+    ("metrics_file", "File the retry metrics are written to in Prometheus text format"),
+    ("metrics_interval_secs", "Seconds between writes of the metrics file"),
+    ("ledger_fsync", "When a file ledger fsyncs its log: always, interval or never"),
+    ("ledger_fsync_interval_ms", "Longest a file ledger leaves appends unsynced with interval"),
+    ("ledger_snapshot_records", "Log records between file ledger snapshots, 0 for none"),
+    ("idempotency_retention_secs", "Seconds a completed request can be replayed by its req_id"),
+    ("idempotency_max_entries", "Most completed requests kept for replay, oldest forgotten first"),
+    ("hold_ttl_secs", "Seconds an authorization holds funds before they are released"),
+];
+
+// What the server does with its settings
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ServerCommand {
+    Serve,
+    // brings the database schema up to date and exits
+    Migrate,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerConfig {
+    // set by the subcommand, not a setting
+    pub command: ServerCommand,
+    pub host: String,
+    pub port: u16,
+    pub database_url: String,
+    pub pool_size: u32,
+    pub pool_connection_timeout_secs: u64,
+    pub pool_idle_timeout_secs: u64,
+    pub grpc_threads: usize,
+    pub max_send_message_len: i32,
+    pub max_receive_message_len: i32,
+    pub stream_window_size: i32,
+    pub max_concurrent_streams: i32,
+    pub shutdown_grace_secs: u64,
+    // empty disables recording
+    pub record_requests: String,
+    pub txn_max_attempts: u32,
+    pub txn_initial_backoff_ms: u64,
+    pub txn_max_backoff_ms: u64,
+    pub txn_max_elapsed_ms: u64,
+    pub max_components: usize,
+    pub max_ids: usize,
+    pub max_leg_amount: i64,
+    pub max_balance: i64,
+    pub batch_transfers: bool,
+    // empty disables the metrics file
+    pub metrics_file: String,
+    pub metrics_interval_secs: u64,
+    pub ledger_fsync: String,
+    pub ledger_fsync_interval_ms: u64,
+    pub ledger_snapshot_records: u64,
+    pub idempotency_retention_secs: u64,
+    pub idempotency_max_entries: usize,
+    pub hold_ttl_secs: u64,
+}
+
+impl Default for ServerConfig {
+    fn default() -> ServerConfig {
+        ServerConfig {
+            command: ServerCommand::Serve,
+            host: "0.0.0.0".to_string(),
+            port: 3000,
+            database_url: "postgresql://accountant@localhost:26257/bank".to_string(),
+            pool_size: 10,
+            pool_connection_timeout_secs: 30,
+            pool_idle_timeout_secs: 600,
+            grpc_threads: 4,
+            max_send_message_len: 32 * 1024 * 1024,
+            max_receive_message_len: 32 * 1024 * 1024,
+            stream_window_size: 2 * 1024 * 1024,
+            max_concurrent_streams: 1024,
+            shutdown_grace_secs: 30,
+            record_requests: String::new(),
+            txn_max_attempts: 10,
+            txn_initial_backoff_ms: 5,
+            txn_max_backoff_ms: 500,
+            txn_max_elapsed_ms: 5_000,
+            max_components: RequestLimits::default().max_components,
+            max_ids: RequestLimits::default().max_ids,
+            max_leg_amount: AmountLimits::default().max_leg_amount,
+            max_balance: AmountLimits::default().max_balance,
+            batch_transfers: true,
+            metrics_file: String::new(),
+            metrics_interval_secs: 15,
+            ledger_fsync: "always".to_string(),
+            ledger_fsync_interval_ms: 100,
+            ledger_snapshot_records: 100_000,
+            idempotency_retention_secs: IdempotencyConfig::default().retention.as_secs(),
+            idempotency_max_entries: IdempotencyConfig::default().max_entries,
+            hold_ttl_secs: ServiceConfig::default().hold_ttl.as_secs(),
+        }
+    }
+}
+
+// One layer of settings; unset fields fall through to the layer below
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PartialConfig {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub database_url: Option<String>,
+    pub pool_size: Option<u32>,
+    pub pool_connection_timeout_secs: Option<u64>,
+    pub pool_idle_timeout_secs: Option<u64>,
+    pub grpc_threads: Option<usize>,
+    pub max_send_message_len: Option<i32>,
+    pub max_receive_message_len: Option<i32>,
+    pub stream_window_size: Option<i32>,
+    pub max_concurrent_streams: Option<i32>,
+    pub shutdown_grace_secs: Option<u64>,
+    pub record_requests: Option<String>,
+    pub txn_max_attempts: Option<u32>,
+    pub txn_initial_backoff_ms: Option<u64>,
+    pub txn_max_backoff_ms: Option<u64>,
+    pub txn_max_elapsed_ms: Option<u64>,
+    pub max_components: Option<usize>,
+    pub max_ids: Option<usize>,
+    pub max_leg_amount: Option<i64>,
+    pub max_balance: Option<i64>,
+    pub batch_transfers: Option<bool>,
+    pub metrics_file: Option<String>,
+    pub metrics_interval_secs: Option<u64>,
+    pub ledger_fsync: Option<String>,
+    pub ledger_fsync_interval_ms: Option<u64>,
+    pub ledger_snapshot_records: Option<u64>,
+    pub idempotency_retention_secs: Option<u64>,
+    pub idempotency_max_entries: Option<usize>,
+    pub hold_ttl_secs: Option<u64>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    // the config file could not be read or parsed
+    File(String, String),
+    // a value from the CLI or environment is not of the right type
+    Value(String, String),
+    Args(String),
+    // every setting that failed validation
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::File(path, reason) => {
+                write!(f, "Could Not Load Config File {}: {}", path, reason)
+            }
+            ConfigError::Value(source, value) => {
+                write!(f, "Invalid Value {:?} For {}", value, source)
+            }
+            ConfigError::Args(reason) => write!(f, "{}", reason),
+            ConfigError::Invalid(problems) => {
+                write!(f, "Invalid Configuration:")?;
+                for problem in problems {
+                    write!(f, "\n  - {}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl PartialConfig {
+    pub fn from_toml(contents: &str) -> Result<PartialConfig, String> {
+        toml::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    pub fn from_file(path: &str) -> Result<PartialConfig, ConfigError> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| ConfigError::File(path.to_string(), e.to_string()))?;
+        PartialConfig::from_toml(&contents).map_err(|e| ConfigError::File(path.to_string(), e))
+    }
+
+    // Reads ACCOUNTANT_<KEY> for every setting
+    pub fn from_env(vars: &HashMap<String, String>) -> Result<PartialConfig, ConfigError> {
+        let mut config = PartialConfig::default();
+        for &(key, _) in SETTINGS {
+            let name = format!("{}{}", ENV_PREFIX, key.to_uppercase());
+            if let Some(value) = vars.get(&name) {
+                config.set(key, value).map_err(|_| ConfigError::Value(name, value.clone()))?;
+            }
+        }
+        Ok(config)
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ()> {
+        match key {
+            "host" => self.host = Some(value.to_string()),
+            "port" => self.port = Some(parse(value)?),
+            "database_url" => self.database_url = Some(value.to_string()),
+            "pool_size" => self.pool_size = Some(parse(value)?),
+            "pool_connection_timeout_secs" => {
+                self.pool_connection_timeout_secs = Some(parse(value)?)
+            }
+            "pool_idle_timeout_secs" => self.pool_idle_timeout_secs = Some(parse(value)?),
+            "grpc_threads" => self.grpc_threads = Some(parse(value)?),
+            "max_send_message_len" => self.max_send_message_len = Some(parse(value)?),
+            "max_receive_message_len" => self.max_receive_message_len = Some(parse(value)?),
+            "stream_window_size" => self.stream_window_size = Some(parse(value)?),
+            "max_concurrent_streams" => self.max_concurrent_streams = Some(parse(value)?),
+            "shutdown_grace_secs" => self.shutdown_grace_secs = Some(parse(value)?),
+            "record_requests" => self.record_requests = Some(value.to_string()),
+            "txn_max_attempts" => self.txn_max_attempts = Some(parse(value)?),
+            "txn_initial_backoff_ms" => self.txn_initial_backoff_ms = Some(parse(value)?),
+            "txn_max_backoff_ms" => self.txn_max_backoff_ms = Some(parse(value)?),
+            "txn_max_elapsed_ms" => self.txn_max_elapsed_ms = Some(parse(value)?),
+            "max_components" => self.max_components = Some(parse(value)?),
+            "max_ids" => self.max_ids = Some(parse(value)?),
+            "max_leg_amount" => self.max_leg_amount = Some(parse(value)?),
+            "max_balance" => self.max_balance = Some(parse(value)?),
+            "batch_transfers" => self.batch_transfers = Some(parse(value)?),
+            "metrics_file" => self.metrics_file = Some(value.to_string()),
+            "metrics_interval_secs" => self.metrics_interval_secs = Some(parse(value)?),
+            "ledger_fsync" => self.ledger_fsync = Some(value.to_string()),
+            "ledger_fsync_interval_ms" => self.ledger_fsync_interval_ms = Some(parse(value)?),
+            "ledger_snapshot_records" => self.ledger_snapshot_records = Some(parse(value)?),
+            "idempotency_retention_secs" => self.idempotency_retention_secs = Some(parse(value)?),
+            "idempotency_max_entries" => self.idempotency_max_entries = Some(parse(value)?),
+            "hold_ttl_secs" => self.hold_ttl_secs = Some(parse(value)?),
+            _ => return Err(()),
+        }
+        Ok(())
+    }
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, ()> {
+    value.trim().parse().map_err(|_| ())
+}
+
+impl ServerConfig {
+    // Defaults, then the config file, then environment variables, then CLI flags
+    pub fn load() -> Result<ServerConfig, ConfigError> {
+        let vars = std::env::vars().collect();
+        ServerConfig::load_from(std::env::args_os(), &vars)
+    }
+
+    pub fn load_from<I, T>(
+        args: I,
+        vars: &HashMap<String, String>,
+    ) -> Result<ServerConfig, ConfigError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let flags: Vec<(String, &str, &str)> = SETTINGS
+            .iter()
+            .map(|&(key, help)| (key.replace('_', "-"), key, help))
+            .collect();
+        let mut app = App::new("server")
+            .about("Accounting gRPC server")
+            .arg(
+                Arg::with_name("config")
+                    .long("config")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .help("TOML config file, also read from ACCOUNTANT_CONFIG"),
+            )
+            .subcommand(
+                SubCommand::with_name("migrate")
+                    .about("Apply pending schema migrations to database_url and exit"),
+            );
+        for (flag, key, help) in &flags {
+            app = app.arg(Arg::with_name(key).long(flag).takes_value(true).help(help));
+        }
+        let matches = app.get_matches_from_safe(args).map_err(|e| match e.kind {
+            ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => e.exit(),
+            _ => ConfigError::Args(e.message),
+        })?;
+
+        let config_file = matches
+            .value_of("config")
+            .map(str::to_string)
+            .or_else(|| vars.get(&format!("{}CONFIG", ENV_PREFIX)).cloned());
+        let file = match config_file {
+            Some(path) => PartialConfig::from_file(&path)?,
+            None => PartialConfig::default(),
+        };
+        let env = PartialConfig::from_env(vars)?;
+        let mut cli = PartialConfig::default();
+        for (flag, key, _) in &flags {
+            if let Some(value) = matches.value_of(key) {
+                cli.set(key, value)
+                    .map_err(|_| ConfigError::Value(format!("--{}", flag), value.to_string()))?;
+            }
+        }
+
+        let mut config = ServerConfig::default().merge(file).merge(env).merge(cli);
+        if matches.subcommand_name() == Some("migrate") {
+            config.command = ServerCommand::Migrate;
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn merge(mut self, layer: PartialConfig) -> ServerConfig {
+        if let Some(host) = layer.host {
+            self.host = host;
+        }
+        if let Some(port) = layer.port {
+            self.port = port;
+        }
+        if let Some(database_url) = layer.database_url {
+            self.database_url = database_url;
+        }
+        if let Some(pool_size) = layer.pool_size {
+            self.pool_size = pool_size;
+        }
+        if let Some(timeout) = layer.pool_connection_timeout_secs {
+            self.pool_connection_timeout_secs = timeout;
+        }
+        if let Some(timeout) = layer.pool_idle_timeout_secs {
+            self.pool_idle_timeout_secs = timeout;
+        }
+        if let Some(grpc_threads) = layer.grpc_threads {
+            self.grpc_threads = grpc_threads;
+        }
+        if let Some(len) = layer.max_send_message_len {
+            self.max_send_message_len = len;
+        }
+        if let Some(len) = layer.max_receive_message_len {
+            self.max_receive_message_len = len;
+        }
+        if let Some(size) = layer.stream_window_size {
+            self.stream_window_size = size;
+        }
+        if let Some(streams) = layer.max_concurrent_streams {
+            self.max_concurrent_streams = streams;
+        }
+        if let Some(grace) = layer.shutdown_grace_secs {
+            self.shutdown_grace_secs = grace;
+        }
+        if let Some(path) = layer.record_requests {
+            self.record_requests = path;
+        }
+        if let Some(attempts) = layer.txn_max_attempts {
+            self.txn_max_attempts = attempts;
+        }
+        if let Some(backoff) = layer.txn_initial_backoff_ms {
+            self.txn_initial_backoff_ms = backoff;
+        }
+        if let Some(backoff) = layer.txn_max_backoff_ms {
+            self.txn_max_backoff_ms = backoff;
+        }
+        if let Some(elapsed) = layer.txn_max_elapsed_ms {
+            self.txn_max_elapsed_ms = elapsed;
+        }
+        if let Some(components) = layer.max_components {
+            self.max_components = components;
+        }
+        if let Some(ids) = layer.max_ids {
+            self.max_ids = ids;
+        }
+        if let Some(amount) = layer.max_leg_amount {
+            self.max_leg_amount = amount;
+        }
+        if let Some(balance) = layer.max_balance {
+            self.max_balance = balance;
+        }
+        if let Some(batch) = layer.batch_transfers {
+            self.batch_transfers = batch;
+        }
+        if let Some(path) = layer.metrics_file {
+            self.metrics_file = path;
+        }
+        if let Some(interval) = layer.metrics_interval_secs {
+            self.metrics_interval_secs = interval;
+        }
+        if let Some(fsync) = layer.ledger_fsync {
+            self.ledger_fsync = fsync;
+        }
+        if let Some(interval) = layer.ledger_fsync_interval_ms {
+            self.ledger_fsync_interval_ms = interval;
+        }
+        if let Some(records) = layer.ledger_snapshot_records {
+            self.ledger_snapshot_records = records;
+        }
+        if let Some(retention) = layer.idempotency_retention_secs {
+            self.idempotency_retention_secs = retention;
+        }
+        if let Some(entries) = layer.idempotency_max_entries {
+            self.idempotency_max_entries = entries;
+        }
+        if let Some(ttl) = layer.hold_ttl_secs {
+            self.hold_ttl_secs = ttl;
+        }
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+        if self.host.trim().is_empty() {
+            problems.push("host must not be empty".to_string());
+        }
+        if self.port == 0 {
+            problems.push("port must be between 1 and 65535".to_string());
+        }
+        match (self.sqlite_path(), self.ledger_path()) {
+            (Some(""), _) => problems.push("database_url sqlite: needs a file path".to_string()),
+            (Some(_), _) if !cfg!(feature = "sqlite") => problems.push(format!(
+                "database_url {:?} needs a server built with the sqlite feature",
+                self.database_url
+            )),
+            (_, Some("")) => problems.push("database_url file: needs a file path".to_string()),
+            (Some(_), _) | (_, Some(_)) => {}
+            (None, None)
+                if !self.database_url.starts_with("postgres://")
+                    && !self.database_url.starts_with("postgresql://") =>
+            {
+                let schemes = "postgres://, postgresql://, sqlite: or file:";
+                problems.push(format!(
+                    "database_url {:?} must start with {}",
+                    self.database_url, schemes
+                ))
+            }
+            (None, None) => {}
+        }
+        let migrates = self.sqlite_path().is_none() && self.ledger_path().is_none();
+        if self.command == ServerCommand::Migrate && !migrates {
+            problems.push("migrate needs a Postgres or CockroachDB database_url".to_string());
+        }
+        match self.ledger_fsync.as_str() {
+            "always" | "never" => {}
+            "interval" if self.ledger_fsync_interval_ms == 0 => {
+                problems.push("ledger_fsync_interval_ms must be at least 1".to_string())
+            }
+            "interval" => {}
+            other => problems.push(format!(
+                "ledger_fsync {:?} must be always, interval or never",
+                other
+            )),
+        }
+        if self.pool_size == 0 {
+            problems.push("pool_size must be at least 1".to_string());
+        }
+        if self.pool_connection_timeout_secs == 0 {
+            problems.push("pool_connection_timeout_secs must be at least 1".to_string());
+        }
+        if self.grpc_threads == 0 {
+            problems.push("grpc_threads must be at least 1".to_string());
+        }
+        if self.txn_max_attempts == 0 {
+            problems.push("txn_max_attempts must be at least 1".to_string());
+        }
+        if self.txn_initial_backoff_ms > self.txn_max_backoff_ms {
+            problems.push("txn_initial_backoff_ms must not exceed txn_max_backoff_ms".to_string());
+        }
+        if self.metrics_interval_secs == 0 {
+            problems.push("metrics_interval_secs must be at least 1".to_string());
+        }
+        if self.idempotency_retention_secs == 0 {
+            problems.push("idempotency_retention_secs must be at least 1".to_string());
+        }
+        if self.idempotency_max_entries == 0 {
+            problems.push("idempotency_max_entries must be at least 1".to_string());
+        }
+        if self.hold_ttl_secs == 0 {
+            problems.push("hold_ttl_secs must be at least 1".to_string());
+        }
+        let sizes = [
+            ("max_send_message_len", self.max_send_message_len),
+            ("max_receive_message_len", self.max_receive_message_len),
+            ("stream_window_size", self.stream_window_size),
+            ("max_concurrent_streams", self.max_concurrent_streams),
+        ];
+        for &(key, value) in &sizes {
+            if value <= 0 {
+                problems.push(format!("{} must be positive", key));
+            }
+        }
+        if self.max_components == 0 {
+            problems.push("max_components must be at least 1".to_string());
+        }
+        if self.max_ids == 0 {
+            problems.push("max_ids must be at least 1".to_string());
+        }
+        let limits = [("max_leg_amount", self.max_leg_amount), ("max_balance", self.max_balance)];
+        for &(key, value) in &limits {
+            if value <= 0 {
+                problems.push(format!("{} must be positive", key));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+
+    // The file of a sqlite:<file> or sqlite://<file> database_url, None for Postgres
+    pub fn sqlite_path(&self) -> Option<&str> {
+        let url = &self.database_url;
+        url.strip_prefix("sqlite://").or_else(|| url.strip_prefix("sqlite:"))
+    }
+
+    // The log of a file:<file> or file://<file> database_url, None otherwise
+    pub fn ledger_path(&self) -> Option<&str> {
+        let url = &self.database_url;
+        url.strip_prefix("file://").or_else(|| url.strip_prefix("file:"))
+    }
+
+    pub fn ledger_durability(&self) -> Durability {
+        match self.ledger_fsync.as_str() {
+            "never" => Durability::Never,
+            "interval" => {
+                Durability::Interval(Duration::from_millis(self.ledger_fsync_interval_ms))
+            }
+            _ => Durability::Always,
+        }
+    }
+
+    pub fn shutdown_grace(&self) -> Duration {
+        Duration::from_secs(self.shutdown_grace_secs)
+    }
+
+    pub fn metrics_interval(&self) -> Duration {
+        Duration::from_secs(self.metrics_interval_secs)
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.txn_max_attempts,
+            initial_backoff: Duration::from_millis(self.txn_initial_backoff_ms),
+            max_backoff: Duration::from_millis(self.txn_max_backoff_ms),
+            max_elapsed: Duration::from_millis(self.txn_max_elapsed_ms),
+        }
+    }
+
+    pub fn idempotency(&self) -> IdempotencyConfig {
+        IdempotencyConfig {
+            retention: Duration::from_secs(self.idempotency_retention_secs),
+            max_entries: self.idempotency_max_entries,
+        }
+    }
+
+    pub fn hold_ttl(&self) -> Duration {
+        Duration::from_secs(self.hold_ttl_secs)
+    }
+
+    pub fn request_limits(&self) -> RequestLimits {
+        RequestLimits {
+            max_components: self.max_components,
+            max_ids: self.max_ids,
+            amounts: AmountLimits {
+                max_leg_amount: self.max_leg_amount,
+                max_balance: self.max_balance,
+            },
+        }
+    }
+
+    pub fn pool(&self) -> PoolConfig {
+        PoolConfig {
+            max_size: self.pool_size,
+            connection_timeout: Duration::from_secs(self.pool_connection_timeout_secs),
+            idle_timeout: match self.pool_idle_timeout_secs {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = ServerConfig::load_from(vec!["server"], &HashMap::new()).unwrap();
+        assert_eq!(config, ServerConfig::default());
+    }
+
+    #[test]
+    fn test_precedence() {
+        let path = std::env::temp_dir().join(format!("accountant-{}.toml", std::process::id()));
+        fs::write(&path, "port = 4000\npool_size = 3\ngrpc_threads = 8\n").unwrap();
+        let path = path.to_str().unwrap().to_string();
+
+        let env = vars(&[
+            ("ACCOUNTANT_PORT", "5000"),
+            ("ACCOUNTANT_POOL_SIZE", "5"),
+            ("ACCOUNTANT_BATCH_TRANSFERS", "false"),
+            ("ACCOUNTANT_IDEMPOTENCY_MAX_ENTRIES", "500"),
+        ]);
+        let args = vec!["server", "--config", &path, "--port", "6000"];
+        let config = ServerConfig::load_from(args, &env).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // CLI over environment over file over defaults
+        assert_eq!(config.port, 6000);
+        assert_eq!(config.pool_size, 5);
+        assert_eq!(config.grpc_threads, 8);
+        assert!(!config.batch_transfers);
+        assert_eq!(config.idempotency().max_entries, 500);
+        assert_eq!(config.host, "0.0.0.0");
+        assert_eq!(config.command, ServerCommand::Serve);
+
+        let args = vec!["server", "--port", "6000", "migrate"];
+        let config = ServerConfig::load_from(args, &env).unwrap();
+        assert_eq!((config.command, config.port), (ServerCommand::Migrate, 6000));
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let env = vars(&[("ACCOUNTANT_PORT", "http")]);
+        match ServerConfig::load_from(vec!["server"], &env) {
+            Err(ConfigError::Value(name, _)) => assert_eq!(name, "ACCOUNTANT_PORT"),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let args = vec!["server", "--pool-size", "0", "--database-url", "mysql://bank"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(problems.len(), 2),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let args = vec!["server", "--txn-max-attempts", "0", "--txn-initial-backoff-ms", "900"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(problems.len(), 2),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let args = vec!["server", "--idempotency-retention-secs", "0", "--hold-ttl-secs", "0"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(problems, vec![
+                "idempotency_retention_secs must be at least 1".to_string(),
+                "hold_ttl_secs must be at least 1".to_string(),
+            ]),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let args = vec!["server", "--hold-ttl-secs", "week"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Value(name, _)) => assert_eq!(name, "--hold-ttl-secs"),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let args = vec!["server", "--max-balance", "0"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(problems, vec![
+                "max_balance must be positive".to_string(),
+            ]),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert!(PartialConfig::from_toml("prot = 3000").is_err());
+    }
+
+    #[test]
+    fn test_sqlite_url() {
+        assert_eq!(ServerConfig::default().sqlite_path(), None);
+        let args = vec!["server", "--database-url", "sqlite:///var/lib/bank.db"];
+        let res = ServerConfig::load_from(args, &HashMap::new());
+        // only valid when the sqlite feature is on
+        if cfg!(feature = "sqlite") {
+            assert_eq!(res.unwrap().sqlite_path(), Some("/var/lib/bank.db"));
+        } else {
+            assert!(res.is_err());
+        }
+
+        let args = vec!["server", "--database-url", "sqlite:"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => {
+                assert_eq!(problems, vec!["database_url sqlite: needs a file path".to_string()])
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ledger_url() {
+        let args = vec!["server", "--database-url", "file:///var/lib/bank.log"];
+        let config = ServerConfig::load_from(args, &HashMap::new()).unwrap();
+        assert_eq!(config.ledger_path(), Some("/var/lib/bank.log"));
+        assert_eq!(config.ledger_durability(), Durability::Always);
+
+        let env = vars(&[("ACCOUNTANT_LEDGER_FSYNC", "interval")]);
+        let args = vec!["server", "--ledger-fsync-interval-ms", "250"];
+        let config = ServerConfig::load_from(args, &env).unwrap();
+        assert_eq!(config.ledger_durability(), Durability::Interval(Duration::from_millis(250)));
+
+        let args = vec!["server", "--database-url", "file:bank.log", "migrate"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(problems, vec![
+                "migrate needs a Postgres or CockroachDB database_url".to_string(),
+            ]),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let args = vec!["server", "--database-url", "file:", "--ledger-fsync", "sometimes"];
+        match ServerConfig::load_from(args, &HashMap::new()) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(problems, vec![
+                "database_url file: needs a file path".to_string(),
+                "ledger_fsync \"sometimes\" must be always, interval or never".to_string(),
+            ]),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,309 @@
+// Checks every DataStore must pass the same way, whatever it keeps its state in. A store's
+// tests run them with datastore_conformance!, which see.
+use std::collections::HashMap;
+use std::thread;
+
+use rand::Rng;
+
+use crate::idempotency::RequestRecord;
+use crate::proto::accounting::TransferComponent;
+use crate::transfer::TransferPlan;
+use crate::{DataStore, DataStoreError, LedgerReport, TransactionFilter};
+
+// Adds one #[test] per check to the enclosing module. `$fixture` is evaluated afresh for
+// each test and must deref to an empty store, e.g. a guard that removes its files on drop.
+// Attributes after it go on every test, so a store that needs a live database can pass
+// #[ignore].
+macro_rules! datastore_conformance {
+    ($fixture:expr $(, #[$attr:meta])*) => {
+        mod conformance {
+            use super::*;
+
+            $(#[$attr])*
+            #[test]
+            fn duplicate_account() {
+                crate::db::conformance::duplicate_account(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn missing_account() {
+                crate::db::conformance::missing_account(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn negative_balance() {
+                crate::db::conformance::negative_balance(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn atomic_transfer() {
+                crate::db::conformance::atomic_transfer(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn concurrent_transfers() {
+                crate::db::conformance::concurrent_transfers(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn verify_ledger() {
+                crate::db::conformance::verify_ledger(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn recorded_request() {
+                crate::db::conformance::recorded_request(&mut *$fixture);
+            }
+
+            $(#[$attr])*
+            #[test]
+            fn reset() {
+                crate::db::conformance::reset(&mut *$fixture);
+            }
+        }
+    };
+}
+
+fn plan(transfers: &[(u32, i64)]) -> TransferPlan {
+    let components: Vec<TransferComponent> = transfers
+        .iter()
+        .map(|&(account_id, amount)| {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(account_id);
+            comp.set_money_delta(amount);
+            comp
+        })
+        .collect();
+    TransferPlan::new(&components)
+}
+
+fn ledger<T: DataStore>(store: &mut T, account: u32) -> Option<i64> {
+    store.get_account_balance(account).unwrap().map(|balance| balance.ledger)
+}
+
+// (req_id, account_id, amount) of every recorded leg
+fn rows<T: DataStore>(store: &mut T) -> Vec<(u64, u32, i64)> {
+    let filter = TransactionFilter { limit: 100_000, ..Default::default() };
+    let mut rows: Vec<(u64, u32, i64)> = store
+        .list_transactions(&filter)
+        .unwrap()
+        .iter()
+        .map(|row| (row.get_req_id(), row.get_account_id(), row.get_amount()))
+        .collect();
+    rows.sort();
+    rows
+}
+
+pub fn duplicate_account<T: DataStore>(store: &mut T) {
+    assert_eq!(store.create_account(1, 1, 100), Ok(1));
+    assert_eq!(store.create_account(1, 2, 500), Err(DataStoreError::AccountExists(1)));
+    // the first opening balance stands
+    assert_eq!(ledger(store, 1), Some(100));
+}
+
+pub fn missing_account<T: DataStore>(store: &mut T) {
+    store.create_account(1, 1, 100).unwrap();
+    assert_eq!(store.get_account_balance(2), Ok(None));
+    assert_eq!(
+        store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 2),
+        Err(DataStoreError::AccountNotFound(2))
+    );
+    assert_eq!(
+        store.execute_transfers(&plan(&[(3, -10), (1, 10)]), 3),
+        Err(DataStoreError::AccountNotFound(3))
+    );
+    // components that cancel out still name an account that must exist
+    assert_eq!(
+        store.execute_transfers(&plan(&[(4, -10), (4, 10)]), 5),
+        Err(DataStoreError::AccountNotFound(4))
+    );
+    assert_eq!(ledger(store, 1), Some(100));
+    assert_eq!(store.reverse_transfer(2, 4), Err(DataStoreError::TransferNotFound(2)));
+    assert_eq!(store.reverse_transfer(5, 6), Err(DataStoreError::TransferNotFound(5)));
+
+    // and for an account that does, the entries are recorded without touching its balance
+    assert!(store.execute_transfers(&plan(&[(1, -10), (1, 10)]), 7).is_ok());
+    assert_eq!(ledger(store, 1), Some(100));
+    assert_eq!(rows(store), vec![(7, 1, -10), (7, 1, 10)]);
+}
+
+pub fn negative_balance<T: DataStore>(store: &mut T) {
+    assert_eq!(store.create_account(1, 1, -1), Err(DataStoreError::InvalidBalance(1, -1)));
+    assert_eq!(ledger(store, 1), None);
+
+    // a zero balance is allowed, going below it is not
+    store.create_account(1, 2, 0).unwrap();
+    store.create_account(2, 3, 50).unwrap();
+    assert_eq!(
+        store.execute_transfers(&plan(&[(1, -1), (2, 1)]), 4),
+        Err(DataStoreError::InsufficientFunds(1))
+    );
+    assert!(store.execute_transfers(&plan(&[(2, -50), (1, 50)]), 5).is_ok());
+    assert_eq!((ledger(store, 1), ledger(store, 2)), (Some(50), Some(0)));
+}
+
+pub fn atomic_transfer<T: DataStore>(store: &mut T) {
+    store.create_account(1, 1, 100).unwrap();
+    store.create_account(2, 2, 0).unwrap();
+    store.create_account(3, 3, 10).unwrap();
+
+    // the legs on 1 and 2 are fine, the one on 3 overdraws it
+    assert_eq!(
+        store.execute_transfers(&plan(&[(1, -40), (2, 60), (3, -20)]), 4),
+        Err(DataStoreError::InsufficientFunds(3))
+    );
+    // and a missing account fails the whole transfer too
+    assert_eq!(
+        store.execute_transfers(&plan(&[(1, -40), (2, 30), (9, 10)]), 5),
+        Err(DataStoreError::AccountNotFound(9))
+    );
+    let balances = (ledger(store, 1), ledger(store, 2), ledger(store, 3));
+    assert_eq!(balances, (Some(100), Some(0), Some(10)));
+    assert!(rows(store).is_empty());
+
+    assert!(store.execute_transfers(&plan(&[(1, -40), (2, 30), (3, 10)]), 6).is_ok());
+    let balances = (ledger(store, 1), ledger(store, 2), ledger(store, 3));
+    assert_eq!(balances, (Some(60), Some(30), Some(20)));
+    assert_eq!(rows(store), vec![(6, 1, -40), (6, 2, 30), (6, 3, 10)]);
+}
+
+// Random transfers from several threads, each on its own clone of the store. Transfers may
+// fail for lack of funds or by giving up on contention, but no money appears or disappears.
+pub fn concurrent_transfers<T>(store: &mut T)
+where
+    T: 'static + DataStore + Clone + Send,
+{
+    const ACCOUNTS: u32 = 5;
+    const THREADS: u64 = 4;
+    const TRANSFERS: u64 = 50;
+    for account in 1..=ACCOUNTS {
+        store.create_account(account, u64::from(account), 100).unwrap();
+    }
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let mut store = store.clone();
+            thread::spawn(move || {
+                let mut rng = rand::thread_rng();
+                let mut applied = Vec::new();
+                for i in 0..TRANSFERS {
+                    let from = rng.gen_range(1, ACCOUNTS + 1);
+                    let to = from % ACCOUNTS + 1;
+                    let amount = rng.gen_range(1, 60);
+                    let req_id = ((t + 1) * 1_000 + i) as i64;
+                    let transfer = plan(&[(from, -amount), (to, amount)]);
+                    match store.execute_transfers(&transfer, req_id) {
+                        Ok(()) => applied.push(req_id as u64),
+                        Err(DataStoreError::InsufficientFunds(_))
+                        | Err(DataStoreError::SerializationConflict)
+                        | Err(DataStoreError::Contention(_)) => {}
+                        Err(err) => panic!("transfer {} failed: {:?}", req_id, err),
+                    }
+                }
+                applied
+            })
+        })
+        .collect();
+    let mut applied: Vec<u64> = handles.into_iter().flat_map(|h| h.join().unwrap()).collect();
+    applied.sort();
+    assert!(!applied.is_empty());
+
+    let balances: Vec<i64> = (1..=ACCOUNTS).map(|id| ledger(store, id).unwrap()).collect();
+    assert!(balances.iter().all(|&balance| balance >= 0), "{:?}", balances);
+    assert_eq!(balances.iter().sum::<i64>(), i64::from(ACCOUNTS) * 100);
+
+    // every applied transfer left both of its legs and failed ones left none
+    let mut legs: HashMap<u64, i64> = HashMap::new();
+    let rows = rows(store);
+    for &(req_id, _, amount) in &rows {
+        *legs.entry(req_id).or_insert(0) += amount;
+    }
+    assert_eq!(rows.len(), applied.len() * 2);
+    let mut recorded: Vec<u64> = legs.keys().cloned().collect();
+    recorded.sort();
+    assert_eq!(recorded, applied);
+    assert!(legs.values().all(|&sum| sum == 0));
+}
+
+// Every way of moving money leaves balances that add up from the ledger, opening balances
+// included, without opening entries showing up as transfers
+pub fn verify_ledger<T: DataStore>(store: &mut T) {
+    let empty = LedgerReport::default();
+    assert_eq!(store.verify_ledger(false), Ok(empty));
+
+    store.create_account(1, 1, 100).unwrap();
+    store.create_account(2, 2, 0).unwrap();
+    store.create_account(3, 3, 25).unwrap();
+    store.execute_transfers(&plan(&[(1, -40), (2, 30), (3, 10)]), 4).unwrap();
+    store.execute_transfers(&plan(&[(3, -5), (1, 5)]), 5).unwrap();
+    store.reverse_transfer(5, 6).unwrap();
+    // a CreateAccount is not a transfer that can be reversed
+    assert_eq!(store.reverse_transfer(1, 7), Err(DataStoreError::TransferNotFound(1)));
+
+    let clean = LedgerReport { accounts: 3, ..LedgerReport::default() };
+    assert_eq!(store.verify_ledger(false), Ok(clean.clone()));
+    assert_eq!(store.verify_ledger(true), Ok(clean));
+    let balances = (ledger(store, 1), ledger(store, 2), ledger(store, 3));
+    assert_eq!(balances, (Some(60), Some(30), Some(35)));
+    assert_eq!(rows(store).len(), 7);
+}
+
+fn request(req_id: u64, fingerprint: &[u8], reply: &[u8]) -> Option<RequestRecord> {
+    Some(RequestRecord { req_id, fingerprint: fingerprint.to_vec(), reply: reply.to_vec() })
+}
+
+// A recorded req_id is answered with its stored reply or refused, and changes nothing
+pub fn recorded_request<T: DataStore>(store: &mut T) {
+    store.set_request(request(1, b"create 1", b"reply 1"));
+    store.create_account(1, 1, 100).unwrap();
+    store.set_request(request(2, b"create 2", b"reply 2"));
+    store.create_account(2, 2, 0).unwrap();
+    store.set_request(request(3, b"transfer", b"reply 3"));
+    store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 3).unwrap();
+
+    store.set_request(request(3, b"transfer", b"other reply"));
+    let replayed = store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 3);
+    assert_eq!(replayed, Err(DataStoreError::RequestReplayed(b"reply 3".to_vec())));
+    store.set_request(request(3, b"another transfer", b"other reply"));
+    let reused = store.execute_transfers(&plan(&[(1, -20), (2, 20)]), 3);
+    assert_eq!(reused, Err(DataStoreError::RequestIdReused(3)));
+    // a CreateAccount under a transfer's req_id is a different request too
+    store.set_request(request(3, b"create 3", b"reply 3"));
+    assert_eq!(store.create_account(3, 3, 0), Err(DataStoreError::RequestIdReused(3)));
+    store.set_request(request(1, b"create 1", b"reply 1"));
+    let replayed = store.create_account(1, 1, 100);
+    assert_eq!(replayed, Err(DataStoreError::RequestReplayed(b"reply 1".to_vec())));
+
+    let balances = (ledger(store, 1), ledger(store, 2), ledger(store, 3));
+    assert_eq!(balances, (Some(90), Some(10), None));
+    assert_eq!(rows(store), vec![(3, 1, -10), (3, 2, 10)]);
+    // a failed request is not recorded, so it can be retried
+    store.set_request(request(4, b"overdraw", b"reply 4"));
+    let overdraw = store.execute_transfers(&plan(&[(2, -50), (1, 50)]), 4);
+    assert_eq!(overdraw, Err(DataStoreError::InsufficientFunds(2)));
+    store.set_request(request(4, b"overdraw", b"reply 4"));
+    assert_eq!(store.execute_transfers(&plan(&[(2, -5), (1, 5)]), 4), Ok(()));
+}
+
+pub fn reset<T: DataStore>(store: &mut T) {
+    store.set_request(request(1, b"create 1", b"reply 1"));
+    store.create_account(1, 1, 100).unwrap();
+    store.create_account(2, 2, 0).unwrap();
+    store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 3).unwrap();
+
+    assert_eq!(store.reset(), Ok(()));
+    assert_eq!((ledger(store, 1), ledger(store, 2)), (None, None));
+    assert!(rows(store).is_empty());
+    // ids and req_ids can be used again
+    store.set_request(request(1, b"create 1 again", b"reply 1"));
+    assert_eq!(store.create_account(1, 1, 5), Ok(1));
+    store.create_account(2, 2, 0).unwrap();
+    assert!(store.execute_transfers(&plan(&[(1, -5), (2, 5)]), 3).is_ok());
+    assert_eq!(rows(store), vec![(3, 1, -5), (3, 2, 5)]);
+}
@@ -0,0 +1,883 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::db::memory::{Change, Commit, MemoryState};
+use crate::idempotency::RequestRecord;
+use crate::proto::accounting::{TransactionRecord, TransferComponent};
+use crate::transfer::{JournalEntry, TransferPlan};
+use crate::{now_millis, AccountBalance, DataStore, DataStoreError, Hold, HoldStatus};
+use crate::{LedgerReport, TransactionFilter};
+
+pub type FileResult<T> = Result<T, DataStoreError>;
+
+// Both files start with a magic and the generation of the log, then hold frames of a
+// little endian payload length, the crc32 of the payload and the payload
+const LOG_MAGIC: &[u8; 8] = b"ACCTLOG1";
+const SNAPSHOT_MAGIC: &[u8; 8] = b"ACCTSNP1";
+const HEADER_LEN: usize = 16;
+const FRAME_LEN: usize = 8;
+
+// When appended records are fsynced
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Durability {
+    // before the call returns, so nothing acknowledged is lost
+    Always,
+    // by the first commit after the interval, or by FileLedgerDataStore::sync. A power loss
+    // can lose the commits of the last interval, a crash of the process loses none.
+    Interval(Duration),
+    // only on snapshots and sync, the OS writes the rest back when it likes
+    Never,
+}
+
+struct Ledger {
+    path: PathBuf,
+    state: MemoryState,
+    log: File,
+    // the snapshot covers every earlier generation of the log
+    generation: u64,
+    durability: Durability,
+    unsynced: bool,
+    last_sync: Instant,
+    // records in the current log, a snapshot is taken once there are snapshot_every
+    records: u64,
+    snapshot_every: u64,
+    // why writes are refused; the file may be missing a record the state has or the
+    // other way round, so only reopening it can tell
+    failed: Option<String>,
+}
+
+// Single node store with no database: the state lives in memory and every call that
+// changes it is appended to a write-ahead log first as one checksummed record.
+// Opening loads the last snapshot and replays the log on top of it.
+#[derive(Clone)]
+pub struct FileLedgerDataStore {
+    ledger: Arc<Mutex<Ledger>>,
+    // None allows any balance that fits in an i64
+    max_balance: Option<i64>,
+    // recorded with the next create or transfer
+    request: Option<RequestRecord>,
+}
+
+impl FileLedgerDataStore {
+    // Creates the log if it doesn't exist. A record torn by a crash during its append is
+    // cut off, any other damage fails with Internal.
+    pub fn open(path: &str) -> FileResult<FileLedgerDataStore> {
+        let path = PathBuf::from(path);
+        let mut state = MemoryState::default();
+        let covered = match read_snapshot(&snapshot_path(&path))? {
+            Some((generation, changes)) => {
+                changes.iter().for_each(|change| state.apply(change));
+                generation
+            }
+            None => 0,
+        };
+
+        let generation = covered + 1;
+        let (log, records) = match fs::read(&path) {
+            Ok(bytes) => {
+                let log_generation = read_header(&bytes, LOG_MAGIC).ok_or_else(|| {
+                    DataStoreError::Internal(format!("{} Is Not A Ledger", path.display()))
+                })?;
+                if log_generation <= covered {
+                    // the snapshot was written but the log not yet replaced
+                    (create_log(&path, generation).map_err(unavailable)?, 0)
+                } else if log_generation > generation {
+                    return Err(DataStoreError::Internal(format!(
+                        "Ledger {} Is Generation {} But Its Snapshot Covers {}",
+                        path.display(),
+                        log_generation,
+                        covered
+                    )));
+                } else {
+                    let (records, end) = read_records(&bytes[HEADER_LEN..], HEADER_LEN)?;
+                    for changes in &records {
+                        changes.iter().for_each(|change| state.apply(change));
+                    }
+                    let log = OpenOptions::new().append(true).open(&path).map_err(unavailable)?;
+                    if HEADER_LEN + end < bytes.len() {
+                        let len = (HEADER_LEN + end) as u64;
+                        log.set_len(len).and_then(|_| log.sync_all()).map_err(unavailable)?;
+                    }
+                    (log, records.len() as u64)
+                }
+            }
+            Err(ref err) if err.kind() == ErrorKind::NotFound => {
+                (create_log(&path, generation).map_err(unavailable)?, 0)
+            }
+            Err(err) => return Err(unavailable(err)),
+        };
+
+        let ledger = Ledger {
+            path,
+            state,
+            log,
+            generation,
+            durability: Durability::Always,
+            unsynced: false,
+            last_sync: Instant::now(),
+            records,
+            snapshot_every: 0,
+            failed: None,
+        };
+        Ok(FileLedgerDataStore {
+            ledger: Arc::new(Mutex::new(ledger)),
+            max_balance: None,
+            request: None,
+        })
+    }
+
+    pub fn with_durability(self, durability: Durability) -> FileLedgerDataStore {
+        self.lock().durability = durability;
+        self
+    }
+
+    // Snapshots after every `records` appends, 0 only snapshots on request
+    pub fn with_snapshot_every(self, records: u64) -> FileLedgerDataStore {
+        self.lock().snapshot_every = records;
+        self
+    }
+
+    // Fsyncs whatever was appended since the last sync
+    pub fn sync(&self) -> FileResult<()> {
+        let mut ledger = self.lock();
+        ledger.check_writable()?;
+        let res = ledger.sync();
+        ledger.fail_on(res)
+    }
+
+    // Writes the state to the snapshot file and starts an empty log
+    pub fn snapshot(&self) -> FileResult<()> {
+        let mut ledger = self.lock();
+        ledger.check_writable()?;
+        ledger.snapshot().map_err(unavailable)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Ledger> {
+        self.ledger.lock().unwrap()
+    }
+
+    fn commit<T, F>(&self, op: F) -> FileResult<T>
+    where
+        F: FnOnce(&MemoryState) -> FileResult<Commit<T>>,
+    {
+        let mut ledger = self.lock();
+        ledger.check_writable()?;
+        let (changes, res) = op(&ledger.state)?;
+        if changes.is_empty() {
+            return Ok(res);
+        }
+        let appended = ledger.append(&changes);
+        ledger.fail_on(appended)?;
+        changes.iter().for_each(|change| ledger.state.apply(change));
+
+        ledger.records += 1;
+        if ledger.snapshot_every > 0 && ledger.records >= ledger.snapshot_every {
+            // the call already committed; a snapshot that fails before the log is replaced
+            // is tried again after another snapshot_every records
+            if ledger.snapshot().is_err() {
+                ledger.records = 0;
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl Ledger {
+    fn check_writable(&self) -> FileResult<()> {
+        match self.failed {
+            Some(ref reason) => Err(DataStoreError::Unavailable(format!(
+                "Ledger Write Failed, Reopen To Recover: {}",
+                reason
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    // Refuses every later write once the file may no longer match the state
+    fn fail_on<T>(&mut self, res: io::Result<T>) -> FileResult<T> {
+        res.map_err(|err| {
+            self.failed = Some(err.to_string());
+            DataStoreError::Unavailable(format!("Could Not Write Ledger: {}", err))
+        })
+    }
+
+    fn append(&mut self, changes: &[Change]) -> io::Result<()> {
+        let mut payload = Vec::new();
+        changes.iter().for_each(|change| encode_change(&mut payload, change));
+        let mut record = Vec::with_capacity(FRAME_LEN + payload.len());
+        frame(&mut record, &payload);
+        self.log.write_all(&record)?;
+        self.unsynced = true;
+        match self.durability {
+            Durability::Always => self.sync(),
+            Durability::Interval(interval) if self.last_sync.elapsed() >= interval => self.sync(),
+            _ => Ok(()),
+        }
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        if self.unsynced {
+            self.log.sync_data()?;
+            self.unsynced = false;
+        }
+        self.last_sync = Instant::now();
+        Ok(())
+    }
+
+    fn snapshot(&mut self) -> io::Result<()> {
+        let mut payload = Vec::new();
+        self.state.compact().iter().for_each(|change| encode_change(&mut payload, change));
+        let mut bytes = header(SNAPSHOT_MAGIC, self.generation);
+        frame(&mut bytes, &payload);
+        write_atomically(&snapshot_path(&self.path), &bytes)?;
+
+        // from here the snapshot covers the log, so appending to it would lose records
+        let log = create_log(&self.path, self.generation + 1);
+        if let Err(ref err) = log {
+            self.failed = Some(err.to_string());
+        }
+        self.log = log?;
+        self.generation += 1;
+        self.records = 0;
+        self.unsynced = false;
+        self.last_sync = Instant::now();
+        Ok(())
+    }
+}
+
+fn unavailable(err: io::Error) -> DataStoreError {
+    DataStoreError::Unavailable(format!("Ledger I/O Failed: {}", err))
+}
+
+fn snapshot_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".snapshot");
+    PathBuf::from(name)
+}
+
+fn header(magic: &[u8; 8], generation: u64) -> Vec<u8> {
+    let mut bytes = magic.to_vec();
+    bytes.extend_from_slice(&generation.to_le_bytes());
+    bytes
+}
+
+fn read_header(bytes: &[u8], magic: &[u8; 8]) -> Option<u64> {
+    if bytes.len() < HEADER_LEN || &bytes[..8] != magic {
+        return None;
+    }
+    Decoder::new(&bytes[8..HEADER_LEN]).u64()
+}
+
+fn frame(out: &mut Vec<u8>, payload: &[u8]) {
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(&crc32fast::hash(payload).to_le_bytes());
+    out.extend_from_slice(payload);
+}
+
+// Writes a file that is either complete or absent after a crash
+fn write_atomically(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let mut file = File::create(&tmp)?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)?;
+    // the rename itself is only durable once the directory is synced
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()
+}
+
+fn create_log(path: &Path, generation: u64) -> io::Result<File> {
+    write_atomically(path, &header(LOG_MAGIC, generation))?;
+    OpenOptions::new().append(true).open(path)
+}
+
+fn read_snapshot(path: &Path) -> FileResult<Option<(u64, Vec<Change>)>> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(ref err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(unavailable(err)),
+    };
+    // snapshots are renamed into place whole, so a bad one was damaged after it was written
+    let corrupt = || DataStoreError::Internal(format!("Snapshot {} Is Corrupt", path.display()));
+    let generation = read_header(&bytes, SNAPSHOT_MAGIC).ok_or_else(corrupt)?;
+    match read_records(&bytes[HEADER_LEN..], HEADER_LEN) {
+        Ok((mut records, end)) if records.len() == 1 && HEADER_LEN + end == bytes.len() => {
+            Ok(Some((generation, records.remove(0))))
+        }
+        _ => Err(corrupt()),
+    }
+}
+
+// Returns the changes of each record and the length of the intact prefix. A torn append
+// leaves a short or bad last record, or one followed by nothing but zeros where the file
+// system extended the file before the data reached it. A bad record followed by data fails.
+fn read_records(bytes: &[u8], offset: usize) -> FileResult<(Vec<Vec<Change>>, usize)> {
+    let mut records = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let rest = &bytes[pos..];
+        let corrupt = || {
+            DataStoreError::Internal(format!("Ledger Record At Offset {} Is Corrupt", offset + pos))
+        };
+        let mut decoder = Decoder::new(rest);
+        let (len, crc) = match (decoder.u32(), decoder.u32()) {
+            (Some(len), Some(crc)) => (len as usize, crc),
+            _ => break,
+        };
+        if rest.len() - FRAME_LEN < len {
+            // the crc doesn't cover the length, so a damaged one can run past records that
+            // were committed after it; only a record with none after it was torn
+            if contains_record(&rest[FRAME_LEN..]) {
+                return Err(corrupt());
+            }
+            break;
+        }
+        let payload = &rest[FRAME_LEN..FRAME_LEN + len];
+        if len == 0 || crc32fast::hash(payload) != crc {
+            if FRAME_LEN + len == rest.len() || rest.iter().all(|&b| b == 0) {
+                break;
+            }
+            return Err(corrupt());
+        }
+        match decode_changes(payload) {
+            Some(changes) => records.push(changes),
+            None => {
+                return Err(DataStoreError::Internal(format!(
+                    "Ledger Record At Offset {} Is Unreadable",
+                    offset + pos
+                )))
+            }
+        }
+        pos += FRAME_LEN + len;
+    }
+    Ok((records, pos))
+}
+
+// Whether an intact record starts anywhere in `bytes`
+fn contains_record(bytes: &[u8]) -> bool {
+    (0..bytes.len()).any(|start| {
+        let rest = &bytes[start..];
+        let mut decoder = Decoder::new(rest);
+        match (decoder.u32(), decoder.u32()) {
+            (Some(len), Some(crc)) => {
+                let len = len as usize;
+                len > 0
+                    && rest.len() - FRAME_LEN >= len
+                    && crc32fast::hash(&rest[FRAME_LEN..FRAME_LEN + len]) == crc
+                    && decode_changes(&rest[FRAME_LEN..FRAME_LEN + len]).is_some()
+            }
+            _ => false,
+        }
+    })
+}
+
+fn encode_change(out: &mut Vec<u8>, change: &Change) {
+    match change {
+        Change::CreateAccount { account_id, req_id, balance, created_at } => {
+            out.push(1);
+            out.extend_from_slice(&account_id.to_le_bytes());
+            out.extend_from_slice(&req_id.to_le_bytes());
+            out.extend_from_slice(&balance.to_le_bytes());
+            out.extend_from_slice(&created_at.to_le_bytes());
+        }
+        Change::Transfer { req_id, created_at, entries } => {
+            out.push(2);
+            out.extend_from_slice(&req_id.to_le_bytes());
+            out.extend_from_slice(&created_at.to_le_bytes());
+            out.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+            for entry in entries {
+                out.extend_from_slice(&entry.transaction_index.to_le_bytes());
+                out.extend_from_slice(&entry.account_id.to_le_bytes());
+                out.extend_from_slice(&entry.amount.to_le_bytes());
+            }
+        }
+        Change::PutHold(hold) => {
+            out.push(3);
+            out.extend_from_slice(&hold.id.to_le_bytes());
+            out.extend_from_slice(&hold.account_id.to_le_bytes());
+            out.extend_from_slice(&hold.to_account_id.to_le_bytes());
+            out.extend_from_slice(&hold.amount.to_le_bytes());
+            out.extend_from_slice(&hold.captured.to_le_bytes());
+            out.push(match hold.status {
+                HoldStatus::Active => 0,
+                HoldStatus::Captured => 1,
+                HoldStatus::Voided => 2,
+            });
+            out.extend_from_slice(&hold.expires_at.to_le_bytes());
+        }
+        Change::Reverse { original_req_id, req_id } => {
+            out.push(4);
+            out.extend_from_slice(&original_req_id.to_le_bytes());
+            out.extend_from_slice(&req_id.to_le_bytes());
+        }
+        Change::Reset => out.push(5),
+        Change::SetBalance { account_id, balance } => {
+            out.push(6);
+            out.extend_from_slice(&account_id.to_le_bytes());
+            out.extend_from_slice(&balance.to_le_bytes());
+        }
+        Change::Request(request) => {
+            out.push(7);
+            out.extend_from_slice(&request.req_id.to_le_bytes());
+            for bytes in &[&request.fingerprint, &request.reply] {
+                out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+                out.extend_from_slice(bytes);
+            }
+        }
+    }
+}
+
+fn decode_changes(payload: &[u8]) -> Option<Vec<Change>> {
+    let mut decoder = Decoder::new(payload);
+    let mut changes = Vec::new();
+    while !decoder.is_empty() {
+        let change = match decoder.u8()? {
+            1 => Change::CreateAccount {
+                account_id: decoder.u32()?,
+                req_id: decoder.u64()?,
+                balance: decoder.i64()?,
+                created_at: decoder.i64()?,
+            },
+            2 => {
+                let (req_id, created_at) = (decoder.i64()?, decoder.i64()?);
+                let count = decoder.u32()?;
+                let mut entries = Vec::new();
+                for _ in 0..count {
+                    entries.push(JournalEntry {
+                        transaction_index: decoder.u32()?,
+                        account_id: decoder.u32()?,
+                        amount: decoder.i64()?,
+                    });
+                }
+                Change::Transfer { req_id, created_at, entries }
+            }
+            3 => Change::PutHold(Hold {
+                id: decoder.u64()?,
+                account_id: decoder.u32()?,
+                to_account_id: decoder.u32()?,
+                amount: decoder.i64()?,
+                captured: decoder.i64()?,
+                status: match decoder.u8()? {
+                    0 => HoldStatus::Active,
+                    1 => HoldStatus::Captured,
+                    2 => HoldStatus::Voided,
+                    _ => return None,
+                },
+                expires_at: decoder.i64()?,
+            }),
+            4 => Change::Reverse { original_req_id: decoder.i64()?, req_id: decoder.i64()? },
+            5 => Change::Reset,
+            6 => Change::SetBalance { account_id: decoder.u32()?, balance: decoder.i64()? },
+            7 => Change::Request(RequestRecord {
+                req_id: decoder.u64()?,
+                fingerprint: decoder.bytes()?,
+                reply: decoder.bytes()?,
+            }),
+            _ => return None,
+        };
+        changes.push(change);
+    }
+    Some(changes)
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Decoder<'a> {
+        Decoder { bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Some(u32::from_le_bytes(buf))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Some(u64::from_le_bytes(buf))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        self.u64().map(|n| n as i64)
+    }
+
+    // a u32 length and that many bytes
+    fn bytes(&mut self) -> Option<Vec<u8>> {
+        let len = self.u32()? as usize;
+        self.take(len).map(<[u8]>::to_vec)
+    }
+}
+
+impl DataStore for FileLedgerDataStore {
+    fn create_account(&mut self, account: u32, req_id: u64, balance: i64) -> FileResult<u64> {
+        let request = self.request.take();
+        self.commit(|state| state.create_account(account, req_id, balance, request))
+    }
+
+    fn get_account_balance(&mut self, account: u32) -> FileResult<Option<AccountBalance>> {
+        Ok(self.lock().state.balance(account, now_millis()))
+    }
+
+    fn execute_transfers(&mut self, plan: &TransferPlan, req_id: i64) -> FileResult<()> {
+        let (max_balance, request) = (self.max_balance, self.request.take());
+        self.commit(|state| state.transfer(plan, req_id, max_balance, request))
+    }
+
+    fn authorize_transfer(&mut self, hold: &Hold) -> FileResult<()> {
+        self.commit(|state| state.authorize(hold))
+    }
+
+    fn capture_transfer(
+        &mut self,
+        hold_id: u64,
+        amount: Option<i64>,
+        req_id: i64,
+    ) -> FileResult<Hold> {
+        let max_balance = self.max_balance;
+        self.commit(|state| state.capture(hold_id, amount, req_id, max_balance))
+    }
+
+    fn void_transfer(&mut self, hold_id: u64) -> FileResult<Hold> {
+        self.commit(|state| state.void(hold_id))
+    }
+
+    fn reverse_transfer(
+        &mut self,
+        original_req_id: i64,
+        req_id: i64,
+    ) -> FileResult<Vec<TransferComponent>> {
+        let max_balance = self.max_balance;
+        self.commit(|state| state.reverse(original_req_id, req_id, max_balance))
+    }
+
+    fn list_transactions(
+        &mut self,
+        filter: &TransactionFilter,
+    ) -> FileResult<Vec<TransactionRecord>> {
+        Ok(self.lock().state.list_transactions(filter))
+    }
+
+    fn reset(&mut self) -> FileResult<()> {
+        self.commit(|_| Ok((vec![Change::Reset], ())))
+    }
+
+    fn verify_ledger(&mut self, rebuild: bool) -> FileResult<LedgerReport> {
+        if !rebuild {
+            // only reads, so it works on a ledger that refuses writes too
+            return Ok(self.lock().state.verify_ledger(false)?.1);
+        }
+        self.commit(|state| state.verify_ledger(true))
+    }
+
+    fn set_request(&mut self, request: Option<RequestRecord>) {
+        self.request = request;
+    }
+
+    fn set_max_balance(&mut self, max_balance: i64) {
+        self.max_balance = Some(max_balance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::ops::{Deref, DerefMut};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::LedgerMismatch;
+
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+    // A ledger path of its own, removed with its snapshot when dropped
+    struct TempLedger {
+        path: String,
+    }
+
+    impl TempLedger {
+        fn new() -> TempLedger {
+            let n = NEXT_FILE.fetch_add(1, Ordering::SeqCst);
+            let path = env::temp_dir().join(format!("accountant-{}-{}.log", std::process::id(), n));
+            TempLedger { path: path.to_string_lossy().into_owned() }
+        }
+
+        fn open(&self) -> FileLedgerDataStore {
+            FileLedgerDataStore::open(&self.path).unwrap()
+        }
+    }
+
+    impl Drop for TempLedger {
+        fn drop(&mut self) {
+            for suffix in &["", ".tmp", ".snapshot", ".snapshot.tmp"] {
+                let _ = fs::remove_file(format!("{}{}", self.path, suffix));
+            }
+        }
+    }
+
+    // A store open on a TempLedger, dropped before the files are removed
+    struct TempStore {
+        store: FileLedgerDataStore,
+        _temp: TempLedger,
+    }
+
+    impl TempStore {
+        fn new() -> TempStore {
+            let temp = TempLedger::new();
+            TempStore { store: temp.open(), _temp: temp }
+        }
+    }
+
+    impl Deref for TempStore {
+        type Target = FileLedgerDataStore;
+
+        fn deref(&self) -> &FileLedgerDataStore {
+            &self.store
+        }
+    }
+
+    impl DerefMut for TempStore {
+        fn deref_mut(&mut self) -> &mut FileLedgerDataStore {
+            &mut self.store
+        }
+    }
+
+    datastore_conformance!(TempStore::new());
+
+    fn plan(transfers: &[(u32, i64)]) -> TransferPlan {
+        let components: Vec<TransferComponent> = transfers
+            .iter()
+            .map(|&(account_id, amount)| {
+                let mut comp = TransferComponent::new();
+                comp.set_account_id(account_id);
+                comp.set_money_delta(amount);
+                comp
+            })
+            .collect();
+        TransferPlan::new(&components)
+    }
+
+    fn ledger(store: &mut FileLedgerDataStore, account: u32) -> Option<i64> {
+        store.get_account_balance(account).unwrap().map(|balance| balance.ledger)
+    }
+
+    fn all(store: &mut FileLedgerDataStore) -> Vec<TransactionRecord> {
+        let filter = TransactionFilter { limit: 1_000, ..Default::default() };
+        store.list_transactions(&filter).unwrap()
+    }
+
+    #[test]
+    fn test_reopen() {
+        let temp = TempLedger::new();
+        let mut store = temp.open();
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 3).unwrap();
+        store.reverse_transfer(3, 4).unwrap();
+        let hold = Hold {
+            id: 5,
+            account_id: 1,
+            to_account_id: 2,
+            amount: 40,
+            captured: 0,
+            status: HoldStatus::Active,
+            expires_at: now_millis() + 60_000,
+        };
+        store.authorize_transfer(&hold).unwrap();
+        store.capture_transfer(5, Some(25), 6).unwrap();
+        store.authorize_transfer(&Hold { id: 7, amount: 10, ..hold }).unwrap();
+        assert_eq!(store.create_account(1, 8, 0), Err(DataStoreError::AccountExists(1)));
+        let rows = all(&mut store);
+        drop(store);
+
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 1), Some(75));
+        assert_eq!(ledger(&mut store, 2), Some(25));
+        assert_eq!(store.get_account_balance(1).unwrap().unwrap().available, 65);
+        assert_eq!(all(&mut store), rows);
+        assert_eq!(store.reverse_transfer(3, 9), Err(DataStoreError::AlreadyReversed(3)));
+        assert_eq!(store.capture_transfer(5, None, 9), Err(DataStoreError::HoldNotActive(5)));
+
+        // a reset is logged like any other write
+        store.reset().unwrap();
+        drop(store);
+        assert_eq!(ledger(&mut temp.open(), 1), None);
+    }
+
+    #[test]
+    fn test_verify_ledger() {
+        let temp = TempLedger::new();
+        let mut store = temp.open();
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 3).unwrap();
+        // a logged balance the rows don't add up to, as a bug might have written
+        let tamper = Change::SetBalance { account_id: 2, balance: 45 };
+        store.commit(|_| Ok((vec![tamper], ()))).unwrap();
+        store.snapshot().unwrap();
+        drop(store);
+
+        let mut store = temp.open();
+        let mismatch = LedgerMismatch { account_id: 2, stored: 45, projected: 30 };
+        let found = LedgerReport {
+            accounts: 2,
+            mismatches: vec![mismatch],
+            backfilled: Vec::new(),
+        };
+        assert_eq!(store.verify_ledger(false), Ok(found.clone()));
+        // a verify that changes nothing isn't logged
+        let len = fs::metadata(&temp.path).unwrap().len();
+        assert_eq!(len, HEADER_LEN as u64);
+
+        assert_eq!(store.verify_ledger(true), Ok(found));
+        assert!(fs::metadata(&temp.path).unwrap().len() > len);
+        drop(store);
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 2), Some(30));
+        assert!(store.verify_ledger(false).unwrap().mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_torn_record() {
+        let temp = TempLedger::new();
+        let mut store = temp.open().with_durability(Durability::Never);
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        let intact = fs::metadata(&temp.path).unwrap().len();
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 3).unwrap();
+        drop(store);
+
+        // the last append only got part of the way to disk
+        let full = fs::metadata(&temp.path).unwrap().len();
+        let file = OpenOptions::new().write(true).open(&temp.path).unwrap();
+        file.set_len(full - 3).unwrap();
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 1), Some(100));
+        assert!(all(&mut store).is_empty());
+        assert_eq!(fs::metadata(&temp.path).unwrap().len(), intact);
+
+        // or the file grew but the record was never written
+        store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 4).unwrap();
+        drop(store);
+        let len = fs::metadata(&temp.path).unwrap().len();
+        file.set_len(len + 64).unwrap();
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 2), Some(10));
+        store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 5).unwrap();
+        drop(store);
+        assert_eq!(ledger(&mut temp.open(), 2), Some(20));
+    }
+
+    #[test]
+    fn test_corrupt_record() {
+        let temp = TempLedger::new();
+        let mut store = temp.open();
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        drop(store);
+
+        // damage before the last record is not a torn append
+        let mut bytes = fs::read(&temp.path).unwrap();
+        bytes[HEADER_LEN + FRAME_LEN + 2] ^= 0xff;
+        fs::write(&temp.path, &bytes).unwrap();
+        match FileLedgerDataStore::open(&temp.path) {
+            Err(DataStoreError::Internal(reason)) => {
+                assert_eq!(reason, format!("Ledger Record At Offset {} Is Corrupt", HEADER_LEN))
+            }
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_corrupt_length() {
+        let temp = TempLedger::new();
+        let mut store = temp.open();
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        let last = fs::metadata(&temp.path).unwrap().len() as usize;
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 3).unwrap();
+        drop(store);
+        let intact = fs::read(&temp.path).unwrap();
+
+        // a length past the end of the file reads like a torn append, but the records after
+        // it show it isn't one
+        let mut bytes = intact.clone();
+        bytes[HEADER_LEN + 3] = 0x7f;
+        fs::write(&temp.path, &bytes).unwrap();
+        match FileLedgerDataStore::open(&temp.path) {
+            Err(DataStoreError::Internal(reason)) => {
+                assert_eq!(reason, format!("Ledger Record At Offset {} Is Corrupt", HEADER_LEN))
+            }
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+        assert_eq!(fs::read(&temp.path).unwrap(), bytes);
+
+        // while the last record's is still cut off as torn
+        let mut bytes = intact;
+        bytes[last + 3] = 0x7f;
+        fs::write(&temp.path, &bytes).unwrap();
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 1), Some(100));
+        assert!(all(&mut store).is_empty());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let temp = TempLedger::new();
+        let mut store = temp.open().with_snapshot_every(3);
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 3).unwrap();
+        // the snapshot left an empty log of the next generation
+        assert_eq!(fs::metadata(&temp.path).unwrap().len(), HEADER_LEN as u64);
+        store.execute_transfers(&plan(&[(1, -20), (2, 20)]), 4).unwrap();
+        let log = fs::read(&temp.path).unwrap();
+        let rows = all(&mut store);
+        drop(store);
+
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 1), Some(50));
+        assert_eq!(all(&mut store), rows);
+        store.snapshot().unwrap();
+        drop(store);
+
+        // a crash after the snapshot was renamed but before the log was replaced must not
+        // apply the log twice
+        fs::write(&temp.path, &log).unwrap();
+        let mut store = temp.open();
+        assert_eq!(ledger(&mut store, 1), Some(50));
+        assert_eq!(all(&mut store), rows);
+        drop(store);
+
+        // a log newer than the snapshot after it means records are missing
+        fs::write(&temp.path, header(LOG_MAGIC, 9)).unwrap();
+        assert!(FileLedgerDataStore::open(&temp.path).is_err());
+    }
+}
@@ -0,0 +1,876 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::idempotency::RequestRecord;
+use crate::proto::accounting::{TransactionRecord, TransferComponent};
+use crate::transfer::{JournalEntry, TransferPlan};
+use crate::{now_millis, AccountBalance, DataStore, DataStoreError, Hold, HoldStatus};
+use crate::{LedgerMismatch, LedgerReport, TransactionFilter};
+
+pub type MemoryResult<T> = Result<T, DataStoreError>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Account {
+    pub balance: i64,
+    pub creation_request: u64,
+    pub created_at: i64,
+}
+
+// What put a row in the ledger, the `kind` column of the `transactions` table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EntryKind {
+    // the opening balance of an account, recorded under its creation request
+    Opening,
+    Transfer,
+}
+
+// One row of the `transactions` table
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransactionEntry {
+    pub id: u64,
+    pub transaction_index: u32,
+    pub req_id: i64,
+    pub account_id: u32,
+    pub amount: i64,
+    // milliseconds since the unix epoch
+    pub created_at: i64,
+    pub kind: EntryKind,
+}
+
+impl TransactionEntry {
+    pub fn to_record(&self) -> TransactionRecord {
+        let mut record = TransactionRecord::new();
+        record.set_id(self.id);
+        record.set_req_id(self.req_id as u64);
+        record.set_account_id(self.account_id);
+        record.set_amount(self.amount);
+        record.set_transaction_index(self.transaction_index);
+        record.set_created_at(self.created_at);
+        record
+    }
+
+    // the leg that undoes this one
+    pub fn reversed(&self) -> TransferComponent {
+        let mut comp = TransferComponent::new();
+        comp.set_account_id(self.account_id);
+        comp.set_money_delta(-self.amount);
+        comp
+    }
+}
+
+// A checked write, applied without further checks. Every call that succeeds turns into a
+// list of these, which FileLedgerDataStore logs before applying.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    // a non-zero balance is recorded as an opening row
+    CreateAccount { account_id: u32, req_id: u64, balance: i64, created_at: i64 },
+    // rows are numbered in the order they are applied
+    Transfer { req_id: i64, created_at: i64, entries: Vec<JournalEntry> },
+    // inserts or replaces the hold with the same id
+    PutHold(Hold),
+    Reverse { original_req_id: i64, req_id: i64 },
+    // overwrites the stored balance, e.g. with the one its rows add up to on a rebuild
+    SetBalance { account_id: u32, balance: i64 },
+    Request(RequestRecord),
+    Reset,
+}
+
+// The changes a call makes and what it returns
+pub type Commit<T> = (Vec<Change>, T);
+
+#[derive(Default)]
+pub struct MemoryState {
+    accounts: HashMap<u32, Account>,
+    transactions: Vec<TransactionEntry>,
+    // transfer rows only, opening rows share the req_id of their CreateAccount
+    by_req_id: HashMap<i64, Vec<usize>>,
+    holds: HashMap<u64, Hold>,
+    // original req_id -> req_id of its reversal
+    reversals: HashMap<i64, i64>,
+    requests: HashMap<u64, RequestRecord>,
+}
+
+impl MemoryState {
+    pub fn balance(&self, account: u32, now: i64) -> Option<AccountBalance> {
+        self.accounts.get(&account).map(|acc| AccountBalance {
+            ledger: acc.balance,
+            available: acc.balance - self.held(account, now, None),
+        })
+    }
+
+    pub fn transactions(&self, req_id: i64) -> Vec<TransactionEntry> {
+        match self.by_req_id.get(&req_id) {
+            Some(rows) => rows.iter().map(|&i| self.transactions[i].clone()).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn list_transactions(&self, filter: &TransactionFilter) -> Vec<TransactionRecord> {
+        self.transactions
+            .iter()
+            .filter(|row| row.kind == EntryKind::Transfer)
+            .map(TransactionEntry::to_record)
+            .filter(|record| filter.matches(record))
+            .take(filter.limit)
+            .collect()
+    }
+
+    // Funds reserved by active holds, except the one being captured
+    fn held(&self, account: u32, now: i64, released: Option<u64>) -> i64 {
+        self.holds
+            .values()
+            .filter(|hold| hold.account_id == account && hold.is_active(now))
+            .filter(|hold| Some(hold.id) != released)
+            .map(|hold| hold.amount)
+            .sum()
+    }
+
+    // Returns the hold if it can still be captured
+    fn active_hold(&self, hold_id: u64, now: i64) -> MemoryResult<Hold> {
+        let hold = match self.holds.get(&hold_id) {
+            Some(hold) => hold,
+            None => return Err(DataStoreError::HoldNotFound(hold_id)),
+        };
+        if hold.status != HoldStatus::Active {
+            return Err(DataStoreError::HoldNotActive(hold_id));
+        }
+        if !hold.is_active(now) {
+            return Err(DataStoreError::HoldExpired(hold_id));
+        }
+        Ok(hold.clone())
+    }
+
+    pub fn create_account(
+        &self,
+        account: u32,
+        req_id: u64,
+        balance: i64,
+        request: Option<RequestRecord>,
+    ) -> MemoryResult<Commit<u64>> {
+        let recorded = self.record_request(request)?;
+        if self.accounts.contains_key(&account) {
+            return Err(DataStoreError::AccountExists(account));
+        }
+        // balance_check
+        if balance < 0 {
+            return Err(DataStoreError::InvalidBalance(account, balance));
+        }
+        let created_at = now_millis();
+        let mut changes =
+            vec![Change::CreateAccount { account_id: account, req_id, balance, created_at }];
+        changes.extend(recorded);
+        Ok((changes, 1))
+    }
+
+    pub fn transfer(
+        &self,
+        plan: &TransferPlan,
+        req_id: i64,
+        max_balance: Option<i64>,
+        request: Option<RequestRecord>,
+    ) -> MemoryResult<Commit<()>> {
+        let recorded = self.record_request(request)?;
+        self.check_transfer(plan, max_balance, None)?;
+        let mut changes = vec![transfer_change(plan, req_id)];
+        changes.extend(recorded);
+        Ok((changes, ()))
+    }
+
+    // The change that records the request, checked before anything else like the first
+    // INSERT of the Postgres transaction
+    fn record_request(&self, request: Option<RequestRecord>) -> MemoryResult<Option<Change>> {
+        let request = match request {
+            Some(request) => request,
+            None => return Ok(None),
+        };
+        match self.requests.get(&request.req_id) {
+            Some(stored) if stored.fingerprint == request.fingerprint => {
+                Err(DataStoreError::RequestReplayed(stored.reply.clone()))
+            }
+            Some(_) => Err(DataStoreError::RequestIdReused(request.req_id)),
+            None => Ok(Some(Change::Request(request))),
+        }
+    }
+
+    pub fn authorize(&self, hold: &Hold) -> MemoryResult<Commit<()>> {
+        if self.holds.contains_key(&hold.id) {
+            return Err(DataStoreError::HoldExists(hold.id));
+        }
+        if !self.accounts.contains_key(&hold.to_account_id) {
+            return Err(DataStoreError::AccountNotFound(hold.to_account_id));
+        }
+        match self.balance(hold.account_id, now_millis()) {
+            None => return Err(DataStoreError::AccountNotFound(hold.account_id)),
+            Some(balance) if balance.available < hold.amount => {
+                return Err(DataStoreError::InsufficientFunds(hold.account_id))
+            }
+            Some(_) => {}
+        }
+        Ok((vec![Change::PutHold(hold.clone())], ()))
+    }
+
+    pub fn capture(
+        &self,
+        hold_id: u64,
+        amount: Option<i64>,
+        req_id: i64,
+        max_balance: Option<i64>,
+    ) -> MemoryResult<Commit<Hold>> {
+        let mut hold = self.active_hold(hold_id, now_millis())?;
+        let amount = amount.unwrap_or(hold.amount);
+        if amount > hold.amount {
+            return Err(DataStoreError::CaptureExceedsHold(hold_id, amount));
+        }
+
+        // the hold stops reserving funds before the captured amount is moved
+        hold.status = HoldStatus::Captured;
+        hold.captured = amount;
+        let plan = TransferPlan::new(&hold.components(amount));
+        self.check_transfer(&plan, max_balance, Some(hold_id))?;
+        let changes = vec![Change::PutHold(hold.clone()), transfer_change(&plan, req_id)];
+        Ok((changes, hold))
+    }
+
+    pub fn void(&self, hold_id: u64) -> MemoryResult<Commit<Hold>> {
+        let mut hold = match self.holds.get(&hold_id) {
+            Some(hold) => hold.clone(),
+            None => return Err(DataStoreError::HoldNotFound(hold_id)),
+        };
+        if hold.status != HoldStatus::Active {
+            return Err(DataStoreError::HoldNotActive(hold_id));
+        }
+        hold.status = HoldStatus::Voided;
+        Ok((vec![Change::PutHold(hold.clone())], hold))
+    }
+
+    pub fn reverse(
+        &self,
+        original_req_id: i64,
+        req_id: i64,
+        max_balance: Option<i64>,
+    ) -> MemoryResult<Commit<Vec<TransferComponent>>> {
+        if self.reversals.contains_key(&original_req_id) {
+            return Err(DataStoreError::AlreadyReversed(original_req_id as u64));
+        }
+        let transfers: Vec<TransferComponent> = match self.by_req_id.get(&original_req_id) {
+            Some(rows) => rows.iter().map(|&i| self.transactions[i].reversed()).collect(),
+            None => return Err(DataStoreError::TransferNotFound(original_req_id as u64)),
+        };
+        let plan = TransferPlan::new(&transfers);
+        self.check_transfer(&plan, max_balance, None)?;
+        let changes = vec![
+            transfer_change(&plan, req_id),
+            Change::Reverse { original_req_id, req_id },
+        ];
+        Ok((changes, transfers))
+    }
+
+    // Every account's balance as its rows add it up, the opening row included
+    fn projection(&self) -> HashMap<u32, i64> {
+        let mut balances: HashMap<u32, i64> = HashMap::new();
+        for row in &self.transactions {
+            let balance = balances.entry(row.account_id).or_insert(0);
+            *balance = balance.wrapping_add(row.amount);
+        }
+        balances
+    }
+
+    // Compares each stored balance with the projection and, with rebuild, replaces the
+    // ones that disagree
+    pub fn verify_ledger(&self, rebuild: bool) -> MemoryResult<Commit<LedgerReport>> {
+        let projection = self.projection();
+        let mut mismatches: Vec<LedgerMismatch> = self
+            .accounts
+            .iter()
+            .map(|(&account_id, account)| LedgerMismatch {
+                account_id,
+                stored: account.balance,
+                projected: projection.get(&account_id).cloned().unwrap_or(0),
+            })
+            .filter(|mismatch| mismatch.stored != mismatch.projected)
+            .collect();
+        mismatches.sort_by_key(|mismatch| mismatch.account_id);
+
+        let changes = if rebuild {
+            mismatches
+                .iter()
+                .map(|mismatch| Change::SetBalance {
+                    account_id: mismatch.account_id,
+                    balance: mismatch.projected,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let report = LedgerReport {
+            accounts: self.accounts.len() as u64,
+            mismatches,
+            // every opening here is the balance the account was created with
+            backfilled: Vec::new(),
+        };
+        Ok((changes, report))
+    }
+
+    // Applies the legs in account order, the same way the UPDATE statements run inside
+    // the Postgres transaction, without touching state
+    fn check_transfer(
+        &self,
+        plan: &TransferPlan,
+        max_balance: Option<i64>,
+        released: Option<u64>,
+    ) -> MemoryResult<()> {
+        let now = now_millis();
+        if let Some(&account) = plan.unchanged().iter().find(|a| !self.accounts.contains_key(a)) {
+            return Err(DataStoreError::AccountNotFound(account));
+        }
+        for leg in plan.legs() {
+            let account = leg.account_id;
+            let current = match self.accounts.get(&account) {
+                Some(account) => account.balance,
+                None => return Err(DataStoreError::AccountNotFound(account)),
+            };
+            let balance = match current.checked_add(leg.delta) {
+                Some(balance) if max_balance.map_or(true, |max| balance <= max) => balance,
+                _ => return Err(DataStoreError::BalanceOutOfRange(account)),
+            };
+            if balance < 0 {
+                return Err(DataStoreError::InsufficientFunds(account));
+            }
+            // debits may not dip into funds held by authorizations
+            if leg.delta < 0 && balance < self.held(account, now, released) {
+                return Err(DataStoreError::InsufficientFunds(account));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn apply(&mut self, change: &Change) {
+        match change {
+            Change::CreateAccount { account_id, req_id, balance, created_at } => {
+                let account = Account {
+                    balance: *balance,
+                    creation_request: *req_id,
+                    created_at: *created_at,
+                };
+                self.accounts.insert(*account_id, account);
+                if *balance != 0 {
+                    let row = self.transactions.len();
+                    self.transactions.push(TransactionEntry {
+                        id: row as u64 + 1,
+                        transaction_index: 0,
+                        req_id: *req_id as i64,
+                        account_id: *account_id,
+                        amount: *balance,
+                        created_at: *created_at,
+                        kind: EntryKind::Opening,
+                    });
+                }
+            }
+            Change::Transfer { req_id, created_at, entries } => {
+                for entry in entries {
+                    // the net of each account was checked, wrapping only matters when the
+                    // components on their own would overflow
+                    let account = self.accounts.get_mut(&entry.account_id).unwrap();
+                    account.balance = account.balance.wrapping_add(entry.amount);
+
+                    let row = self.transactions.len();
+                    self.transactions.push(TransactionEntry {
+                        id: row as u64 + 1,
+                        transaction_index: entry.transaction_index,
+                        req_id: *req_id,
+                        account_id: entry.account_id,
+                        amount: entry.amount,
+                        created_at: *created_at,
+                        kind: EntryKind::Transfer,
+                    });
+                    self.by_req_id.entry(*req_id).or_insert_with(Vec::new).push(row);
+                }
+            }
+            Change::PutHold(hold) => {
+                self.holds.insert(hold.id, hold.clone());
+            }
+            Change::Reverse { original_req_id, req_id } => {
+                self.reversals.insert(*original_req_id, *req_id);
+            }
+            Change::SetBalance { account_id, balance } => {
+                if let Some(account) = self.accounts.get_mut(account_id) {
+                    account.balance = *balance;
+                }
+            }
+            Change::Request(request) => {
+                self.requests.insert(request.req_id, request.clone());
+            }
+            Change::Reset => *self = MemoryState::default(),
+        }
+    }
+
+    // The fewest changes that rebuild this state from empty: the accounts opened at zero,
+    // then the rows in order with each opening row as the account it opened, the holds,
+    // the reversals, the recorded requests and any balance that disagrees with its rows
+    pub fn compact(&self) -> Vec<Change> {
+        let opened: HashSet<u32> = self
+            .transactions
+            .iter()
+            .filter(|row| row.kind == EntryKind::Opening)
+            .map(|row| row.account_id)
+            .collect();
+        let mut accounts: Vec<(&u32, &Account)> = self.accounts.iter().collect();
+        accounts.sort_by_key(|&(id, _)| *id);
+        let mut changes: Vec<Change> = accounts
+            .iter()
+            .filter(|&&(id, _)| !opened.contains(id))
+            .map(|&(&account_id, account)| Change::CreateAccount {
+                account_id,
+                req_id: account.creation_request,
+                balance: 0,
+                created_at: account.created_at,
+            })
+            .collect();
+
+        for row in &self.transactions {
+            if row.kind == EntryKind::Opening {
+                changes.push(Change::CreateAccount {
+                    account_id: row.account_id,
+                    req_id: row.req_id as u64,
+                    balance: row.amount,
+                    created_at: row.created_at,
+                });
+                continue;
+            }
+            let entry = JournalEntry {
+                transaction_index: row.transaction_index,
+                account_id: row.account_id,
+                amount: row.amount,
+            };
+            match changes.last_mut() {
+                Some(Change::Transfer { req_id, created_at, entries })
+                    if *req_id == row.req_id && *created_at == row.created_at =>
+                {
+                    entries.push(entry)
+                }
+                _ => changes.push(Change::Transfer {
+                    req_id: row.req_id,
+                    created_at: row.created_at,
+                    entries: vec![entry],
+                }),
+            }
+        }
+
+        let mut holds: Vec<&Hold> = self.holds.values().collect();
+        holds.sort_by_key(|hold| hold.id);
+        changes.extend(holds.into_iter().cloned().map(Change::PutHold));
+        let mut reversals: Vec<(&i64, &i64)> = self.reversals.iter().collect();
+        reversals.sort();
+        changes.extend(reversals.into_iter().map(|(&original_req_id, &req_id)| {
+            Change::Reverse { original_req_id, req_id }
+        }));
+        let mut requests: Vec<&RequestRecord> = self.requests.values().collect();
+        requests.sort_by_key(|request| request.req_id);
+        changes.extend(requests.into_iter().cloned().map(Change::Request));
+        let projection = self.projection();
+        changes.extend(
+            accounts
+                .into_iter()
+                .filter(|&(id, account)| {
+                    account.balance != projection.get(id).cloned().unwrap_or(0)
+                })
+                .map(|(&account_id, account)| Change::SetBalance {
+                    account_id,
+                    balance: account.balance,
+                }),
+        );
+        changes
+    }
+}
+
+fn transfer_change(plan: &TransferPlan, req_id: i64) -> Change {
+    Change::Transfer { req_id, created_at: now_millis(), entries: plan.entries().to_vec() }
+}
+
+// Keeps the same guarantees as the accounts/transactions schema without a database:
+// unique account ids, non-negative balances and all-or-nothing transfers
+#[derive(Clone, Default)]
+pub struct InMemoryDataStore {
+    state: Arc<Mutex<MemoryState>>,
+    // None allows any balance that fits in an i64
+    max_balance: Option<i64>,
+    // recorded with the next create or transfer
+    request: Option<RequestRecord>,
+}
+
+impl InMemoryDataStore {
+    pub fn new() -> InMemoryDataStore {
+        InMemoryDataStore::default()
+    }
+
+    pub fn transactions(&self, req_id: i64) -> Vec<TransactionEntry> {
+        self.lock().transactions(req_id)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap()
+    }
+
+    fn commit<T, F>(&self, op: F) -> MemoryResult<T>
+    where
+        F: FnOnce(&MemoryState) -> MemoryResult<Commit<T>>,
+    {
+        let mut state = self.lock();
+        let (changes, res) = op(&state)?;
+        for change in &changes {
+            state.apply(change);
+        }
+        Ok(res)
+    }
+}
+
+impl DataStore for InMemoryDataStore {
+    fn create_account(&mut self, account: u32, req_id: u64, balance: i64) -> MemoryResult<u64> {
+        let request = self.request.take();
+        self.commit(|state| state.create_account(account, req_id, balance, request))
+    }
+
+    fn get_account_balance(&mut self, account: u32) -> MemoryResult<Option<AccountBalance>> {
+        Ok(self.lock().balance(account, now_millis()))
+    }
+
+    fn execute_transfers(&mut self, plan: &TransferPlan, req_id: i64) -> MemoryResult<()> {
+        let (max_balance, request) = (self.max_balance, self.request.take());
+        self.commit(|state| state.transfer(plan, req_id, max_balance, request))
+    }
+
+    fn authorize_transfer(&mut self, hold: &Hold) -> MemoryResult<()> {
+        self.commit(|state| state.authorize(hold))
+    }
+
+    fn capture_transfer(
+        &mut self,
+        hold_id: u64,
+        amount: Option<i64>,
+        req_id: i64,
+    ) -> MemoryResult<Hold> {
+        let max_balance = self.max_balance;
+        self.commit(|state| state.capture(hold_id, amount, req_id, max_balance))
+    }
+
+    fn void_transfer(&mut self, hold_id: u64) -> MemoryResult<Hold> {
+        self.commit(|state| state.void(hold_id))
+    }
+
+    fn reverse_transfer(
+        &mut self,
+        original_req_id: i64,
+        req_id: i64,
+    ) -> MemoryResult<Vec<TransferComponent>> {
+        let max_balance = self.max_balance;
+        self.commit(|state| state.reverse(original_req_id, req_id, max_balance))
+    }
+
+    fn list_transactions(
+        &mut self,
+        filter: &TransactionFilter,
+    ) -> MemoryResult<Vec<TransactionRecord>> {
+        Ok(self.lock().list_transactions(filter))
+    }
+
+    fn reset(&mut self) -> MemoryResult<()> {
+        self.commit(|_| Ok((vec![Change::Reset], ())))
+    }
+
+    fn verify_ledger(&mut self, rebuild: bool) -> MemoryResult<LedgerReport> {
+        self.commit(|state| state.verify_ledger(rebuild))
+    }
+
+    fn set_request(&mut self, request: Option<RequestRecord>) {
+        self.request = request;
+    }
+
+    fn set_max_balance(&mut self, max_balance: i64) {
+        self.max_balance = Some(max_balance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(transfers: &[(u32, i64)]) -> TransferPlan {
+        let components: Vec<TransferComponent> = transfers
+            .iter()
+            .map(|&(account_id, amount)| {
+                let mut comp = TransferComponent::new();
+                comp.set_account_id(account_id);
+                comp.set_money_delta(amount);
+                comp
+            })
+            .collect();
+        TransferPlan::new(&components)
+    }
+
+    fn ledger(store: &mut InMemoryDataStore, account: u32) -> Option<i64> {
+        store.get_account_balance(account).unwrap().map(|balance| balance.ledger)
+    }
+
+    datastore_conformance!(Box::new(InMemoryDataStore::new()));
+
+    fn hold(id: u64, amount: i64, expires_at: i64) -> Hold {
+        Hold {
+            id,
+            account_id: 1,
+            to_account_id: 2,
+            amount,
+            captured: 0,
+            status: HoldStatus::Active,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_create_account() {
+        let mut store = InMemoryDataStore::new();
+        assert_eq!(store.create_account(1, 0, 1_000), Ok(1));
+        assert_eq!(ledger(&mut store, 1), Some(1_000));
+
+        // duplicate account and negative opening balance
+        assert_eq!(store.create_account(1, 1, 1_000), Err(DataStoreError::AccountExists(1)));
+        assert_eq!(store.create_account(2, 2, -1), Err(DataStoreError::InvalidBalance(2, -1)));
+        assert_eq!(ledger(&mut store, 2), None);
+    }
+
+    #[test]
+    fn test_get_balance() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 0).unwrap();
+
+        // a genuine zero balance is distinct from a missing account
+        assert_eq!(ledger(&mut store, 1), Some(0));
+        assert_eq!(ledger(&mut store, 2), None);
+    }
+
+    #[test]
+    fn test_transfer() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+
+        // missing account leaves every balance untouched
+        assert_eq!(
+            store.execute_transfers(&plan(&[(1, -50), (5, 50)]), 2),
+            Err(DataStoreError::AccountNotFound(5))
+        );
+        assert_eq!(ledger(&mut store, 1), Some(100));
+
+        // insufficient funds on a later leg rolls back the earlier ones
+        assert_eq!(
+            store.execute_transfers(&plan(&[(2, 10), (1, -110)]), 3),
+            Err(DataStoreError::InsufficientFunds(1))
+        );
+        assert_eq!(ledger(&mut store, 1), Some(100));
+        assert_eq!(ledger(&mut store, 2), Some(0));
+        assert!(store.transactions(3).is_empty());
+
+        assert!(store.execute_transfers(&plan(&[(1, -40), (2, 40)]), 4).is_ok());
+        assert_eq!(ledger(&mut store, 1), Some(60));
+        assert_eq!(ledger(&mut store, 2), Some(40));
+
+        let log = store.transactions(4);
+        assert_eq!(log.len(), 2);
+        assert_eq!((log[1].transaction_index, log[1].account_id, log[1].amount), (1, 2, 40));
+
+        // legs for the same account are netted, the log keeps every non-zero component
+        assert!(store.execute_transfers(&plan(&[(2, 30), (1, -70), (1, 40), (2, 0)]), 5).is_ok());
+        assert_eq!(ledger(&mut store, 1), Some(30));
+        assert_eq!(ledger(&mut store, 2), Some(70));
+        let log: Vec<(u32, u32, i64)> = store
+            .transactions(5)
+            .iter()
+            .map(|row| (row.transaction_index, row.account_id, row.amount))
+            .collect();
+        assert_eq!(log, vec![(0, 2, 30), (1, 1, -70), (2, 1, 40)]);
+
+        // balances above the limit or beyond i64 are out of range
+        store.set_max_balance(70);
+        assert_eq!(
+            store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 6),
+            Err(DataStoreError::BalanceOutOfRange(2))
+        );
+        assert_eq!(ledger(&mut store, 1), Some(30));
+
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, i64::MAX).unwrap();
+        store.create_account(2, 1, 10).unwrap();
+        assert_eq!(
+            store.execute_transfers(&plan(&[(1, 1), (2, -1)]), 2),
+            Err(DataStoreError::BalanceOutOfRange(1))
+        );
+    }
+
+    #[test]
+    fn test_list_transactions() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+        store.create_account(3, 2, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -10), (2, 10)]), 3).unwrap();
+        store.execute_transfers(&plan(&[(1, -10), (3, 10)]), 4).unwrap();
+
+        let mut filter = TransactionFilter {
+            account_ids: vec![1],
+            limit: 10,
+            ..Default::default()
+        };
+        let records = store.list_transactions(&filter).unwrap();
+        assert_eq!(records.iter().map(|r| r.get_req_id()).collect::<Vec<_>>(), vec![3, 4]);
+
+        filter.after_id = Some(records[0].get_id());
+        assert_eq!(store.list_transactions(&filter).unwrap().len(), 1);
+
+        let filter = TransactionFilter { req_ids: vec![4], limit: 1, ..Default::default() };
+        let records = store.list_transactions(&filter).unwrap();
+        assert_eq!((records[0].get_account_id(), records[0].get_amount()), (1, -10));
+
+        let filter = TransactionFilter { end_time: Some(0), limit: 10, ..Default::default() };
+        assert!(store.list_transactions(&filter).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.reset().unwrap();
+        assert_eq!(ledger(&mut store, 1), None);
+        assert!(store.create_account(1, 0, 100).is_ok());
+    }
+
+    #[test]
+    fn test_holds() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+        let expires_at = now_millis() + 60_000;
+
+        // a hold reduces the available but not the ledger balance
+        assert!(store.authorize_transfer(&hold(10, 60, expires_at)).is_ok());
+        let balance = store.get_account_balance(1).unwrap().unwrap();
+        assert_eq!((balance.ledger, balance.available), (100, 40));
+        assert_eq!(
+            store.authorize_transfer(&hold(11, 50, expires_at)),
+            Err(DataStoreError::InsufficientFunds(1))
+        );
+        assert_eq!(
+            store.execute_transfers(&plan(&[(1, -50), (2, 50)]), 12),
+            Err(DataStoreError::InsufficientFunds(1))
+        );
+
+        // a partial capture moves the amount and releases the rest
+        assert_eq!(
+            store.capture_transfer(10, Some(70), 13),
+            Err(DataStoreError::CaptureExceedsHold(10, 70))
+        );
+        assert_eq!(store.capture_transfer(10, Some(25), 14).unwrap().captured, 25);
+        let balance = store.get_account_balance(1).unwrap().unwrap();
+        assert_eq!((balance.ledger, balance.available), (75, 75));
+        assert_eq!(ledger(&mut store, 2), Some(25));
+        assert_eq!(store.capture_transfer(10, None, 15), Err(DataStoreError::HoldNotActive(10)));
+
+        // a voided hold releases its funds without moving money
+        assert!(store.authorize_transfer(&hold(16, 75, expires_at)).is_ok());
+        assert_eq!(store.void_transfer(16).unwrap().status, HoldStatus::Voided);
+        assert_eq!(store.get_account_balance(1).unwrap().unwrap().available, 75);
+        assert_eq!(store.void_transfer(16), Err(DataStoreError::HoldNotActive(16)));
+        assert_eq!(store.void_transfer(17), Err(DataStoreError::HoldNotFound(17)));
+    }
+
+    #[test]
+    fn test_expired_hold() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+
+        assert!(store.authorize_transfer(&hold(10, 100, now_millis() - 1)).is_ok());
+        assert_eq!(store.get_account_balance(1).unwrap().unwrap().available, 100);
+        assert_eq!(store.capture_transfer(10, None, 11), Err(DataStoreError::HoldExpired(10)));
+    }
+
+    #[test]
+    fn test_reverse_transfer() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+        store.create_account(3, 2, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -30), (2, 20), (3, 10)]), 3).unwrap();
+
+        assert_eq!(store.reverse_transfer(9, 4), Err(DataStoreError::TransferNotFound(9)));
+
+        // the reversal cannot drive a balance below zero
+        store.execute_transfers(&plan(&[(3, -10), (1, 10)]), 5).unwrap();
+        assert_eq!(store.reverse_transfer(3, 6), Err(DataStoreError::InsufficientFunds(3)));
+        store.reverse_transfer(5, 7).unwrap();
+
+        let legs = store.reverse_transfer(3, 8).unwrap();
+        let deltas: Vec<i64> = legs.iter().map(|c| c.get_money_delta()).collect();
+        assert_eq!(deltas, vec![30, -20, -10]);
+        assert_eq!(ledger(&mut store, 1), Some(100));
+        assert_eq!(ledger(&mut store, 2), Some(0));
+        assert_eq!(store.transactions(8).len(), 3);
+        assert_eq!(store.reverse_transfer(3, 9), Err(DataStoreError::AlreadyReversed(3)));
+    }
+
+    #[test]
+    fn test_verify_ledger() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 2).unwrap();
+
+        // the opening balance is a row of its own, but not a transfer
+        let rows = store.lock().transactions.clone();
+        let kinds: Vec<(EntryKind, i64)> = rows.iter().map(|row| (row.kind, row.amount)).collect();
+        let transfer = EntryKind::Transfer;
+        assert_eq!(kinds, vec![(EntryKind::Opening, 100), (transfer, -30), (transfer, 30)]);
+        assert!(store.transactions(0).is_empty());
+
+        // a balance the rows don't add up to is reported, and replaced on rebuild
+        store.lock().apply(&Change::SetBalance { account_id: 1, balance: 50 });
+        let mismatch = LedgerMismatch { account_id: 1, stored: 50, projected: 70 };
+        let found = LedgerReport {
+            accounts: 2,
+            mismatches: vec![mismatch],
+            backfilled: Vec::new(),
+        };
+        assert_eq!(store.verify_ledger(false), Ok(found.clone()));
+        assert_eq!(ledger(&mut store, 1), Some(50));
+        assert_eq!(store.verify_ledger(true), Ok(found));
+        assert_eq!(ledger(&mut store, 1), Some(70));
+        assert!(store.verify_ledger(false).unwrap().mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut store = InMemoryDataStore::new();
+        store.create_account(1, 0, 100).unwrap();
+        store.create_account(2, 1, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -30), (2, 30)]), 2).unwrap();
+        store.execute_transfers(&plan(&[(2, -5), (1, 5)]), 3).unwrap();
+        store.reverse_transfer(3, 4).unwrap();
+        store.authorize_transfer(&hold(5, 20, now_millis() + 60_000)).unwrap();
+        store.capture_transfer(5, Some(15), 6).unwrap();
+        store.create_account(3, 7, 10).unwrap();
+        // a mismatch survives compaction, so rebuilding the state doesn't hide it
+        store.lock().apply(&Change::SetBalance { account_id: 2, balance: 1 });
+
+        let state = store.lock();
+        let mut rebuilt = MemoryState::default();
+        for change in state.compact() {
+            rebuilt.apply(&change);
+        }
+        assert_eq!(rebuilt.compact(), state.compact());
+        let filter = TransactionFilter { limit: 100, ..Default::default() };
+        assert_eq!(rebuilt.list_transactions(&filter), state.list_transactions(&filter));
+        assert_eq!(rebuilt.balance(1, 0), Some(AccountBalance { ledger: 55, available: 55 }));
+        assert_eq!(rebuilt.verify_ledger(false), state.verify_ledger(false));
+        assert_eq!(rebuilt.verify_ledger(false).unwrap().1.mismatches.len(), 1);
+        assert_eq!(rebuilt.reverse(3, 7, None), Err(DataStoreError::AlreadyReversed(3)));
+        assert_eq!(rebuilt.capture(5, None, 7, None), Err(DataStoreError::HoldNotActive(5)));
+    }
+}
@@ -0,0 +1,132 @@
+use postgres::error::{T_R_SERIALIZATION_FAILURE, UNIQUE_VIOLATION};
+use postgres::Connection;
+
+use crate::db::postgres::{has_code, PostgresResult};
+use crate::DataStoreError;
+
+// One forward step of the Postgres and CockroachDB schema, applied in version order
+#[derive(Debug, PartialEq)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+// Append only: a released migration is never edited, later changes get a new version.
+// 0001 uses IF NOT EXISTS so databases created before migrations are adopted as they are.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "create_tables",
+        sql: include_str!("../sql/migrations/0001_create_tables.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "transaction_kind",
+        sql: include_str!("../sql/migrations/0002_transaction_kind.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "opening_entries",
+        sql: include_str!("../sql/migrations/0003_opening_entries.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "requests",
+        sql: include_str!("../sql/migrations/0004_requests.sql"),
+    },
+];
+
+const CREATE_MIGRATIONS_TABLE: &str = "CREATE TABLE IF NOT EXISTS schema_migrations (
+    version INT4 PRIMARY KEY,
+    name TEXT,
+    applied_at TIMESTAMP DEFAULT now()
+);";
+
+pub fn latest_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |migration| migration.version)
+}
+
+// The migrations not yet applied. A database migrated by a newer server is refused, since
+// this one doesn't know what its schema looks like.
+pub fn pending<'a>(
+    applied: &[u32],
+    migrations: &'a [Migration],
+) -> PostgresResult<Vec<&'a Migration>> {
+    let latest = migrations.last().map_or(0, |migration| migration.version);
+    if let Some(&newest) = applied.iter().max().filter(|&&newest| newest > latest) {
+        return Err(DataStoreError::Internal(format!(
+            "Database Schema Version {} Is Newer Than This Server's {}",
+            newest, latest
+        )));
+    }
+    Ok(migrations.iter().filter(|migration| !applied.contains(&migration.version)).collect())
+}
+
+fn applied_versions(conn: &Connection) -> PostgresResult<Vec<u32>> {
+    let rows = conn.query("SELECT version FROM schema_migrations", &[])?;
+    Ok(rows.iter().map(|row| row.get::<_, i32>(0) as u32).collect())
+}
+
+// Applies the pending migrations, each in a transaction with the row that records it, and
+// returns them. Safe to run from several servers at once: one applies each migration and
+// the others find it recorded when their own insert conflicts.
+pub fn migrate(conn: &Connection) -> PostgresResult<Vec<&'static Migration>> {
+    conn.batch_execute(CREATE_MIGRATIONS_TABLE)?;
+    let mut applied = Vec::new();
+    for migration in pending(&applied_versions(conn)?, MIGRATIONS)? {
+        let txn = conn.transaction()?;
+        let res = txn.batch_execute(migration.sql).and_then(|_| {
+            txn.execute(
+                "INSERT INTO schema_migrations (version, name) VALUES ($1, $2)",
+                &[&(migration.version as i32), &migration.name],
+            )
+        });
+        match res.and_then(|_| txn.commit()) {
+            Ok(()) => applied.push(migration),
+            Err(ref err)
+                if (has_code(err, &UNIQUE_VIOLATION)
+                    || has_code(err, &T_R_SERIALIZATION_FAILURE))
+                    && applied_versions(conn)?.contains(&migration.version) => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEPS: &[Migration] = &[
+        Migration { version: 1, name: "one", sql: "" },
+        Migration { version: 2, name: "two", sql: "" },
+        Migration { version: 3, name: "three", sql: "" },
+    ];
+
+    fn versions(migrations: Vec<&Migration>) -> Vec<u32> {
+        migrations.iter().map(|migration| migration.version).collect()
+    }
+
+    #[test]
+    fn test_pending() {
+        assert_eq!(versions(pending(&[], STEPS).unwrap()), vec![1, 2, 3]);
+        assert_eq!(versions(pending(&[1], STEPS).unwrap()), vec![2, 3]);
+        assert!(pending(&[1, 2, 3], STEPS).unwrap().is_empty());
+
+        match pending(&[1, 2, 3, 4], STEPS) {
+            Err(DataStoreError::Internal(reason)) => {
+                assert_eq!(reason, "Database Schema Version 4 Is Newer Than This Server's 3")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_versions_increase() {
+        let versions: Vec<u32> = MIGRATIONS.iter().map(|migration| migration.version).collect();
+        let expected: Vec<u32> = (1..=MIGRATIONS.len() as u32).collect();
+        assert_eq!(versions, expected);
+        assert_eq!(latest_version(), MIGRATIONS.len() as u32);
+    }
+}
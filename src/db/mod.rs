@@ -1,3 +1,17 @@
+#[cfg(test)]
+#[macro_use]
+mod conformance;
+pub mod file;
+pub mod memory;
+pub mod migrations;
 pub mod postgres;
+pub mod retry;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
+pub use self::file::{Durability, FileLedgerDataStore};
+pub use self::memory::InMemoryDataStore;
 pub use self::postgres::PostgresDataStore;
+pub use self::retry::{RetryMetrics, RetryPolicy};
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::SqliteDataStore;
@@ -2,171 +2,947 @@ extern crate postgres;
 extern crate r2d2;
 extern crate r2d2_postgres;
 
-use std::io;
+use std::collections::{HashMap, HashSet};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use postgres::error::T_R_SERIALIZATION_FAILURE;
+use postgres::error::{
+    SqlState, ADMIN_SHUTDOWN, CANNOT_CONNECT_NOW, CHECK_VIOLATION, T_R_SERIALIZATION_FAILURE,
+    TOO_MANY_CONNECTIONS, UNIQUE_VIOLATION,
+};
 use postgres::transaction::Transaction;
-use postgres::{Connection, Error};
+use postgres::types::ToSql;
+use postgres::{Connection, Error, GenericConnection};
 use postgres::transaction::{Config, IsolationLevel};
 use r2d2::{Pool, PooledConnection};
 use r2d2_postgres::{PostgresConnectionManager, TlsMode};
 
-use crate::proto::accounting::TransferComponent;
-use crate::DataStore;
+use crate::db::migrations::{self, Migration};
+use crate::db::retry::{RetryMetrics, RetryPolicy};
+use crate::idempotency::RequestRecord;
+use crate::proto::accounting::{TransactionRecord, TransferComponent};
+use crate::transfer::{AmountLimits, TransferLeg, TransferPlan};
+use crate::{now_millis, AccountBalance, DataStore, DataStoreError, DeadlineCheck, Hold, HoldStatus};
+use crate::{LedgerBackfill, LedgerMismatch, LedgerReport, TransactionFilter};
 
 pub type PostgresPool = Pool<PostgresConnectionManager>;
 pub type PostgresConnection = PooledConnection<PostgresConnectionManager>;
-pub type PostgresResult<T> = Result<T, ()>;
+pub type PostgresResult<T> = Result<T, DataStoreError>;
+
+// created_at as milliseconds since the unix epoch
+const CREATED_AT_MILLIS: &str = "(extract(epoch FROM created_at) * 1000)::INT8";
+
+// ledger and available balance of account $1 at time $2
+const BALANCE_QUERY: &str = "SELECT balance, (balance - COALESCE((
+        SELECT SUM(amount) FROM holds
+        WHERE account_id = $1 AND status = 'active' AND expires_at > $2
+    ), 0))::INT8
+    FROM accounts WHERE id = $1";
+
+// id, stored and projected balance of every account whose rows don't add up to its balance
+const MISMATCH_QUERY: &str = "SELECT accounts.id::INT8, accounts.balance,
+        COALESCE(SUM(transactions.amount), 0)::INT8
+    FROM accounts LEFT JOIN transactions ON transactions.account_id = accounts.id
+    GROUP BY accounts.id, accounts.balance
+    HAVING accounts.balance != COALESCE(SUM(transactions.amount), 0)
+    ORDER BY accounts.id";
+
+// account and amount of every opening row migration 0003 inferred
+const BACKFILL_QUERY: &str = "SELECT account_id::INT8, amount FROM transactions
+    WHERE kind = 'opening_backfill' ORDER BY account_id";
+
+// legs or ledger rows per statement of a batched transfer, keeps the INSERT well under the
+// 65535 parameter limit
+const BATCH_ROWS: usize = 1_000;
+
+// How the transfers, captures and reversals of a store apply their legs
+#[derive(Clone, Copy, Debug)]
+struct TransferSettings {
+    // one UPDATE and one INSERT instead of one of each per leg
+    batch: bool,
+    // no leg may leave a balance above this
+    max_balance: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct PoolConfig {
+    pub max_size: u32,
+    // how long to wait for a connection before failing the request
+    pub connection_timeout: Duration,
+    // None keeps idle connections open
+    pub idle_timeout: Option<Duration>,
+}
+
+impl Default for PoolConfig {
+    fn default() -> PoolConfig {
+        PoolConfig {
+            max_size: 10,
+            connection_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(10 * 60)),
+        }
+    }
+}
 
 // This will work with both Postgres and CockroachDb
 #[derive(Clone)]
 pub struct PostgresDataStore {
     pool: PostgresPool,
+    retry: RetryPolicy,
+    metrics: RetryMetrics,
+    transfer: TransferSettings,
+    // deadline of the request being served, taken by the next transaction
+    deadline: Option<DeadlineCheck>,
+    // recorded by the next create or transfer
+    request: Option<RequestRecord>,
 }
 
 impl PostgresDataStore {
     pub fn new(conn_string: &str) -> PostgresDataStore {
+        PostgresDataStore::with_pool_config(conn_string, &PoolConfig::default())
+            .expect("Database Connection Error")
+    }
+
+    pub fn with_pool_config(
+        conn_string: &str,
+        config: &PoolConfig,
+    ) -> PostgresResult<PostgresDataStore> {
         let manager = PostgresConnectionManager::new(conn_string, TlsMode::None)
-            .expect("Database Connection Error");
-        let pool = Pool::new(manager)
-            .expect("ThreadPool Error");
-        PostgresDataStore { pool }
+            .map_err(|e| DataStoreError::Unavailable(e.to_string()))?;
+        let pool = Pool::builder()
+            .max_size(config.max_size)
+            .connection_timeout(config.connection_timeout)
+            .idle_timeout(config.idle_timeout)
+            .build(manager)
+            .map_err(|e| DataStoreError::Unavailable(e.to_string()))?;
+        Ok(PostgresDataStore {
+            pool,
+            retry: RetryPolicy::default(),
+            metrics: RetryMetrics::new(),
+            transfer: TransferSettings {
+                batch: true,
+                max_balance: AmountLimits::default().max_balance,
+            },
+            deadline: None,
+            request: None,
+        })
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> PostgresDataStore {
+        self.retry = retry;
+        self
     }
 
+    pub fn with_batched_transfers(mut self, batch: bool) -> PostgresDataStore {
+        self.transfer.batch = batch;
+        self
+    }
+
+    // shared by every clone of this store
+    pub fn retry_metrics(&self) -> RetryMetrics {
+        self.metrics.clone()
+    }
+
+    // connections currently open in the pool
+    pub fn connections(&self) -> u32 {
+        self.pool.state().connections
+    }
+
+    // Brings the schema up to date, see db::migrations
+    pub fn migrate(&mut self) -> PostgresResult<Vec<&'static Migration>> {
+        migrations::migrate(&*self.get_conn()?)
+    }
+
+    // Empties every table, creating them first if needed; schema_migrations is kept
     pub fn reset_db_state(&mut self) -> PostgresResult<()> {
-        self.get_conn().execute("DROP TABLE IF EXISTS accounts;", &[]).unwrap();
-        self.get_conn().execute("CREATE TABLE accounts (
-            id INT4 PRIMARY KEY,
-            balance BIGINT,
-            created_at TIMESTAMP DEFAULT now(),
-            creation_request BIGINT,
-            CONSTRAINT balance_check CHECK (balance >= 0)
-        );", &[]).unwrap();
-        self.get_conn().execute("GRANT ALL ON TABLE accounts TO accountant;", &[]).unwrap();
-
-        self.get_conn().execute("DROP TABLE IF EXISTS transactions;", &[]).unwrap();
-        self.get_conn().execute("CREATE TABLE transactions (
-            id INT PRIMARY KEY DEFAULT unique_rowid(),
-            transaction_index INT4,
-            req_id BIGINT,
-            account_id INT4,
-            amount INT,
-            created_at TIMESTAMP DEFAULT now()
-        );", &[]).unwrap();
-        self.get_conn().execute("GRANT ALL ON TABLE transactions TO accountant;", &[]).unwrap();
+        self.migrate()?;
+        let conn = self.get_conn()?;
+        conn.batch_execute("TRUNCATE accounts, transactions, holds, reversals, requests;")?;
         Ok(())
     }
 
-    fn get_conn(&mut self) -> PostgresConnection {
-        self.pool.get().unwrap()
+    fn get_conn(&mut self) -> PostgresResult<PostgresConnection> {
+        self.pool
+            .get()
+            .map_err(|e| DataStoreError::Unavailable(e.to_string()))
+    }
+
+    fn run_txn<T, F>(&mut self, op: F) -> PostgresResult<T>
+    where
+        F: Fn(&Transaction) -> PostgresResult<T>,
+    {
+        let deadline = self.deadline.take();
+        let conn = self.get_conn()?;
+        execute_txn(&conn, &self.retry, &self.metrics, deadline, op)
     }
 }
 
 impl DataStore for PostgresDataStore {
     fn create_account(&mut self, account: u32, req_id: u64, balance: i64) -> PostgresResult<u64> {
-        let res = create_account(self.get_conn(), account, req_id, balance);
+        let request = self.request.take();
+        let res = self.run_txn(|txn| {
+            record_request(txn, &request)?;
+            create_account(txn, account, req_id, balance)
+        });
         match res {
             Ok(1) => Ok(1),
-            _ => Err(()),
+            Ok(_) => Err(DataStoreError::Internal("Account Was Not Inserted".to_string())),
+            Err(err) => Err(err),
         }
     }
 
-    fn get_account_balance(&mut self, account: u32) -> PostgresResult<i64> {
-        let res = get_account_balance(self.get_conn(), account);
-        match res {
-            Ok(val) => Ok(val),
-            _ => Err(()),
-        }
+    fn get_account_balance(&mut self, account: u32) -> PostgresResult<Option<AccountBalance>> {
+        Ok(account_balance(&*self.get_conn()?, account, now_millis())?)
+    }
+
+    fn execute_transfers(&mut self, plan: &TransferPlan, req_id: i64) -> PostgresResult<()> {
+        let (settings, request) = (self.transfer, self.request.take());
+        self.run_txn(|txn| {
+            record_request(txn, &request)?;
+            transfer_funds(txn, plan, req_id, settings)
+        })
+    }
+
+    fn authorize_transfer(&mut self, hold: &Hold) -> PostgresResult<()> {
+        self.run_txn(|txn| authorize_transfer(txn, hold))
     }
 
-    fn execute_transfers(
+    fn capture_transfer(
         &mut self,
-        transfers: &[TransferComponent],
+        hold_id: u64,
+        amount: Option<i64>,
         req_id: i64,
-    ) -> PostgresResult<()> {
-        let res = execute_transfers(self.get_conn(), transfers, req_id);
-        match res {
-            Ok(_) => Ok(()),
-            _ => Err(()),
-        }
+    ) -> PostgresResult<Hold> {
+        let settings = self.transfer;
+        self.run_txn(|txn| capture_transfer(txn, hold_id, amount, req_id, settings))
+    }
+
+    fn void_transfer(&mut self, hold_id: u64) -> PostgresResult<Hold> {
+        self.run_txn(|txn| void_transfer(txn, hold_id))
+    }
+
+    fn reverse_transfer(
+        &mut self,
+        original_req_id: i64,
+        req_id: i64,
+    ) -> PostgresResult<Vec<TransferComponent>> {
+        let settings = self.transfer;
+        self.run_txn(|txn| reverse_transfer(txn, original_req_id, req_id, settings))
+    }
+
+    fn list_transactions(
+        &mut self,
+        filter: &TransactionFilter,
+    ) -> PostgresResult<Vec<TransactionRecord>> {
+        Ok(list_transactions(self.get_conn()?, filter)?)
     }
 
     fn reset(&mut self) -> PostgresResult<()> {
         self.reset_db_state()
     }
+
+    fn verify_ledger(&mut self, rebuild: bool) -> PostgresResult<LedgerReport> {
+        self.run_txn(|txn| verify_ledger(txn, rebuild))
+    }
+
+    fn set_deadline(&mut self, deadline: Option<DeadlineCheck>) {
+        self.deadline = deadline;
+    }
+
+    fn set_request(&mut self, request: Option<RequestRecord>) {
+        self.request = request;
+    }
+
+    fn set_max_balance(&mut self, max_balance: i64) {
+        self.transfer.max_balance = max_balance;
+    }
+}
+
+impl From<Error> for DataStoreError {
+    fn from(err: Error) -> DataStoreError {
+        if has_code(&err, &T_R_SERIALIZATION_FAILURE) {
+            return DataStoreError::SerializationConflict;
+        }
+        let unavailable = err.as_io().is_some()
+            || err.as_db().map_or(false, |e| {
+                e.code.code().starts_with("08")
+                    || e.code == ADMIN_SHUTDOWN
+                    || e.code == CANNOT_CONNECT_NOW
+                    || e.code == TOO_MANY_CONNECTIONS
+            });
+        if unavailable {
+            DataStoreError::Unavailable(err.to_string())
+        } else {
+            DataStoreError::Internal(err.to_string())
+        }
+    }
 }
 
-fn execute_txn<T, F>(conn: &Connection, op: F) -> Result<T, Error>
+pub(crate) fn has_code(err: &Error, code: &SqlState) -> bool {
+    err.as_db().map(|e| e.code == *code).unwrap_or(false)
+}
+
+fn execute_txn<T, F>(
+    conn: &Connection,
+    retry: &RetryPolicy,
+    metrics: &RetryMetrics,
+    deadline: Option<DeadlineCheck>,
+    op: F,
+) -> PostgresResult<T>
 where
-    F: Fn(&Transaction) -> Result<T, Error>,
+    F: Fn(&Transaction) -> PostgresResult<T>,
 {
     // Use serializable isolation to protect against concurrent writes
     let txn = conn.transaction()?;
     let mut cfg = Config::new();
     cfg.isolation_level(IsolationLevel::Serializable);
-    txn.set_config(&cfg).unwrap();
+    txn.set_config(&cfg)?;
 
-    loop {
+    let start = Instant::now();
+    let mut attempts = 0;
+    let res = loop {
+        attempts += 1;
         let sp = txn.savepoint("cockroach_restart")?;
-        match op(&sp).and_then(|t| sp.commit().map(|_| t)) {
-            Err(ref err)
-                if err
-                    .as_db()
-                    .map(|e| e.code == T_R_SERIALIZATION_FAILURE)
-                    .unwrap_or(false) => {}
+        match op(&sp).and_then(|t| sp.commit().map(|_| t).map_err(DataStoreError::from)) {
+            Err(DataStoreError::SerializationConflict) => {}
             r => break r,
         }
+
+        // restarting past the deadline only adds load to the contended rows
+        let elapsed = start.elapsed();
+        let expired = deadline.as_ref().map_or(false, |expired| expired());
+        if attempts >= retry.max_attempts || elapsed >= retry.max_elapsed || expired {
+            break Err(DataStoreError::Contention(attempts));
+        }
+        thread::sleep(retry.backoff(attempts).min(retry.max_elapsed - elapsed));
+    };
+    let exhausted = res.as_ref().err() == Some(&DataStoreError::Contention(attempts));
+    metrics.observe(attempts - 1, exhausted);
+    res.and_then(|t| txn.commit().map(|_| t).map_err(DataStoreError::from))
+}
+
+// Claims the req_id first, so a retry finds it before it touches any account. A concurrent
+// claim of the same req_id conflicts and the retry then sees the committed row.
+fn record_request(txn: &Transaction, request: &Option<RequestRecord>) -> PostgresResult<()> {
+    let request = match request {
+        Some(request) => request,
+        None => return Ok(()),
+    };
+    let inserted = txn.execute(
+        "INSERT INTO requests (req_id, fingerprint, reply) VALUES ($1, $2, $3)
+         ON CONFLICT (req_id) DO NOTHING",
+        &[&(request.req_id as i64), &request.fingerprint, &request.reply],
+    )?;
+    if inserted == 1 {
+        return Ok(());
+    }
+    let rows = txn.query(
+        "SELECT fingerprint, reply FROM requests WHERE req_id = $1",
+        &[&(request.req_id as i64)],
+    )?;
+    if rows.len() != 1 {
+        return Err(DataStoreError::Internal("Request Was Not Recorded".to_string()));
+    }
+    let row = rows.get(0);
+    let fingerprint: Vec<u8> = row.get(0);
+    if fingerprint == request.fingerprint {
+        Err(DataStoreError::RequestReplayed(row.get(1)))
+    } else {
+        Err(DataStoreError::RequestIdReused(request.req_id))
     }
-    .and_then(|t| txn.commit().map(|_| t))
 }
 
-fn create_account(conn: PostgresConnection, account: u32, req_id: u64, balance: i64) -> Result<u64, Error> {
-    conn.execute(
+fn create_account(
+    txn: &Transaction,
+    account: u32,
+    req_id: u64,
+    balance: i64,
+) -> PostgresResult<u64> {
+    let res = txn.execute(
         "INSERT INTO accounts (balance, id, creation_request) VALUES ($1, $2, $3)",
         &[&balance, &(account as i64), &(req_id as i64)],
-    )
+    );
+    let inserted = match res {
+        Ok(inserted) => inserted,
+        Err(ref err) if has_code(err, &UNIQUE_VIOLATION) => {
+            return Err(DataStoreError::AccountExists(account))
+        }
+        Err(ref err) if has_code(err, &CHECK_VIOLATION) => {
+            return Err(DataStoreError::InvalidBalance(account, balance))
+        }
+        Err(err) => return Err(DataStoreError::from(err)),
+    };
+    // the opening balance is the account's first ledger entry
+    if balance != 0 {
+        txn.execute(
+            "INSERT INTO transactions
+             (transaction_index, req_id, account_id, amount, created_at, kind)
+             SELECT 0, creation_request, id, balance, created_at, 'opening'
+             FROM accounts WHERE id = $1",
+            &[&(account as i64)],
+        )?;
+    }
+    Ok(inserted)
 }
 
-fn get_account_balance(conn: PostgresConnection, account: u32) -> Result<i64, Error> {
-    let balance = conn.query("SELECT balance FROM accounts WHERE id=$1", &[&(account as i64)])?;
+fn account_balance<C: GenericConnection + ?Sized>(
+    conn: &C,
+    account: u32,
+    now: i64,
+) -> Result<Option<AccountBalance>, Error> {
+    let balance = conn.query(BALANCE_QUERY, &[&(account as i64), &now])?;
     // If no rows are returned, need to inform user
     if balance.len() != 1 {
-        Ok(-1)
+        Ok(None)
     } else {
-        Ok(balance.get(0).get(0))
+        let row = balance.get(0);
+        Ok(Some(AccountBalance { ledger: row.get(0), available: row.get(1) }))
     }
 }
 
-fn execute_transfers(
-    conn: PostgresConnection,
-    transfers: &[TransferComponent],
-    req_id: i64,
-) -> Result<(), Error> {
-    execute_txn(&conn, |txn| transfer_funds(txn, transfers, req_id))
-}
-
 fn transfer_funds(
     txn: &Transaction,
-    transfers: &[TransferComponent],
+    plan: &TransferPlan,
     req_id: i64,
-) -> Result<(), Error> {
-    for (i, transfer) in transfers.iter().enumerate() {
-        let delta: i64 = transfer.get_money_delta();
-        let account = transfer.get_account_id() as i64;
+    settings: TransferSettings,
+) -> PostgresResult<()> {
+    check_unchanged(txn, plan)?;
+    if settings.batch {
+        return transfer_funds_batched(txn, plan, req_id, settings.max_balance);
+    }
+    // one UPDATE per account in account id order, the sum is taken as NUMERIC so it cannot
+    // overflow before it is compared with the limit
+    for leg in plan.legs() {
         let res = txn.execute(
-            "UPDATE accounts SET balance = balance + $1 WHERE id = $2",
-            &[&delta, &account],
-        )?;
-        if res == 0 {
-            return Err(Error::from(io::Error::from(io::ErrorKind::NotFound)))
+            "UPDATE accounts SET balance = balance + $1::INT8
+             WHERE id = $2 AND balance::NUMERIC + $1::INT8 <= $3::INT8",
+            &[&leg.delta, &i64::from(leg.account_id), &settings.max_balance],
+        );
+        match res {
+            Ok(0) => return Err(failed_leg(txn, &[*leg], &HashSet::new())?),
+            Ok(_) => {}
+            Err(ref err) if has_code(err, &CHECK_VIOLATION) => {
+                return Err(DataStoreError::InsufficientFunds(leg.account_id))
+            }
+            Err(err) => return Err(DataStoreError::from(err)),
         }
+    }
+    // the log keeps the components as the client sent them
+    for entry in plan.entries() {
         let res = txn.execute(
             "INSERT INTO transactions (req_id, account_id, amount, transaction_index) VALUES ($1, $2, $3, $4)",
-            &[&req_id, &account, &delta, &(i as i64)],
+            &[
+                &req_id,
+                &i64::from(entry.account_id),
+                &entry.amount,
+                &i64::from(entry.transaction_index),
+            ],
         )?;
         if res == 0 {
-            return Err(Error::from(io::Error::from(io::ErrorKind::NotFound)))
+            return Err(DataStoreError::Internal("Transaction Leg Was Not Recorded".to_string()))
+        }
+    }
+
+    // balance_check only covers the ledger balance, debits may not dip into held funds
+    let now = now_millis();
+    for account in plan.debited() {
+        if let Some(balance) = account_balance(txn, account, now)? {
+            if balance.available < 0 {
+                return Err(DataStoreError::InsufficientFunds(account));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Accounts whose components cancel out keep their balance, so they are read instead of
+// updated. Their ledger rows still need them to exist.
+fn check_unchanged(txn: &Transaction, plan: &TransferPlan) -> PostgresResult<()> {
+    for accounts in plan.unchanged().chunks(BATCH_ROWS) {
+        let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+        let ids = placeholders(&mut params, accounts.iter().map(|&id| i64::from(id)).collect());
+        let query = format!("SELECT id::INT8 FROM accounts WHERE id IN ({})", ids);
+        let params: Vec<&dyn ToSql> = params.iter().map(|p| &**p).collect();
+        let found: HashSet<u32> = txn
+            .query(&query, &params)?
+            .iter()
+            .map(|row| row.get::<_, i64>(0) as u32)
+            .collect();
+        if let Some(&account) = accounts.iter().find(|account| !found.contains(account)) {
+            return Err(DataStoreError::AccountNotFound(account));
+        }
+    }
+    Ok(())
+}
+
+// Same effect as the per leg path in three round trips for up to BATCH_ROWS legs
+fn transfer_funds_batched(
+    txn: &Transaction,
+    plan: &TransferPlan,
+    req_id: i64,
+    max_balance: i64,
+) -> PostgresResult<()> {
+    for legs in plan.legs().chunks(BATCH_ROWS) {
+        // legs that would break balance_check or the limit are left out instead of failing
+        // the statement, so the leg at fault can be reported
+        let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+        let rows = legs.iter().map(|leg| vec![i64::from(leg.account_id), leg.delta]).collect();
+        let rows = values(&mut params, rows);
+        let max_balance = placeholders(&mut params, vec![max_balance]);
+        let query = format!(
+            "UPDATE accounts SET balance = accounts.balance + v.delta
+             FROM (VALUES {}) AS v (id, delta)
+             WHERE accounts.id = v.id
+                AND accounts.balance::NUMERIC + v.delta BETWEEN 0 AND {}::INT8
+             RETURNING accounts.id::INT8",
+            rows, max_balance
+        );
+        let params: Vec<&dyn ToSql> = params.iter().map(|p| &**p).collect();
+        let updated: HashSet<u32> = txn
+            .query(&query, &params)?
+            .iter()
+            .map(|row| row.get::<_, i64>(0) as u32)
+            .collect();
+        if updated.len() != legs.len() {
+            return Err(failed_leg(txn, legs, &updated)?);
+        }
+    }
+
+    // the log keeps the components as the client sent them
+    for entries in plan.entries().chunks(BATCH_ROWS) {
+        let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+        let rows = entries
+            .iter()
+            .map(|e| {
+                let index = i64::from(e.transaction_index);
+                vec![req_id, i64::from(e.account_id), e.amount, index]
+            })
+            .collect();
+        let query = format!(
+            "INSERT INTO transactions (req_id, account_id, amount, transaction_index) VALUES {}",
+            values(&mut params, rows)
+        );
+        let params: Vec<&dyn ToSql> = params.iter().map(|p| &**p).collect();
+        if txn.execute(&query, &params)? != entries.len() as u64 {
+            return Err(DataStoreError::Internal("Transaction Leg Was Not Recorded".to_string()));
         }
     }
+
+    // balance_check only covers the ledger balance, debits may not dip into held funds
+    let debited = plan.debited();
+    if debited.is_empty() {
+        return Ok(());
+    }
+    let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+    let ids = placeholders(&mut params, debited.iter().map(|&id| i64::from(id)).collect());
+    let now = placeholders(&mut params, vec![now_millis()]);
+    let query = format!(
+        "SELECT a.id::INT8 FROM accounts a
+         WHERE a.id IN ({}) AND a.balance - COALESCE((
+             SELECT SUM(h.amount) FROM holds h
+             WHERE h.account_id = a.id AND h.status = 'active' AND h.expires_at > {}
+         ), 0) < 0
+         ORDER BY a.id LIMIT 1",
+        ids, now
+    );
+    let params: Vec<&dyn ToSql> = params.iter().map(|p| &**p).collect();
+    let overdrawn = txn.query(&query, &params)?;
+    if !overdrawn.is_empty() {
+        let account = overdrawn.get(0).get::<_, i64>(0) as u32;
+        return Err(DataStoreError::InsufficientFunds(account));
+    }
     Ok(())
 }
+
+// The error for the first leg, in account id order, that an UPDATE skipped
+fn failed_leg(
+    txn: &Transaction,
+    legs: &[TransferLeg],
+    updated: &HashSet<u32>,
+) -> PostgresResult<DataStoreError> {
+    let skipped: Vec<&TransferLeg> =
+        legs.iter().filter(|leg| !updated.contains(&leg.account_id)).collect();
+    let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+    let ids = skipped.iter().map(|leg| i64::from(leg.account_id)).collect();
+    let query = format!(
+        "SELECT id::INT8, balance FROM accounts WHERE id IN ({})",
+        placeholders(&mut params, ids)
+    );
+    let params: Vec<&dyn ToSql> = params.iter().map(|p| &**p).collect();
+    let balances: HashMap<u32, i64> = txn
+        .query(&query, &params)?
+        .iter()
+        .map(|row| (row.get::<_, i64>(0) as u32, row.get(1)))
+        .collect();
+    let leg = skipped[0];
+    // widened so the sum that was refused cannot overflow here either
+    Ok(match balances.get(&leg.account_id) {
+        None => DataStoreError::AccountNotFound(leg.account_id),
+        Some(&balance) if i128::from(balance) + i128::from(leg.delta) < 0 => {
+            DataStoreError::InsufficientFunds(leg.account_id)
+        }
+        Some(_) => DataStoreError::BalanceOutOfRange(leg.account_id),
+    })
+}
+
+fn reverse_transfer(
+    txn: &Transaction,
+    original_req_id: i64,
+    req_id: i64,
+    settings: TransferSettings,
+) -> PostgresResult<Vec<TransferComponent>> {
+    // the primary key on original_req_id refuses a second reversal
+    let res = txn.execute(
+        "INSERT INTO reversals (original_req_id, reversal_req_id) VALUES ($1, $2)",
+        &[&original_req_id, &req_id],
+    );
+    match res {
+        Ok(_) => {}
+        Err(ref err) if has_code(err, &UNIQUE_VIOLATION) => {
+            return Err(DataStoreError::AlreadyReversed(original_req_id as u64))
+        }
+        Err(err) => return Err(DataStoreError::from(err)),
+    }
+
+    let rows = txn.query(
+        "SELECT account_id::INT8, amount FROM transactions
+         WHERE req_id = $1 AND kind = 'transfer' ORDER BY transaction_index",
+        &[&original_req_id],
+    )?;
+    if rows.is_empty() {
+        return Err(DataStoreError::TransferNotFound(original_req_id as u64));
+    }
+    let transfers: Vec<TransferComponent> = rows
+        .iter()
+        .map(|row| {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(row.get::<_, i64>(0) as u32);
+            comp.set_money_delta(-row.get::<_, i64>(1));
+            comp
+        })
+        .collect();
+    transfer_funds(txn, &TransferPlan::new(&transfers), req_id, settings)?;
+    Ok(transfers)
+}
+
+fn verify_ledger(txn: &Transaction, rebuild: bool) -> PostgresResult<LedgerReport> {
+    let accounts: i64 = txn.query("SELECT COUNT(*) FROM accounts", &[])?.get(0).get(0);
+    let mismatches: Vec<LedgerMismatch> = txn
+        .query(MISMATCH_QUERY, &[])?
+        .iter()
+        .map(|row| LedgerMismatch {
+            account_id: row.get::<_, i64>(0) as u32,
+            stored: row.get(1),
+            projected: row.get(2),
+        })
+        .collect();
+    let backfilled = txn
+        .query(BACKFILL_QUERY, &[])?
+        .iter()
+        .map(|row| LedgerBackfill { account_id: row.get::<_, i64>(0) as u32, amount: row.get(1) })
+        .collect();
+    if rebuild {
+        for mismatch in &mismatches {
+            txn.execute(
+                "UPDATE accounts SET balance = $1 WHERE id = $2",
+                &[&mismatch.projected, &i64::from(mismatch.account_id)],
+            )?;
+        }
+    }
+    Ok(LedgerReport { accounts: accounts as u64, mismatches, backfilled })
+}
+
+fn authorize_transfer(txn: &Transaction, hold: &Hold) -> PostgresResult<()> {
+    let to_account = txn.query(
+        "SELECT id FROM accounts WHERE id = $1",
+        &[&i64::from(hold.to_account_id)],
+    )?;
+    if to_account.is_empty() {
+        return Err(DataStoreError::AccountNotFound(hold.to_account_id));
+    }
+    match account_balance(txn, hold.account_id, now_millis())? {
+        None => return Err(DataStoreError::AccountNotFound(hold.account_id)),
+        Some(balance) if balance.available < hold.amount => {
+            return Err(DataStoreError::InsufficientFunds(hold.account_id))
+        }
+        Some(_) => {}
+    }
+
+    let res = txn.execute(
+        "INSERT INTO holds (id, account_id, to_account_id, amount, status, expires_at)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+        &[
+            &(hold.id as i64),
+            &i64::from(hold.account_id),
+            &i64::from(hold.to_account_id),
+            &hold.amount,
+            &hold.status.as_str(),
+            &hold.expires_at,
+        ],
+    );
+    match res {
+        Ok(_) => Ok(()),
+        Err(ref err) if has_code(err, &UNIQUE_VIOLATION) => {
+            Err(DataStoreError::HoldExists(hold.id))
+        }
+        Err(err) => Err(DataStoreError::from(err)),
+    }
+}
+
+fn capture_transfer(
+    txn: &Transaction,
+    hold_id: u64,
+    amount: Option<i64>,
+    req_id: i64,
+    settings: TransferSettings,
+) -> PostgresResult<Hold> {
+    let mut hold = get_hold(txn, hold_id)?;
+    if hold.status != HoldStatus::Active {
+        return Err(DataStoreError::HoldNotActive(hold_id));
+    }
+    if !hold.is_active(now_millis()) {
+        return Err(DataStoreError::HoldExpired(hold_id));
+    }
+    let amount = amount.unwrap_or(hold.amount);
+    if amount > hold.amount {
+        return Err(DataStoreError::CaptureExceedsHold(hold_id, amount));
+    }
+
+    // the hold stops reserving funds before the captured amount is moved
+    hold.status = HoldStatus::Captured;
+    hold.captured = amount;
+    txn.execute(
+        "UPDATE holds SET status = $1, captured = $2 WHERE id = $3",
+        &[&hold.status.as_str(), &amount, &(hold_id as i64)],
+    )?;
+    let plan = TransferPlan::new(&hold.components(amount));
+    transfer_funds(txn, &plan, req_id, settings)?;
+    Ok(hold)
+}
+
+fn void_transfer(txn: &Transaction, hold_id: u64) -> PostgresResult<Hold> {
+    let mut hold = get_hold(txn, hold_id)?;
+    if hold.status != HoldStatus::Active {
+        return Err(DataStoreError::HoldNotActive(hold_id));
+    }
+    hold.status = HoldStatus::Voided;
+    txn.execute(
+        "UPDATE holds SET status = $1 WHERE id = $2",
+        &[&hold.status.as_str(), &(hold_id as i64)],
+    )?;
+    Ok(hold)
+}
+
+fn get_hold(txn: &Transaction, hold_id: u64) -> PostgresResult<Hold> {
+    let rows = txn.query(
+        "SELECT account_id::INT8, to_account_id::INT8, amount, captured, status, expires_at
+         FROM holds WHERE id = $1",
+        &[&(hold_id as i64)],
+    )?;
+    if rows.is_empty() {
+        return Err(DataStoreError::HoldNotFound(hold_id));
+    }
+    let row = rows.get(0);
+    let status: String = row.get(4);
+    let status = HoldStatus::parse(&status)
+        .ok_or_else(|| DataStoreError::Internal(format!("Unknown Hold Status {}", status)))?;
+    Ok(Hold {
+        id: hold_id,
+        account_id: row.get::<_, i64>(0) as u32,
+        to_account_id: row.get::<_, i64>(1) as u32,
+        amount: row.get(2),
+        captured: row.get(3),
+        status,
+        expires_at: row.get(5),
+    })
+}
+
+fn list_transactions(
+    conn: PostgresConnection,
+    filter: &TransactionFilter,
+) -> Result<Vec<TransactionRecord>, Error> {
+    // opening rows record no transfer, so they aren't listed
+    let mut clauses: Vec<String> = vec!["kind = 'transfer'".to_string()];
+    let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+    if !filter.account_ids.is_empty() {
+        let ids = filter.account_ids.iter().map(|&id| i64::from(id)).collect();
+        clauses.push(format!("account_id IN ({})", placeholders(&mut params, ids)));
+    }
+    if !filter.req_ids.is_empty() {
+        let ids = filter.req_ids.iter().map(|&id| id as i64).collect();
+        clauses.push(format!("req_id IN ({})", placeholders(&mut params, ids)));
+    }
+    if let Some(start) = filter.start_time {
+        let start = placeholders(&mut params, vec![start]);
+        clauses.push(format!("{} >= {}", CREATED_AT_MILLIS, start));
+    }
+    if let Some(end) = filter.end_time {
+        let end = placeholders(&mut params, vec![end]);
+        clauses.push(format!("{} < {}", CREATED_AT_MILLIS, end));
+    }
+    if let Some(after) = filter.after_id {
+        clauses.push(format!("id > {}", placeholders(&mut params, vec![after as i64])));
+    }
+
+    let limit = placeholders(&mut params, vec![filter.limit as i64]);
+    let query = format!(
+        "SELECT id, req_id, account_id::INT8, amount, transaction_index::INT8, {}
+         FROM transactions WHERE {} ORDER BY id LIMIT {}",
+        CREATED_AT_MILLIS,
+        clauses.join(" AND "),
+        limit
+    );
+    let params: Vec<&dyn ToSql> = params.iter().map(|p| &**p).collect();
+
+    let rows = conn.query(&query, &params)?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let mut record = TransactionRecord::new();
+            record.set_id(row.get::<_, i64>(0) as u64);
+            record.set_req_id(row.get::<_, i64>(1) as u64);
+            record.set_account_id(row.get::<_, i64>(2) as u32);
+            record.set_amount(row.get(3));
+            record.set_transaction_index(row.get::<_, i64>(4) as u32);
+            record.set_created_at(row.get(5));
+            record
+        })
+        .collect())
+}
+
+// Appends the values as query parameters and returns their comma separated placeholders
+fn placeholders(params: &mut Vec<Box<dyn ToSql>>, values: Vec<i64>) -> String {
+    values
+        .into_iter()
+        .map(|value| {
+            params.push(Box::new(value));
+            format!("${}", params.len())
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Appends each row as query parameters and returns them as a VALUES list,
+// e.g. ($1::INT8, $2::INT8), ($3::INT8, $4::INT8)
+fn values(params: &mut Vec<Box<dyn ToSql>>, rows: Vec<Vec<i64>>) -> String {
+    rows.into_iter()
+        .map(|row| {
+            let columns: Vec<String> = row
+                .into_iter()
+                .map(|value| format!("{}::INT8", placeholders(params, vec![value])))
+                .collect();
+            format!("({})", columns.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::config::{ServerConfig, ENV_PREFIX};
+
+    // Recreates the tables in ACCOUNTANT_DATABASE_URL, or the default database. Run with
+    // `cargo test --lib -- --ignored --test-threads=1` against Postgres or CockroachDB.
+    fn fresh_store() -> Box<PostgresDataStore> {
+        let url = env::var(format!("{}DATABASE_URL", ENV_PREFIX))
+            .unwrap_or_else(|_| ServerConfig::default().database_url);
+        let mut store = PostgresDataStore::new(&url);
+        store.reset_db_state().unwrap();
+        Box::new(store)
+    }
+
+    datastore_conformance!(fresh_store(), #[ignore]);
+
+    #[test]
+    #[ignore]
+    fn test_verify_ledger() {
+        let mut store = fresh_store();
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        let mut leg = TransferComponent::new();
+        leg.set_account_id(1);
+        leg.set_money_delta(-30);
+        let mut other = leg.clone();
+        other.set_account_id(2);
+        other.set_money_delta(30);
+        store.execute_transfers(&TransferPlan::new(&[leg, other]), 3).unwrap();
+
+        // a balance changed behind the ledger's back
+        let conn = store.get_conn().unwrap();
+        conn.execute("UPDATE accounts SET balance = 10 WHERE id = 1", &[]).unwrap();
+        let mismatch = LedgerMismatch { account_id: 1, stored: 10, projected: 70 };
+        let found = LedgerReport {
+            accounts: 2,
+            mismatches: vec![mismatch],
+            backfilled: Vec::new(),
+        };
+        assert_eq!(store.verify_ledger(false), Ok(found.clone()));
+        assert_eq!(store.verify_ledger(true), Ok(found));
+        assert_eq!(store.get_account_balance(1).unwrap().unwrap().ledger, 70);
+        assert!(store.verify_ledger(false).unwrap().mismatches.is_empty());
+    }
+
+    // Times the batched and per leg transfer paths on the same 50 leg transfer. Run with
+    // `cargo test --release --lib bench_transfer_paths -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_transfer_paths() {
+        const LEGS: u32 = 50;
+        const TRANSFERS: u32 = 200;
+        // one debit of 49 and a credit of 1 to each other account
+        let components: Vec<TransferComponent> = (1..=LEGS)
+            .map(|account_id| {
+                let mut component = TransferComponent::new();
+                component.set_account_id(account_id);
+                component.set_money_delta(if account_id == 1 { 1 - i64::from(LEGS) } else { 1 });
+                component
+            })
+            .collect();
+        let plan = TransferPlan::new(&components);
+
+        for &batch in &[false, true] {
+            let mut store = fresh_store().with_batched_transfers(batch);
+            store.create_account(1, 1, i64::from((LEGS - 1) * TRANSFERS)).unwrap();
+            for account in 2..=LEGS {
+                store.create_account(account, u64::from(account), 0).unwrap();
+            }
+            let started = Instant::now();
+            for i in 0..TRANSFERS {
+                store.execute_transfers(&plan, i64::from(LEGS + 1 + i)).unwrap();
+            }
+            let elapsed = started.elapsed();
+            println!(
+                "batch_transfers = {}: {} transfers in {:?}, {:.2}ms each",
+                batch,
+                TRANSFERS,
+                elapsed,
+                elapsed.as_secs_f64() * 1_000.0 / f64::from(TRANSFERS)
+            );
+            assert_eq!(store.get_account_balance(1).unwrap().unwrap().ledger, 0);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn test_migrate() {
+        let mut store = fresh_store();
+        assert!(store.migrate().unwrap().is_empty());
+
+        // a version this server doesn't know means a newer server migrated the database
+        let conn = store.get_conn().unwrap();
+        let newer = migrations::latest_version() as i32 + 1;
+        conn.execute("INSERT INTO schema_migrations (version, name) VALUES ($1, 'newer')", &[
+            &newer,
+        ])
+        .unwrap();
+        let res = store.migrate();
+        conn.execute("DELETE FROM schema_migrations WHERE version = $1", &[&newer]).unwrap();
+        match res {
+            Err(DataStoreError::Internal(reason)) => assert!(reason.contains("Is Newer")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
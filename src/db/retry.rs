@@ -0,0 +1,165 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+
+// How often a transaction that hit a serialization conflict is restarted
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    // attempts including the first one
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    // total time a transaction may spend retrying, the request's own deadline also applies
+    pub max_elapsed: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(5),
+            max_backoff: Duration::from_millis(500),
+            max_elapsed: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    // Exponential backoff before attempt `attempt` (counting from 1), with full jitter
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let shift = attempt.saturating_sub(1).min(16);
+        let backoff = (self.initial_backoff * (1 << shift)).min(self.max_backoff);
+        let millis = backoff.as_secs() * 1_000 + u64::from(backoff.subsec_millis());
+        if millis == 0 {
+            return backoff;
+        }
+        Duration::from_millis(rand::thread_rng().gen_range(0, millis + 1))
+    }
+}
+
+// Upper bounds of the retries-per-transaction buckets, the last bucket takes everything above
+const RETRY_BUCKETS: [u64; 5] = [0, 1, 2, 4, 8];
+
+#[derive(Default)]
+struct Counters {
+    transactions: AtomicU64,
+    retries: AtomicU64,
+    // transactions that gave up with DataStoreError::Contention
+    exhausted: AtomicU64,
+    // transactions by how many times they were retried
+    buckets: [AtomicU64; RETRY_BUCKETS.len() + 1],
+}
+
+// Retry counters shared by every clone of a data store
+#[derive(Clone, Default)]
+pub struct RetryMetrics {
+    counters: Arc<Counters>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetrySnapshot {
+    pub transactions: u64,
+    pub retries: u64,
+    pub exhausted: u64,
+    // (upper bound, transactions), None is the bucket above the last bound
+    pub retries_per_transaction: Vec<(Option<u64>, u64)>,
+}
+
+impl RetryMetrics {
+    pub fn new() -> RetryMetrics {
+        RetryMetrics::default()
+    }
+
+    // Records a finished transaction and how many times it was retried
+    pub fn observe(&self, retries: u32, exhausted: bool) {
+        let retries = u64::from(retries);
+        let counters = &self.counters;
+        counters.transactions.fetch_add(1, Ordering::Relaxed);
+        counters.retries.fetch_add(retries, Ordering::Relaxed);
+        if exhausted {
+            counters.exhausted.fetch_add(1, Ordering::Relaxed);
+        }
+        let bucket = RETRY_BUCKETS
+            .iter()
+            .position(|&bound| retries <= bound)
+            .unwrap_or(RETRY_BUCKETS.len());
+        counters.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> RetrySnapshot {
+        let counters = &self.counters;
+        let bounds = RETRY_BUCKETS.iter().map(|&bound| Some(bound)).chain(Some(None));
+        RetrySnapshot {
+            transactions: counters.transactions.load(Ordering::Relaxed),
+            retries: counters.retries.load(Ordering::Relaxed),
+            exhausted: counters.exhausted.load(Ordering::Relaxed),
+            retries_per_transaction: bounds
+                .zip(counters.buckets.iter())
+                .map(|(bound, count)| (bound, count.load(Ordering::Relaxed)))
+                .collect(),
+        }
+    }
+}
+
+impl RetrySnapshot {
+    // Prometheus text format, e.g. for the node_exporter textfile collector
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# TYPE accountant_txn_total counter");
+        let _ = writeln!(out, "accountant_txn_total {}", self.transactions);
+        let _ = writeln!(out, "# TYPE accountant_txn_retries_total counter");
+        let _ = writeln!(out, "accountant_txn_retries_total {}", self.retries);
+        let _ = writeln!(out, "# TYPE accountant_txn_contention_total counter");
+        let _ = writeln!(out, "accountant_txn_contention_total {}", self.exhausted);
+        let _ = writeln!(out, "# TYPE accountant_txn_retries histogram");
+        let mut cumulative = 0;
+        for &(bound, count) in &self.retries_per_transaction {
+            cumulative += count;
+            let le = bound.map_or("+Inf".to_string(), |bound| bound.to_string());
+            let _ = writeln!(out, "accountant_txn_retries_bucket{{le=\"{}\"}} {}", le, cumulative);
+        }
+        let _ = writeln!(out, "accountant_txn_retries_sum {}", self.retries);
+        let _ = writeln!(out, "accountant_txn_retries_count {}", self.transactions);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(40),
+            max_elapsed: Duration::from_secs(1),
+        };
+        for _ in 0..100 {
+            assert!(policy.backoff(1) <= Duration::from_millis(10));
+            assert!(policy.backoff(3) <= Duration::from_millis(40));
+            assert!(policy.backoff(30) <= Duration::from_millis(40));
+        }
+    }
+
+    #[test]
+    fn test_metrics() {
+        let metrics = RetryMetrics::new();
+        metrics.observe(0, false);
+        metrics.observe(3, false);
+        metrics.observe(20, true);
+
+        let snapshot = metrics.clone().snapshot();
+        assert_eq!((snapshot.transactions, snapshot.retries, snapshot.exhausted), (3, 23, 1));
+        let counts: Vec<u64> = snapshot.retries_per_transaction.iter().map(|b| b.1).collect();
+        assert_eq!(counts, vec![1, 0, 0, 1, 0, 1]);
+
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("accountant_txn_retries_bucket{le=\"4\"} 2\n"));
+        assert!(text.contains("accountant_txn_retries_bucket{le=\"+Inf\"} 3\n"));
+    }
+}
@@ -0,0 +1,827 @@
+use std::os::raw::c_int;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+use rusqlite::ffi;
+use rusqlite::{params, Connection, Error, ErrorCode, OptionalExtension, ToSql};
+use rusqlite::{Transaction, TransactionBehavior, NO_PARAMS};
+
+use crate::db::retry::{RetryMetrics, RetryPolicy};
+use crate::idempotency::RequestRecord;
+use crate::proto::accounting::{TransactionRecord, TransferComponent};
+use crate::transfer::{AmountLimits, TransferPlan};
+use crate::{now_millis, AccountBalance, DataStore, DataStoreError, DeadlineCheck, Hold, HoldStatus};
+use crate::{LedgerMismatch, LedgerReport, TransactionFilter};
+
+pub type SqliteResult<T> = Result<T, DataStoreError>;
+
+// See the file for how it differs from the Postgres migrations
+const SCHEMA: &str = include_str!("../sql/sqlite.sql");
+
+// id, stored and projected balance of every account whose rows don't add up to its balance
+const MISMATCH_QUERY: &str = "
+    SELECT accounts.id, accounts.balance, COALESCE(SUM(transactions.amount), 0) AS projected
+    FROM accounts LEFT JOIN transactions ON transactions.account_id = accounts.id
+    GROUP BY accounts.id, accounts.balance
+    HAVING accounts.balance != projected
+    ORDER BY accounts.id";
+
+// ledger and available balance of account ?1 at time ?2
+const BALANCE_QUERY: &str = "SELECT balance, balance - COALESCE((
+        SELECT SUM(amount) FROM holds
+        WHERE account_id = ?1 AND status = 'active' AND expires_at > ?2
+    ), 0)
+    FROM accounts WHERE id = ?1";
+
+// Single node store in one SQLite file. Writes take the write lock up front with BEGIN
+// IMMEDIATE and WAL mode lets reads go on while a transfer commits.
+pub struct SqliteDataStore {
+    path: String,
+    // every clone opens its own connection on first use
+    conn: Mutex<Option<Connection>>,
+    retry: RetryPolicy,
+    metrics: RetryMetrics,
+    max_balance: i64,
+    // deadline of the request being served, taken by the next transaction
+    deadline: Option<DeadlineCheck>,
+    // recorded by the next create or transfer
+    request: Option<RequestRecord>,
+}
+
+impl Clone for SqliteDataStore {
+    fn clone(&self) -> SqliteDataStore {
+        SqliteDataStore {
+            path: self.path.clone(),
+            conn: Mutex::new(None),
+            retry: self.retry.clone(),
+            metrics: self.metrics.clone(),
+            max_balance: self.max_balance,
+            deadline: None,
+            request: None,
+        }
+    }
+}
+
+impl SqliteDataStore {
+    // Creates the file and its tables if they don't exist. Each connection opens the path
+    // separately, so ":memory:" would give every clone its own empty database.
+    pub fn open(path: &str) -> SqliteResult<SqliteDataStore> {
+        let retry = RetryPolicy::default();
+        let conn = open_connection(path, &retry)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(SqliteDataStore {
+            path: path.to_string(),
+            conn: Mutex::new(Some(conn)),
+            retry,
+            metrics: RetryMetrics::new(),
+            max_balance: AmountLimits::default().max_balance,
+            deadline: None,
+            request: None,
+        })
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> SqliteDataStore {
+        self.retry = retry;
+        // connections opened under the old policy wait for the lock as it said
+        self.conn = Mutex::new(None);
+        self
+    }
+
+    // shared by every clone of this store
+    pub fn retry_metrics(&self) -> RetryMetrics {
+        self.metrics.clone()
+    }
+
+    // Empties every table in one transaction, like the Postgres TRUNCATE
+    pub fn reset_db_state(&mut self) -> SqliteResult<()> {
+        self.run_txn(|txn| {
+            Ok(txn.execute_batch(
+                "DELETE FROM accounts;
+                 DELETE FROM transactions;
+                 DELETE FROM holds;
+                 DELETE FROM reversals;
+                 DELETE FROM requests;",
+            )?)
+        })
+    }
+
+    fn with_conn<T, F>(&self, op: F) -> SqliteResult<T>
+    where
+        F: FnOnce(&mut Connection) -> SqliteResult<T>,
+    {
+        let mut conn = self.conn.lock().unwrap();
+        if conn.is_none() {
+            *conn = Some(open_connection(&self.path, &self.retry)?);
+        }
+        op(conn.as_mut().unwrap())
+    }
+
+    fn run_txn<T, F>(&mut self, op: F) -> SqliteResult<T>
+    where
+        F: Fn(&Transaction) -> SqliteResult<T>,
+    {
+        let deadline = self.deadline.take();
+        let (retry, metrics) = (&self.retry, &self.metrics);
+        self.with_conn(|conn| execute_txn(conn, retry, metrics, deadline, op))
+    }
+}
+
+impl DataStore for SqliteDataStore {
+    fn create_account(&mut self, account: u32, req_id: u64, balance: i64) -> SqliteResult<u64> {
+        let request = self.request.take();
+        let res = self.run_txn(|txn| {
+            record_request(txn, &request)?;
+            let res = txn.execute(
+                "INSERT INTO accounts (balance, id, creation_request, created_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![balance, account, req_id as i64, now_millis()],
+            );
+            let inserted = match constraint(&res) {
+                Some(ffi::SQLITE_CONSTRAINT_PRIMARYKEY) => {
+                    return Err(DataStoreError::AccountExists(account))
+                }
+                Some(ffi::SQLITE_CONSTRAINT_CHECK) => {
+                    return Err(DataStoreError::InvalidBalance(account, balance))
+                }
+                _ => res? as u64,
+            };
+            // the opening balance is the account's first ledger entry
+            if balance != 0 {
+                txn.execute(
+                    "INSERT INTO transactions
+                     (req_id, account_id, amount, transaction_index, created_at, kind)
+                     SELECT creation_request, id, balance, 0, created_at, 'opening'
+                     FROM accounts WHERE id = ?1",
+                    params![account],
+                )?;
+            }
+            Ok(inserted)
+        });
+        match res {
+            Ok(1) => Ok(1),
+            Ok(_) => Err(DataStoreError::Internal("Account Was Not Inserted".to_string())),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn get_account_balance(&mut self, account: u32) -> SqliteResult<Option<AccountBalance>> {
+        self.with_conn(|conn| Ok(account_balance(conn, account, now_millis())?))
+    }
+
+    fn execute_transfers(&mut self, plan: &TransferPlan, req_id: i64) -> SqliteResult<()> {
+        let (max_balance, request) = (self.max_balance, self.request.take());
+        self.run_txn(|txn| {
+            record_request(txn, &request)?;
+            transfer_funds(txn, plan, req_id, max_balance)
+        })
+    }
+
+    fn authorize_transfer(&mut self, hold: &Hold) -> SqliteResult<()> {
+        self.run_txn(|txn| authorize_transfer(txn, hold))
+    }
+
+    fn capture_transfer(
+        &mut self,
+        hold_id: u64,
+        amount: Option<i64>,
+        req_id: i64,
+    ) -> SqliteResult<Hold> {
+        let max_balance = self.max_balance;
+        self.run_txn(|txn| capture_transfer(txn, hold_id, amount, req_id, max_balance))
+    }
+
+    fn void_transfer(&mut self, hold_id: u64) -> SqliteResult<Hold> {
+        self.run_txn(|txn| void_transfer(txn, hold_id))
+    }
+
+    fn reverse_transfer(
+        &mut self,
+        original_req_id: i64,
+        req_id: i64,
+    ) -> SqliteResult<Vec<TransferComponent>> {
+        let max_balance = self.max_balance;
+        self.run_txn(|txn| reverse_transfer(txn, original_req_id, req_id, max_balance))
+    }
+
+    fn list_transactions(
+        &mut self,
+        filter: &TransactionFilter,
+    ) -> SqliteResult<Vec<TransactionRecord>> {
+        self.with_conn(|conn| Ok(list_transactions(conn, filter)?))
+    }
+
+    fn reset(&mut self) -> SqliteResult<()> {
+        self.reset_db_state()
+    }
+
+    fn verify_ledger(&mut self, rebuild: bool) -> SqliteResult<LedgerReport> {
+        self.run_txn(|txn| verify_ledger(txn, rebuild))
+    }
+
+    fn set_deadline(&mut self, deadline: Option<DeadlineCheck>) {
+        self.deadline = deadline;
+    }
+
+    fn set_request(&mut self, request: Option<RequestRecord>) {
+        self.request = request;
+    }
+
+    fn set_max_balance(&mut self, max_balance: i64) {
+        self.max_balance = max_balance;
+    }
+}
+
+impl From<Error> for DataStoreError {
+    fn from(err: Error) -> DataStoreError {
+        let code = match err {
+            Error::SqliteFailure(ref e, _) => e.code,
+            err => return DataStoreError::Internal(err.to_string()),
+        };
+        match code {
+            // another connection held the write lock for longer than the busy timeout
+            ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked => {
+                DataStoreError::SerializationConflict
+            }
+            ErrorCode::CannotOpen
+            | ErrorCode::SystemIOFailure
+            | ErrorCode::DiskFull
+            | ErrorCode::ReadOnly
+            | ErrorCode::PermissionDenied => DataStoreError::Unavailable(err.to_string()),
+            _ => DataStoreError::Internal(err.to_string()),
+        }
+    }
+}
+
+// Extended result code of a failed constraint, e.g. SQLITE_CONSTRAINT_CHECK
+fn constraint<T>(res: &Result<T, Error>) -> Option<c_int> {
+    match res {
+        Err(Error::SqliteFailure(e, _)) if e.code == ErrorCode::ConstraintViolation => {
+            Some(e.extended_code)
+        }
+        _ => None,
+    }
+}
+
+fn open_connection(path: &str, retry: &RetryPolicy) -> SqliteResult<Connection> {
+    let conn = Connection::open(path).map_err(|e| DataStoreError::Unavailable(e.to_string()))?;
+    // journal_mode answers with the mode it switched to, so it is read like a query
+    let mode: String = conn.query_row("PRAGMA journal_mode = WAL", NO_PARAMS, |row| row.get(0))?;
+    if !mode.eq_ignore_ascii_case("wal") {
+        return Err(DataStoreError::Unavailable(format!("Could Not Enable WAL On {}", path)));
+    }
+    // every commit is on disk before the call returns
+    conn.execute_batch("PRAGMA synchronous = FULL;")?;
+    // SQLite waits this long for the write lock, then the retry policy backs off and counts
+    // the attempt
+    conn.busy_timeout(retry.initial_backoff)?;
+    Ok(conn)
+}
+
+fn execute_txn<T, F>(
+    conn: &mut Connection,
+    retry: &RetryPolicy,
+    metrics: &RetryMetrics,
+    deadline: Option<DeadlineCheck>,
+    op: F,
+) -> SqliteResult<T>
+where
+    F: Fn(&Transaction) -> SqliteResult<T>,
+{
+    let start = Instant::now();
+    let mut attempts = 0;
+    let res = loop {
+        attempts += 1;
+        // IMMEDIATE takes the write lock before the first read, so no other writer can
+        // change a balance between reading and updating it. An error rolls back on drop.
+        let res = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(DataStoreError::from)
+            .and_then(|txn| {
+                let t = op(&txn)?;
+                txn.commit()?;
+                Ok(t)
+            });
+        match res {
+            Err(DataStoreError::SerializationConflict) => {}
+            r => break r,
+        }
+
+        let elapsed = start.elapsed();
+        let expired = deadline.as_ref().map_or(false, |expired| expired());
+        if attempts >= retry.max_attempts || elapsed >= retry.max_elapsed || expired {
+            break Err(DataStoreError::Contention(attempts));
+        }
+        thread::sleep(retry.backoff(attempts).min(retry.max_elapsed - elapsed));
+    };
+    let exhausted = res.as_ref().err() == Some(&DataStoreError::Contention(attempts));
+    metrics.observe(attempts - 1, exhausted);
+    res
+}
+
+fn account_balance(
+    conn: &Connection,
+    account: u32,
+    now: i64,
+) -> Result<Option<AccountBalance>, Error> {
+    conn.query_row(BALANCE_QUERY, params![account, now], |row| {
+        Ok(AccountBalance { ledger: row.get(0)?, available: row.get(1)? })
+    })
+    .optional()
+}
+
+// Claims the req_id before anything else is written, like the Postgres store
+fn record_request(txn: &Transaction, request: &Option<RequestRecord>) -> SqliteResult<()> {
+    let request = match request {
+        Some(request) => request,
+        None => return Ok(()),
+    };
+    let inserted = txn.execute(
+        "INSERT OR IGNORE INTO requests (req_id, fingerprint, reply, created_at)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![request.req_id as i64, request.fingerprint, request.reply, now_millis()],
+    )?;
+    if inserted == 1 {
+        return Ok(());
+    }
+    let (fingerprint, reply): (Vec<u8>, Vec<u8>) = txn.query_row(
+        "SELECT fingerprint, reply FROM requests WHERE req_id = ?1",
+        params![request.req_id as i64],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    if fingerprint == request.fingerprint {
+        Err(DataStoreError::RequestReplayed(reply))
+    } else {
+        Err(DataStoreError::RequestIdReused(request.req_id))
+    }
+}
+
+// New balances are worked out here rather than in SQL, where an overflowing sum would
+// silently turn into a float. Holding the write lock makes the read and the update atomic.
+fn transfer_funds(
+    txn: &Transaction,
+    plan: &TransferPlan,
+    req_id: i64,
+    max_balance: i64,
+) -> SqliteResult<()> {
+    // accounts whose components cancel out keep their balance but get ledger rows
+    for &account in plan.unchanged() {
+        let found: Option<i64> = txn
+            .prepare_cached("SELECT id FROM accounts WHERE id = ?1")?
+            .query_row(params![account], |row| row.get(0))
+            .optional()?;
+        if found.is_none() {
+            return Err(DataStoreError::AccountNotFound(account));
+        }
+    }
+    for leg in plan.legs() {
+        let account = leg.account_id;
+        let current: Option<i64> = txn
+            .prepare_cached("SELECT balance FROM accounts WHERE id = ?1")?
+            .query_row(params![account], |row| row.get(0))
+            .optional()?;
+        let current = current.ok_or(DataStoreError::AccountNotFound(account))?;
+        let balance = match current.checked_add(leg.delta) {
+            Some(balance) if balance <= max_balance => balance,
+            _ => return Err(DataStoreError::BalanceOutOfRange(account)),
+        };
+        if balance < 0 {
+            return Err(DataStoreError::InsufficientFunds(account));
+        }
+        txn.prepare_cached("UPDATE accounts SET balance = ?1 WHERE id = ?2")?
+            .execute(params![balance, account])?;
+    }
+
+    // the log keeps the components as the client sent them
+    let created_at = now_millis();
+    let mut insert = txn.prepare_cached(
+        "INSERT INTO transactions (req_id, account_id, amount, transaction_index, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    for entry in plan.entries() {
+        let index = entry.transaction_index;
+        insert.execute(params![req_id, entry.account_id, entry.amount, index, created_at])?;
+    }
+
+    // balance_check only covers the ledger balance, debits may not dip into held funds
+    for account in plan.debited() {
+        if let Some(balance) = account_balance(txn, account, created_at)? {
+            if balance.available < 0 {
+                return Err(DataStoreError::InsufficientFunds(account));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn reverse_transfer(
+    txn: &Transaction,
+    original_req_id: i64,
+    req_id: i64,
+    max_balance: i64,
+) -> SqliteResult<Vec<TransferComponent>> {
+    // the primary key on original_req_id refuses a second reversal
+    let res = txn.execute(
+        "INSERT INTO reversals (original_req_id, reversal_req_id, created_at)
+         VALUES (?1, ?2, ?3)",
+        params![original_req_id, req_id, now_millis()],
+    );
+    if constraint(&res) == Some(ffi::SQLITE_CONSTRAINT_PRIMARYKEY) {
+        return Err(DataStoreError::AlreadyReversed(original_req_id as u64));
+    }
+    res?;
+
+    let mut stmt = txn.prepare(
+        "SELECT account_id, amount FROM transactions
+         WHERE req_id = ?1 AND kind = 'transfer' ORDER BY transaction_index",
+    )?;
+    let transfers = stmt
+        .query_map(params![original_req_id], |row| {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(row.get(0)?);
+            comp.set_money_delta(-row.get::<_, i64>(1)?);
+            Ok(comp)
+        })?
+        .collect::<Result<Vec<TransferComponent>, Error>>()?;
+    if transfers.is_empty() {
+        return Err(DataStoreError::TransferNotFound(original_req_id as u64));
+    }
+    transfer_funds(txn, &TransferPlan::new(&transfers), req_id, max_balance)?;
+    Ok(transfers)
+}
+
+fn verify_ledger(txn: &Transaction, rebuild: bool) -> SqliteResult<LedgerReport> {
+    let accounts: i64 = txn.query_row("SELECT COUNT(*) FROM accounts", NO_PARAMS, |row| {
+        row.get(0)
+    })?;
+    let mut stmt = txn.prepare(MISMATCH_QUERY)?;
+    let mismatches = stmt
+        .query_map(NO_PARAMS, |row| {
+            Ok(LedgerMismatch {
+                account_id: row.get(0)?,
+                stored: row.get(1)?,
+                projected: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<LedgerMismatch>, Error>>()?;
+    if rebuild {
+        for mismatch in &mismatches {
+            txn.prepare_cached("UPDATE accounts SET balance = ?1 WHERE id = ?2")?
+                .execute(params![mismatch.projected, mismatch.account_id])?;
+        }
+    }
+    // every account here was opened with its opening row, so none were backfilled
+    Ok(LedgerReport { accounts: accounts as u64, mismatches, backfilled: Vec::new() })
+}
+
+fn authorize_transfer(txn: &Transaction, hold: &Hold) -> SqliteResult<()> {
+    let to_account: Option<i64> = txn
+        .query_row(
+            "SELECT id FROM accounts WHERE id = ?1",
+            params![hold.to_account_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if to_account.is_none() {
+        return Err(DataStoreError::AccountNotFound(hold.to_account_id));
+    }
+    match account_balance(txn, hold.account_id, now_millis())? {
+        None => return Err(DataStoreError::AccountNotFound(hold.account_id)),
+        Some(balance) if balance.available < hold.amount => {
+            return Err(DataStoreError::InsufficientFunds(hold.account_id))
+        }
+        Some(_) => {}
+    }
+
+    let res = txn.execute(
+        "INSERT INTO holds (id, account_id, to_account_id, amount, status, expires_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            hold.id as i64,
+            hold.account_id,
+            hold.to_account_id,
+            hold.amount,
+            hold.status.as_str(),
+            hold.expires_at,
+        ],
+    );
+    if constraint(&res) == Some(ffi::SQLITE_CONSTRAINT_PRIMARYKEY) {
+        return Err(DataStoreError::HoldExists(hold.id));
+    }
+    res?;
+    Ok(())
+}
+
+fn capture_transfer(
+    txn: &Transaction,
+    hold_id: u64,
+    amount: Option<i64>,
+    req_id: i64,
+    max_balance: i64,
+) -> SqliteResult<Hold> {
+    let mut hold = get_hold(txn, hold_id)?;
+    if hold.status != HoldStatus::Active {
+        return Err(DataStoreError::HoldNotActive(hold_id));
+    }
+    if !hold.is_active(now_millis()) {
+        return Err(DataStoreError::HoldExpired(hold_id));
+    }
+    let amount = amount.unwrap_or(hold.amount);
+    if amount > hold.amount {
+        return Err(DataStoreError::CaptureExceedsHold(hold_id, amount));
+    }
+
+    // the hold stops reserving funds before the captured amount is moved
+    hold.status = HoldStatus::Captured;
+    hold.captured = amount;
+    txn.execute(
+        "UPDATE holds SET status = ?1, captured = ?2 WHERE id = ?3",
+        params![hold.status.as_str(), amount, hold_id as i64],
+    )?;
+    let plan = TransferPlan::new(&hold.components(amount));
+    transfer_funds(txn, &plan, req_id, max_balance)?;
+    Ok(hold)
+}
+
+fn void_transfer(txn: &Transaction, hold_id: u64) -> SqliteResult<Hold> {
+    let mut hold = get_hold(txn, hold_id)?;
+    if hold.status != HoldStatus::Active {
+        return Err(DataStoreError::HoldNotActive(hold_id));
+    }
+    hold.status = HoldStatus::Voided;
+    txn.execute(
+        "UPDATE holds SET status = ?1 WHERE id = ?2",
+        params![hold.status.as_str(), hold_id as i64],
+    )?;
+    Ok(hold)
+}
+
+fn get_hold(txn: &Transaction, hold_id: u64) -> SqliteResult<Hold> {
+    let row = txn
+        .query_row(
+            "SELECT account_id, to_account_id, amount, captured, status, expires_at
+             FROM holds WHERE id = ?1",
+            params![hold_id as i64],
+            |row| {
+                let hold = Hold {
+                    id: hold_id,
+                    account_id: row.get(0)?,
+                    to_account_id: row.get(1)?,
+                    amount: row.get(2)?,
+                    captured: row.get(3)?,
+                    status: HoldStatus::Active,
+                    expires_at: row.get(5)?,
+                };
+                Ok((hold, row.get::<_, String>(4)?))
+            },
+        )
+        .optional()?;
+    let (mut hold, status) = row.ok_or(DataStoreError::HoldNotFound(hold_id))?;
+    hold.status = HoldStatus::parse(&status)
+        .ok_or_else(|| DataStoreError::Internal(format!("Unknown Hold Status {}", status)))?;
+    Ok(hold)
+}
+
+fn list_transactions(
+    conn: &Connection,
+    filter: &TransactionFilter,
+) -> Result<Vec<TransactionRecord>, Error> {
+    // opening rows record no transfer, so they aren't listed
+    let mut clauses: Vec<String> = vec!["kind = 'transfer'".to_string()];
+    let mut params: Vec<i64> = Vec::new();
+
+    if !filter.account_ids.is_empty() {
+        let ids = filter.account_ids.iter().map(|&id| i64::from(id)).collect();
+        clauses.push(format!("account_id IN ({})", placeholders(&mut params, ids)));
+    }
+    if !filter.req_ids.is_empty() {
+        let ids = filter.req_ids.iter().map(|&id| id as i64).collect();
+        clauses.push(format!("req_id IN ({})", placeholders(&mut params, ids)));
+    }
+    if let Some(start) = filter.start_time {
+        clauses.push(format!("created_at >= {}", placeholders(&mut params, vec![start])));
+    }
+    if let Some(end) = filter.end_time {
+        clauses.push(format!("created_at < {}", placeholders(&mut params, vec![end])));
+    }
+    if let Some(after) = filter.after_id {
+        clauses.push(format!("id > {}", placeholders(&mut params, vec![after as i64])));
+    }
+
+    let limit = placeholders(&mut params, vec![filter.limit as i64]);
+    let query = format!(
+        "SELECT id, req_id, account_id, amount, transaction_index, created_at
+         FROM transactions WHERE {} ORDER BY id LIMIT {}",
+        clauses.join(" AND "),
+        limit
+    );
+    let params: Vec<&dyn ToSql> = params.iter().map(|p| p as &dyn ToSql).collect();
+
+    let mut stmt = conn.prepare(&query)?;
+    let rows = stmt.query_map(&params, |row| {
+        let mut record = TransactionRecord::new();
+        record.set_id(row.get::<_, i64>(0)? as u64);
+        record.set_req_id(row.get::<_, i64>(1)? as u64);
+        record.set_account_id(row.get(2)?);
+        record.set_amount(row.get(3)?);
+        record.set_transaction_index(row.get(4)?);
+        record.set_created_at(row.get(5)?);
+        Ok(record)
+    })?;
+    rows.collect()
+}
+
+// Appends the values as query parameters and returns their comma separated placeholders
+fn placeholders(params: &mut Vec<i64>, values: Vec<i64>) -> String {
+    values
+        .into_iter()
+        .map(|value| {
+            params.push(value);
+            format!("?{}", params.len())
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::ops::{Deref, DerefMut};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+    // A store in a file of its own, removed with its WAL when dropped
+    struct TempStore {
+        store: SqliteDataStore,
+        path: String,
+    }
+
+    impl TempStore {
+        fn new() -> TempStore {
+            let n = NEXT_FILE.fetch_add(1, Ordering::SeqCst);
+            let path = env::temp_dir().join(format!("accountant-{}-{}.db", std::process::id(), n));
+            let path = path.to_string_lossy().into_owned();
+            TempStore { store: SqliteDataStore::open(&path).unwrap(), path }
+        }
+    }
+
+    impl Deref for TempStore {
+        type Target = SqliteDataStore;
+
+        fn deref(&self) -> &SqliteDataStore {
+            &self.store
+        }
+    }
+
+    impl DerefMut for TempStore {
+        fn deref_mut(&mut self) -> &mut SqliteDataStore {
+            &mut self.store
+        }
+    }
+
+    impl Drop for TempStore {
+        fn drop(&mut self) {
+            for suffix in &["", "-wal", "-shm"] {
+                let _ = fs::remove_file(format!("{}{}", self.path, suffix));
+            }
+        }
+    }
+
+    datastore_conformance!(TempStore::new());
+
+    fn plan(transfers: &[(u32, i64)]) -> TransferPlan {
+        let components: Vec<TransferComponent> = transfers
+            .iter()
+            .map(|&(account_id, amount)| {
+                let mut comp = TransferComponent::new();
+                comp.set_account_id(account_id);
+                comp.set_money_delta(amount);
+                comp
+            })
+            .collect();
+        TransferPlan::new(&components)
+    }
+
+    fn ledger(store: &mut SqliteDataStore, account: u32) -> Option<i64> {
+        store.get_account_balance(account).unwrap().map(|balance| balance.ledger)
+    }
+
+    #[test]
+    fn test_create_account() {
+        let mut temp = TempStore::new();
+        let store = &mut temp.store;
+        assert_eq!(store.create_account(1, 1, 100), Ok(1));
+        assert_eq!(store.create_account(1, 2, 100), Err(DataStoreError::AccountExists(1)));
+        assert_eq!(store.create_account(2, 3, -1), Err(DataStoreError::InvalidBalance(2, -1)));
+        assert_eq!(ledger(store, 1), Some(100));
+        assert_eq!(ledger(store, 2), None);
+    }
+
+    #[test]
+    fn test_transfer() {
+        let mut temp = TempStore::new();
+        let store = &mut temp.store;
+        store.set_max_balance(960);
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 900).unwrap();
+
+        assert_eq!(store.execute_transfers(&plan(&[(1, -40), (2, 40)]), 10), Ok(()));
+        assert_eq!((ledger(store, 1), ledger(store, 2)), (Some(60), Some(940)));
+
+        // every failure leaves both balances and the log untouched
+        let failures = vec![
+            (plan(&[(1, -70), (2, 70)]), DataStoreError::InsufficientFunds(1)),
+            (plan(&[(1, -5), (3, 5)]), DataStoreError::AccountNotFound(3)),
+            (plan(&[(1, -30), (2, 30)]), DataStoreError::BalanceOutOfRange(2)),
+            (plan(&[(1, i64::MAX), (2, -1)]), DataStoreError::BalanceOutOfRange(1)),
+        ];
+        for (i, (plan, err)) in failures.into_iter().enumerate() {
+            assert_eq!(store.execute_transfers(&plan, 11 + i as i64), Err(err));
+        }
+        assert_eq!((ledger(store, 1), ledger(store, 2)), (Some(60), Some(940)));
+
+        let mut filter = TransactionFilter { limit: 10, ..TransactionFilter::default() };
+        let records = store.list_transactions(&filter).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|r| r.get_req_id() == 10));
+        filter.after_id = Some(records[0].get_id());
+        filter.account_ids = vec![2];
+        assert_eq!(store.list_transactions(&filter).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_holds_and_reversal() {
+        let mut temp = TempStore::new();
+        let store = &mut temp.store;
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+
+        let hold = Hold {
+            id: 5,
+            account_id: 1,
+            to_account_id: 2,
+            amount: 60,
+            captured: 0,
+            status: HoldStatus::Active,
+            expires_at: now_millis() + 60_000,
+        };
+        assert_eq!(store.authorize_transfer(&hold), Ok(()));
+        let again = Hold { amount: 10, ..hold.clone() };
+        assert_eq!(store.authorize_transfer(&again), Err(DataStoreError::HoldExists(5)));
+        let balance = store.get_account_balance(1).unwrap().unwrap();
+        assert_eq!((balance.ledger, balance.available), (100, 40));
+        let err = store.execute_transfers(&plan(&[(1, -50), (2, 50)]), 10);
+        assert_eq!(err, Err(DataStoreError::InsufficientFunds(1)));
+
+        assert_eq!(store.capture_transfer(5, Some(25), 11).unwrap().captured, 25);
+        assert_eq!(store.void_transfer(5), Err(DataStoreError::HoldNotActive(5)));
+        assert_eq!((ledger(store, 1), ledger(store, 2)), (Some(75), Some(25)));
+
+        assert_eq!(store.reverse_transfer(11, 12).unwrap().len(), 2);
+        assert_eq!(store.reverse_transfer(11, 13), Err(DataStoreError::AlreadyReversed(11)));
+        assert_eq!(store.reverse_transfer(99, 14), Err(DataStoreError::TransferNotFound(99)));
+        assert_eq!((ledger(store, 1), ledger(store, 2)), (Some(100), Some(0)));
+    }
+
+    #[test]
+    fn test_verify_ledger() {
+        let mut temp = TempStore::new();
+        let store = &mut temp.store;
+        store.create_account(1, 1, 100).unwrap();
+        store.create_account(2, 2, 0).unwrap();
+        store.execute_transfers(&plan(&[(1, -40), (2, 40)]), 3).unwrap();
+
+        // a balance changed behind the ledger's back
+        let tamper = "UPDATE accounts SET balance = 90 WHERE id = 2;";
+        store.with_conn(|conn| Ok(conn.execute_batch(tamper)?)).unwrap();
+        let mismatch = LedgerMismatch { account_id: 2, stored: 90, projected: 40 };
+        let found = LedgerReport {
+            accounts: 2,
+            mismatches: vec![mismatch],
+            backfilled: Vec::new(),
+        };
+        assert_eq!(store.verify_ledger(false), Ok(found.clone()));
+        assert_eq!(ledger(store, 2), Some(90));
+
+        assert_eq!(store.verify_ledger(true), Ok(found));
+        assert_eq!((ledger(store, 1), ledger(store, 2)), (Some(60), Some(40)));
+        assert!(store.verify_ledger(false).unwrap().mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_clones_share_the_file() {
+        let mut temp = TempStore::new();
+        let mut other = temp.store.clone();
+        temp.store.create_account(1, 1, 100).unwrap();
+        assert_eq!(ledger(&mut other, 1), Some(100));
+
+        other.reset().unwrap();
+        assert_eq!(ledger(&mut temp.store, 1), None);
+        // the create and the reset, counted in the metrics the clones share
+        assert_eq!(temp.store.retry_metrics().snapshot().transactions, 2);
+    }
+}
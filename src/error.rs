@@ -0,0 +1,85 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataStoreError {
+    AccountExists(u32),
+    AccountNotFound(u32),
+    InsufficientFunds(u32),
+    // the transfer would take the balance above the configured maximum
+    BalanceOutOfRange(u32),
+    // opening balance rejected by balance_check
+    InvalidBalance(u32, i64),
+    // no legs were recorded for the req_id
+    TransferNotFound(u64),
+    AlreadyReversed(u64),
+    HoldExists(u64),
+    HoldNotFound(u64),
+    // the hold was already captured or voided
+    HoldNotActive(u64),
+    HoldExpired(u64),
+    // more was captured than the hold reserved
+    CaptureExceedsHold(u64, i64),
+    // the req_id was already recorded for the same request, holds the reply it was given
+    RequestReplayed(Vec<u8>),
+    // the req_id was already recorded for a different request
+    RequestIdReused(u64),
+    SerializationConflict,
+    // still conflicting after the number of attempts the retry policy allows
+    Contention(u32),
+    Unavailable(String),
+    Internal(String),
+}
+
+impl fmt::Display for DataStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DataStoreError::AccountExists(account) => {
+                write!(f, "Account {} Already Exists", account)
+            }
+            DataStoreError::AccountNotFound(account) => write!(f, "Account {} Not Found", account),
+            DataStoreError::InsufficientFunds(account) => {
+                write!(f, "Insufficient Funds In Account {}", account)
+            }
+            DataStoreError::BalanceOutOfRange(account) => {
+                write!(f, "Balance Of Account {} Would Exceed Its Limit", account)
+            }
+            DataStoreError::InvalidBalance(account, balance) => {
+                write!(f, "Invalid Balance {} For Account {}", balance, account)
+            }
+            DataStoreError::TransferNotFound(req_id) => {
+                write!(f, "Transfer {} Not Found", req_id)
+            }
+            DataStoreError::AlreadyReversed(req_id) => {
+                write!(f, "Transfer {} Was Already Reversed", req_id)
+            }
+            DataStoreError::HoldExists(hold) => write!(f, "Hold {} Already Exists", hold),
+            DataStoreError::HoldNotFound(hold) => write!(f, "Hold {} Not Found", hold),
+            DataStoreError::HoldNotActive(hold) => {
+                write!(f, "Hold {} Was Already Captured Or Voided", hold)
+            }
+            DataStoreError::HoldExpired(hold) => write!(f, "Hold {} Has Expired", hold),
+            DataStoreError::CaptureExceedsHold(hold, amount) => {
+                write!(f, "Capture Of {} Exceeds The Amount Held By Hold {}", amount, hold)
+            }
+            DataStoreError::RequestReplayed(_) => write!(f, "Request Was Already Applied"),
+            DataStoreError::RequestIdReused(req_id) => write!(
+                f,
+                "Request Id {} Was Already Used For A Different Request",
+                req_id
+            ),
+            DataStoreError::SerializationConflict => {
+                write!(f, "Transaction Conflicted With A Concurrent Request")
+            }
+            DataStoreError::Contention(attempts) => write!(
+                f,
+                "Too Much Contention After {} Attempts, Retry Later",
+                attempts
+            ),
+            DataStoreError::Unavailable(reason) => write!(f, "Data Store Unavailable: {}", reason),
+            DataStoreError::Internal(reason) => write!(f, "Data Store Error: {}", reason),
+        }
+    }
+}
+
+impl Error for DataStoreError {}
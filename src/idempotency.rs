@@ -0,0 +1,216 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use protobuf::Message;
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyConfig {
+    // how long a completed request can be replayed for
+    pub retention: Duration,
+    // oldest completed requests are forgotten first once this is reached
+    pub max_entries: usize,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> IdempotencyConfig {
+        IdempotencyConfig {
+            retention: Duration::from_secs(60 * 60),
+            max_entries: 100_000,
+        }
+    }
+}
+
+// A CreateAccount or Transfer as the store records it, in the same transaction as its
+// changes, so a retry is still recognised after a restart or on another server
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestRecord {
+    pub req_id: u64,
+    pub fingerprint: Vec<u8>,
+    // the serialized reply a replay is answered with
+    pub reply: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Claim {
+    // first time this req_id is seen, the caller must `complete` or `release` it
+    New,
+    // the same request already succeeded, holds the serialized response
+    Replay(Vec<u8>),
+    // the same request is still being executed
+    InProgress,
+    // the req_id was already used for a different request
+    Conflict,
+}
+
+enum EntryState {
+    Pending,
+    Done(Vec<u8>),
+}
+
+struct Entry {
+    fingerprint: Vec<u8>,
+    state: EntryState,
+    recorded: Instant,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<u64, Entry>,
+    // completed req_ids in the order they finished, used for expiry
+    completed: VecDeque<(u64, Instant)>,
+}
+
+// Remembers the outcome of mutating requests by req_id so client retries are not applied twice
+#[derive(Clone)]
+pub struct IdempotencyCache {
+    config: IdempotencyConfig,
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl IdempotencyCache {
+    pub fn new(config: IdempotencyConfig) -> IdempotencyCache {
+        IdempotencyCache {
+            config,
+            state: Arc::new(Mutex::new(CacheState::default())),
+        }
+    }
+
+    pub fn claim(&self, req_id: u64, fingerprint: Vec<u8>) -> Claim {
+        let mut state = self.lock();
+        self.evict(&mut state, Instant::now());
+
+        if let Some(entry) = state.entries.get(&req_id) {
+            return if entry.fingerprint != fingerprint {
+                Claim::Conflict
+            } else {
+                match entry.state {
+                    EntryState::Pending => Claim::InProgress,
+                    EntryState::Done(ref reply) => Claim::Replay(reply.clone()),
+                }
+            };
+        }
+
+        state.entries.insert(
+            req_id,
+            Entry {
+                fingerprint,
+                state: EntryState::Pending,
+                recorded: Instant::now(),
+            },
+        );
+        Claim::New
+    }
+
+    pub fn complete(&self, req_id: u64, reply: Vec<u8>) {
+        let mut state = self.lock();
+        let now = Instant::now();
+        if let Some(entry) = state.entries.get_mut(&req_id) {
+            entry.state = EntryState::Done(reply);
+            entry.recorded = now;
+        } else {
+            return;
+        }
+        state.completed.push_back((req_id, now));
+        self.evict(&mut state, now);
+    }
+
+    // Forgets a claimed request that failed so that a retry is executed again
+    pub fn release(&self, req_id: u64) {
+        let mut state = self.lock();
+        let pending = match state.entries.get(&req_id) {
+            Some(entry) => match entry.state {
+                EntryState::Pending => true,
+                EntryState::Done(_) => false,
+            },
+            None => false,
+        };
+        if pending {
+            state.entries.remove(&req_id);
+        }
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = CacheState::default();
+    }
+
+    fn evict(&self, state: &mut CacheState, now: Instant) {
+        while let Some(&(req_id, recorded)) = state.completed.front() {
+            let expired = now.duration_since(recorded) >= self.config.retention;
+            if !expired && state.completed.len() <= self.config.max_entries {
+                break;
+            }
+            state.completed.pop_front();
+            let current = state
+                .entries
+                .get(&req_id)
+                .map_or(false, |entry| entry.recorded == recorded);
+            if current {
+                state.entries.remove(&req_id);
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap()
+    }
+}
+
+// Identifies a request by its RPC and full payload
+pub fn fingerprint<M: Message>(method: &str, req: &M) -> Vec<u8> {
+    let mut bytes = method.as_bytes().to_vec();
+    bytes.push(0);
+    bytes.extend(req.write_to_bytes().unwrap_or_default());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    fn cache(retention: Duration, max_entries: usize) -> IdempotencyCache {
+        IdempotencyCache::new(IdempotencyConfig { retention, max_entries })
+    }
+
+    #[test]
+    fn test_replay_and_conflict() {
+        let requests = cache(Duration::from_secs(60), 10);
+        assert_eq!(requests.claim(1, b"a".to_vec()), Claim::New);
+        assert_eq!(requests.claim(1, b"a".to_vec()), Claim::InProgress);
+        assert_eq!(requests.claim(1, b"b".to_vec()), Claim::Conflict);
+
+        requests.complete(1, b"reply".to_vec());
+        assert_eq!(requests.claim(1, b"a".to_vec()), Claim::Replay(b"reply".to_vec()));
+        assert_eq!(requests.claim(1, b"b".to_vec()), Claim::Conflict);
+    }
+
+    #[test]
+    fn test_release_allows_retry() {
+        let requests = cache(Duration::from_secs(60), 10);
+        assert_eq!(requests.claim(1, b"a".to_vec()), Claim::New);
+        requests.release(1);
+        assert_eq!(requests.claim(1, b"b".to_vec()), Claim::New);
+    }
+
+    #[test]
+    fn test_retention() {
+        let requests = cache(Duration::from_millis(10), 10);
+        requests.claim(1, b"a".to_vec());
+        requests.complete(1, b"reply".to_vec());
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(requests.claim(1, b"b".to_vec()), Claim::New);
+    }
+
+    #[test]
+    fn test_max_entries() {
+        let requests = cache(Duration::from_secs(60), 2);
+        for req_id in 0..3 {
+            requests.claim(req_id, b"a".to_vec());
+            requests.complete(req_id, b"reply".to_vec());
+        }
+        assert_eq!(requests.claim(0, b"b".to_vec()), Claim::New);
+        assert_eq!(requests.claim(2, b"a".to_vec()), Claim::Replay(b"reply".to_vec()));
+    }
+}
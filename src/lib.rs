@@ -1,22 +1,269 @@
+pub mod config;
 pub mod db;
+pub mod error;
+pub mod idempotency;
 pub mod proto;
+pub mod recording;
+pub mod sdk;
+pub mod shutdown;
+pub mod status;
+pub mod transfer;
+pub mod validation;
+pub mod watch;
 
-use futures::Future;
-use grpcio::{RpcContext, RpcStatus, RpcStatusCode, UnarySink};
+use std::cmp;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::proto::accounting::TransferComponent;
+use futures::{future, FutureExt, SinkExt, TryFutureExt};
+use grpcio::{RpcContext, RpcStatus, RpcStatusCode, ServerStreamingSink, UnarySink, WriteFlags};
+use protobuf::{Message, RepeatedField};
+
+use crate::proto::accounting::{TransactionRecord, TransferComponent};
 use crate::proto::accounting::{
     CreateAccountRequest, CreateAccountResponse, GetBalanceRequest, GetBalanceResponse,
     TransferRequest, TransferResponse, ResetRequest, ResetResponse,
+    ListTransactionsRequest, ListTransactionsResponse, WatchBalanceRequest,
+    AuthorizeTransferRequest, AuthorizeTransferResponse, CaptureTransferRequest,
+    CaptureTransferResponse, VoidTransferRequest, VoidTransferResponse,
+    ReverseTransferRequest, ReverseTransferResponse, VerifyLedgerRequest, VerifyLedgerResponse,
 };
 use crate::proto::accounting_grpc::AccountingService;
+use crate::db::retry::RetryPolicy;
+use crate::idempotency::{fingerprint, Claim, IdempotencyCache, IdempotencyConfig, RequestRecord};
+use crate::recording::{JsonMessage, RecordedError, Recorder};
+use crate::shutdown::{Drain, ShutdownHandle};
+use crate::status::{ErrorDetails, ErrorStatus};
+use crate::transfer::TransferPlan;
+use crate::validation::{RequestLimits, ValidationError};
+use crate::watch::{BalanceWatchers, WatchForward};
+
+pub use crate::error::DataStoreError;
+
+const DEFAULT_PAGE_SIZE: usize = 100;
+const SHUTTING_DOWN: &str = "Server Is Shutting Down";
+const MAX_PAGE_SIZE: usize = 1_000;
+
+// Selects rows of the transactions table, ordered by id
+#[derive(Clone, Debug, Default)]
+pub struct TransactionFilter {
+    pub account_ids: Vec<u32>,
+    pub req_ids: Vec<u64>,
+    // milliseconds since the unix epoch, start inclusive and end exclusive
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    // only rows with a greater id are returned
+    pub after_id: Option<u64>,
+    pub limit: usize,
+}
+
+impl TransactionFilter {
+    pub fn matches(&self, record: &TransactionRecord) -> bool {
+        (self.account_ids.is_empty() || self.account_ids.contains(&record.get_account_id()))
+            && (self.req_ids.is_empty() || self.req_ids.contains(&record.get_req_id()))
+            && self.start_time.map_or(true, |start| record.get_created_at() >= start)
+            && self.end_time.map_or(true, |end| record.get_created_at() < end)
+            && self.after_id.map_or(true, |after| record.get_id() > after)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccountBalance {
+    pub ledger: i64,
+    // ledger balance minus the amounts of active holds
+    pub available: i64,
+}
+
+// An account whose stored balance isn't the sum of its ledger entries
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LedgerMismatch {
+    pub account_id: u32,
+    pub stored: i64,
+    // the balance its entries add up to, the opening entry included
+    pub projected: i64,
+}
+
+// An opening entry a migration inferred as the part of a balance its other entries don't
+// account for. The ledger can't tell such an opening from drift that happened before it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LedgerBackfill {
+    pub account_id: u32,
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LedgerReport {
+    // how many accounts were checked
+    pub accounts: u64,
+    // ordered by account id
+    pub mismatches: Vec<LedgerMismatch>,
+    // ordered by account id
+    pub backfilled: Vec<LedgerBackfill>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HoldStatus {
+    Active,
+    Captured,
+    Voided,
+}
+
+// Funds reserved on account_id by an authorization, identified by its req_id
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hold {
+    pub id: u64,
+    pub account_id: u32,
+    pub to_account_id: u32,
+    pub amount: i64,
+    pub captured: i64,
+    pub status: HoldStatus,
+    // milliseconds since the unix epoch
+    pub expires_at: i64,
+}
+
+impl HoldStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HoldStatus::Active => "active",
+            HoldStatus::Captured => "captured",
+            HoldStatus::Voided => "voided",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<HoldStatus> {
+        match status {
+            "active" => Some(HoldStatus::Active),
+            "captured" => Some(HoldStatus::Captured),
+            "voided" => Some(HoldStatus::Voided),
+            _ => None,
+        }
+    }
+}
+
+impl Hold {
+    // whether the hold still reduces the available balance
+    pub fn is_active(&self, now: i64) -> bool {
+        self.status == HoldStatus::Active && self.expires_at > now
+    }
+
+    // the transfer that settles `amount` of the hold
+    pub fn components(&self, amount: i64) -> Vec<TransferComponent> {
+        let mut from = TransferComponent::new();
+        from.set_account_id(self.account_id);
+        from.set_money_delta(-amount);
+        let mut to = TransferComponent::new();
+        to.set_account_id(self.to_account_id);
+        to.set_money_delta(amount);
+        vec![from, to]
+    }
+}
+
+pub fn now_millis() -> i64 {
+    duration_millis(SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default())
+}
+
+fn duration_millis(duration: Duration) -> i64 {
+    duration.as_secs() as i64 * 1_000 + i64::from(duration.subsec_millis())
+}
+
+pub fn balance_response(req_id: u64, account: u32, balance: AccountBalance) -> GetBalanceResponse {
+    let mut reply = GetBalanceResponse::new();
+    reply.set_req_id(req_id);
+    reply.set_account_id(account);
+    reply.set_balance(balance.ledger);
+    reply.set_available_balance(balance.available);
+    reply
+}
+
+// Returns true once the caller of the current request has stopped waiting for it
+pub type DeadlineCheck = Arc<dyn Fn() -> bool + Send + Sync>;
 
 pub trait DataStore {
-    fn create_account(&mut self, account: u32, req_id: u64, balance: i64) -> Result<u64, ()>;
-    fn get_account_balance(&mut self, account: u32) -> Result<i64, ()>;
-    fn execute_transfers(&mut self, transfers: &[TransferComponent], req_id: i64)
-        -> Result<(), ()>;
-    fn reset(&mut self) -> Result<(), ()>;
+    fn create_account(&mut self, account: u32, req_id: u64, balance: i64)
+        -> Result<u64, DataStoreError>;
+    fn get_account_balance(&mut self, account: u32)
+        -> Result<Option<AccountBalance>, DataStoreError>;
+    fn execute_transfers(&mut self, plan: &TransferPlan, req_id: i64)
+        -> Result<(), DataStoreError>;
+    fn authorize_transfer(&mut self, hold: &Hold) -> Result<(), DataStoreError>;
+    // amount None captures everything held; the transfer is recorded under req_id
+    fn capture_transfer(&mut self, hold_id: u64, amount: Option<i64>, req_id: i64)
+        -> Result<Hold, DataStoreError>;
+    fn void_transfer(&mut self, hold_id: u64) -> Result<Hold, DataStoreError>;
+    // applies the negated legs of original_req_id under req_id and returns them
+    fn reverse_transfer(&mut self, original_req_id: i64, req_id: i64)
+        -> Result<Vec<TransferComponent>, DataStoreError>;
+    fn list_transactions(&mut self, filter: &TransactionFilter)
+        -> Result<Vec<TransactionRecord>, DataStoreError>;
+    fn reset(&mut self) -> Result<(), DataStoreError>;
+    // Recomputes every balance from the ledger and reports the accounts whose stored
+    // balance disagrees. With rebuild their stored balance is set to the recomputed one in
+    // the same transaction, the report still shows what was found.
+    fn verify_ledger(&mut self, rebuild: bool) -> Result<LedgerReport, DataStoreError>;
+    // Bounds the retries of the next call, stores that don't retry can ignore it
+    fn set_deadline(&mut self, _deadline: Option<DeadlineCheck>) {}
+    // Recorded by the next create_account or execute_transfers in the same transaction. If
+    // its req_id was recorded before, the call changes nothing and fails with
+    // RequestReplayed or RequestIdReused.
+    fn set_request(&mut self, request: Option<RequestRecord>);
+    // Transfers, captures and reversals that would leave a balance above this fail with
+    // BalanceOutOfRange
+    fn set_max_balance(&mut self, max_balance: i64);
+}
+
+fn shutting_down() -> ErrorStatus {
+    ErrorStatus::new(
+        RpcStatusCode::UNAVAILABLE,
+        SHUTTING_DOWN.to_string(),
+        ErrorDetails::new("SHUTTING_DOWN"),
+    )
+}
+
+pub fn error_status_code(err: &DataStoreError) -> RpcStatusCode {
+    match err {
+        DataStoreError::AccountExists(_) => RpcStatusCode::ALREADY_EXISTS,
+        DataStoreError::AccountNotFound(_) => RpcStatusCode::NOT_FOUND,
+        DataStoreError::InsufficientFunds(_) => RpcStatusCode::FAILED_PRECONDITION,
+        DataStoreError::BalanceOutOfRange(_) => RpcStatusCode::OUT_OF_RANGE,
+        DataStoreError::InvalidBalance(_, _) => RpcStatusCode::INVALID_ARGUMENT,
+        DataStoreError::TransferNotFound(_) => RpcStatusCode::NOT_FOUND,
+        DataStoreError::AlreadyReversed(_) => RpcStatusCode::FAILED_PRECONDITION,
+        DataStoreError::HoldExists(_) => RpcStatusCode::ALREADY_EXISTS,
+        DataStoreError::HoldNotFound(_) => RpcStatusCode::NOT_FOUND,
+        DataStoreError::HoldNotActive(_) => RpcStatusCode::FAILED_PRECONDITION,
+        DataStoreError::HoldExpired(_) => RpcStatusCode::FAILED_PRECONDITION,
+        DataStoreError::CaptureExceedsHold(_, _) => RpcStatusCode::INVALID_ARGUMENT,
+        DataStoreError::RequestReplayed(_) => RpcStatusCode::ALREADY_EXISTS,
+        DataStoreError::RequestIdReused(_) => RpcStatusCode::ALREADY_EXISTS,
+        DataStoreError::SerializationConflict => RpcStatusCode::ABORTED,
+        DataStoreError::Contention(_) => RpcStatusCode::UNAVAILABLE,
+        DataStoreError::Unavailable(_) => RpcStatusCode::UNAVAILABLE,
+        DataStoreError::Internal(_) => RpcStatusCode::INTERNAL,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ServiceConfig {
+    pub idempotency: IdempotencyConfig,
+    // how long an authorization holds funds before they become available again
+    pub hold_ttl: Duration,
+    // CreateAccount, Transfer and GetBalance calls are appended here when set
+    pub recorder: Option<Recorder>,
+    pub limits: RequestLimits,
+    // RetryInfo sent with ABORTED and UNAVAILABLE errors
+    pub retry_delay: Duration,
+}
+
+impl Default for ServiceConfig {
+    fn default() -> ServiceConfig {
+        ServiceConfig {
+            idempotency: IdempotencyConfig::default(),
+            hold_ttl: Duration::from_secs(7 * 24 * 60 * 60),
+            recorder: None,
+            limits: RequestLimits::default(),
+            retry_delay: RetryPolicy::default().max_backoff,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -25,6 +272,13 @@ where
     T: 'static + DataStore + Send + Sync,
 {
     store: T,
+    requests: IdempotencyCache,
+    watchers: BalanceWatchers,
+    drain: Drain,
+    hold_ttl: Duration,
+    recorder: Option<Recorder>,
+    limits: RequestLimits,
+    retry_delay: Duration,
 }
 
 impl<T> GrpcAccountingService<T>
@@ -32,21 +286,170 @@ where
     T: 'static + DataStore + Send + Sync,
 {
     pub fn new(store: T) -> GrpcAccountingService<T> {
-        GrpcAccountingService { store }
+        GrpcAccountingService::with_config(store, ServiceConfig::default())
+    }
+
+    pub fn with_config(mut store: T, config: ServiceConfig) -> GrpcAccountingService<T> {
+        let requests = IdempotencyCache::new(config.idempotency);
+        store.set_max_balance(config.limits.amounts.max_balance);
+        GrpcAccountingService {
+            store,
+            requests,
+            watchers: BalanceWatchers::new(),
+            drain: Drain::new(),
+            hold_ttl: config.hold_ttl,
+            recorder: config.recorder,
+            limits: config.limits,
+            retry_delay: config.retry_delay,
+        }
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            drain: self.drain.clone(),
+            watchers: self.watchers.clone(),
+        }
+    }
+
+    // Returns the original reply when a request is replayed, or None if it should be executed
+    fn claim_request<U: Message>(
+        &self,
+        req_id: u64,
+        fingerprint: Vec<u8>,
+    ) -> Result<Option<U>, ErrorStatus> {
+        match self.requests.claim(req_id, fingerprint) {
+            Claim::New => Ok(None),
+            Claim::Replay(reply) => protobuf::parse_from_bytes(&reply).map(Some).map_err(|e| {
+                ErrorStatus::new(
+                    RpcStatusCode::INTERNAL,
+                    format!("Stored Reply Is Corrupt: {}", e),
+                    ErrorDetails::new("INTERNAL").with("req_id", req_id),
+                )
+            }),
+            Claim::InProgress => Err(ErrorStatus::new(
+                RpcStatusCode::ABORTED,
+                format!("Request {} Is Already In Progress", req_id),
+                ErrorDetails::new("REQUEST_IN_PROGRESS").with("req_id", req_id),
+            )),
+            Claim::Conflict => Err(ErrorStatus::from(DataStoreError::RequestIdReused(req_id))),
+        }
     }
 
-    fn send_error<U>(
+    // A request the store already recorded, e.g. before a restart or on another server, is
+    // answered with the reply it stored
+    fn finish_request<U: Message>(
         &self,
-        sink: UnarySink<U>,
+        req_id: u64,
+        res: Result<U, DataStoreError>,
+    ) -> Result<U, DataStoreError> {
+        let res = match res {
+            Err(DataStoreError::RequestReplayed(reply)) => protobuf::parse_from_bytes(&reply)
+                .map_err(|e| DataStoreError::Internal(format!("Stored Reply Is Corrupt: {}", e))),
+            res => res,
+        };
+        match res {
+            Ok(ref reply) => self
+                .requests
+                .complete(req_id, reply.write_to_bytes().unwrap_or_default()),
+            Err(_) => self.requests.release(req_id),
+        }
+        res
+    }
+
+    // Retryable errors tell the client how long to back off
+    fn rpc_status(&self, mut status: ErrorStatus) -> RpcStatus {
+        if status.is_retryable() && status.details.retry_delay.is_none() {
+            status.details.retry_delay = Some(self.retry_delay);
+        }
+        status.to_rpc_status()
+    }
+
+    fn send_error<U>(&self, sink: UnarySink<U>, ctx: RpcContext, status: ErrorStatus) {
+        let f = sink
+            .fail(self.rpc_status(status))
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn send_invalid<U>(&self, sink: UnarySink<U>, ctx: RpcContext, err: ValidationError) {
+        self.send_error(sink, ctx, ErrorStatus::from(err))
+    }
+
+    fn send_store_error<U>(&mut self, sink: UnarySink<U>, ctx: RpcContext, err: DataStoreError) {
+        let status = self.store_error(err);
+        self.send_error(sink, ctx, status)
+    }
+
+    fn send_shutting_down<U>(&self, sink: UnarySink<U>, ctx: RpcContext) {
+        self.send_error(sink, ctx, shutting_down())
+    }
+
+    fn send_stream_error<U>(
+        &self,
+        sink: ServerStreamingSink<U>,
         ctx: RpcContext,
-        status_code: RpcStatusCode,
-        arg_string: &str,
+        status: ErrorStatus,
     ) {
         let f = sink
-            .fail(RpcStatus::new(status_code, Some(arg_string.to_string())))
-            .map_err(move |e| println!("failed to reply: {:?}", e));
+            .fail(self.rpc_status(status))
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
         ctx.spawn(f);
     }
+
+    // Adds what the client needs to act on the error: the available balance of an overdrawn
+    // account and the limit a balance ran into
+    fn store_error(&mut self, err: DataStoreError) -> ErrorStatus {
+        let mut status = ErrorStatus::from(err.clone());
+        match err {
+            DataStoreError::InsufficientFunds(account) => {
+                if let Ok(Some(balance)) = self.store.get_account_balance(account) {
+                    status.details.set("available_balance", balance.available);
+                }
+            }
+            DataStoreError::BalanceOutOfRange(_) => {
+                status.details.set("max_balance", self.limits.amounts.max_balance);
+            }
+            _ => (),
+        }
+        status
+    }
+
+    // Retries inside the store give up once the caller's deadline has passed
+    fn bind_deadline(&mut self, ctx: &RpcContext) {
+        let deadline = ctx.deadline();
+        self.store.set_deadline(Some(Arc::new(move || deadline.exceeded())));
+    }
+
+    fn record<Req: JsonMessage, Resp: JsonMessage>(&self, method: &str, req: &Req, reply: &Resp) {
+        if let Some(ref recorder) = self.recorder {
+            recorder.record(method, req, Ok(reply));
+        }
+    }
+
+    // Only the message is recorded, replays compare it with the message they get back
+    fn record_error<Req: JsonMessage>(&self, method: &str, req: &Req, status: &ErrorStatus) {
+        if let Some(ref recorder) = self.recorder {
+            let err = RecordedError::new(status.code, &status.message);
+            recorder.record::<Req, Req>(method, req, Err(err));
+        }
+    }
+
+    // Sends the new balance of every watched account touched by a committed request
+    fn publish_balances<I: IntoIterator<Item = u32>>(&mut self, accounts: I) {
+        let accounts: Vec<u32> = accounts
+            .into_iter()
+            .filter(|&account| self.watchers.is_watched(account))
+            .collect();
+        if accounts.is_empty() {
+            return;
+        }
+        let store = &mut self.store;
+        self.watchers.publish(&accounts, |account| {
+            store.get_account_balance(account).ok().and_then(|balance| balance)
+        });
+    }
 }
 
 impl<T> AccountingService for GrpcAccountingService<T>
@@ -59,26 +462,52 @@ where
         req: CreateAccountRequest,
         sink: UnarySink<CreateAccountResponse>,
     ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
         let req_id = req.get_req_id();
         let account_id = req.get_account_id();
         let balance = req.get_balance();
+        if let Err(err) = self.limits.create_account(&req) {
+            let status = ErrorStatus::from(err);
+            self.record_error("CreateAccount", &req, &status);
+            return self.send_error(sink, ctx, status);
+        }
         let mut reply = CreateAccountResponse::new();
         reply.set_req_id(req_id);
         reply.set_account_id(account_id);
 
-        match self.store.create_account(account_id, req_id, balance) {
-            Ok(_) => {
+        let fingerprint = fingerprint("CreateAccount", &req);
+        let res = match self.claim_request(req_id, fingerprint.clone()) {
+            Ok(Some(stored)) => Ok(stored),
+            Ok(None) => {
+                let reply_bytes = reply.write_to_bytes().unwrap_or_default();
+                let request = RequestRecord { req_id, fingerprint, reply: reply_bytes };
+                self.store.set_request(Some(request));
+                let res = self.store.create_account(account_id, req_id, balance);
+                self.finish_request(req_id, res.map(|_| reply))
+            }
+            Err(status) => {
+                self.record_error("CreateAccount", &req, &status);
+                return self.send_error(sink, ctx, status);
+            }
+        };
+
+        match res {
+            Ok(reply) => {
+                self.record("CreateAccount", &req, &reply);
                 let f = sink
                     .success(reply)
-                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e));
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
                 ctx.spawn(f);
             }
-            Err(_) => self.send_error(
-                sink,
-                ctx,
-                RpcStatusCode::InvalidArgument,
-                "Account Already Exists",
-            ),
+            Err(err) => {
+                let status = self.store_error(err);
+                self.record_error("CreateAccount", &req, &status);
+                self.send_error(sink, ctx, status)
+            }
         }
     }
 
@@ -88,27 +517,37 @@ where
         req: GetBalanceRequest,
         sink: UnarySink<GetBalanceResponse>,
     ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
         let req_id = req.get_req_id();
         let account_id = req.get_account_id();
+        if let Err(err) = self.limits.get_balance(&req) {
+            let status = ErrorStatus::from(err);
+            self.record_error("GetBalance", &req, &status);
+            return self.send_error(sink, ctx, status);
+        }
 
-        match self.store.get_account_balance(account_id) {
-            Ok(-1) => self.send_error(
-                sink,
-                ctx,
-                RpcStatusCode::NotFound,
-                "Resource Not Found",
-            ),
-            Ok(balance) => {
-                let mut reply = GetBalanceResponse::new();
-                reply.set_req_id(req_id);
-                reply.set_account_id(account_id);
-                reply.set_balance(balance);
+        let res = match self.store.get_account_balance(account_id) {
+            Ok(None) => Err(DataStoreError::AccountNotFound(account_id)),
+            Ok(Some(balance)) => Ok(balance_response(req_id, account_id, balance)),
+            Err(err) => Err(err),
+        };
+        match res {
+            Ok(reply) => {
+                self.record("GetBalance", &req, &reply);
                 let f = sink
                     .success(reply)
-                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e));
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
                 ctx.spawn(f);
             }
-            Err(_) => self.send_error(sink, ctx, RpcStatusCode::Unknown, "Server Error"),
+            Err(err) => {
+                let status = self.store_error(err);
+                self.record_error("GetBalance", &req, &status);
+                self.send_error(sink, ctx, status)
+            }
         };
     }
 
@@ -118,35 +557,57 @@ where
         req: TransferRequest,
         sink: UnarySink<TransferResponse>,
     ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
         let req_id = req.get_req_id();
         let components = req.get_components();
-        let verify_total = components
-            .into_iter()
-            .fold(0, |sum, i| sum + i.get_money_delta());
-
-        if verify_total != 0 {
-            self.send_error(
-                sink,
-                ctx,
-                RpcStatusCode::FailedPrecondition,
-                "Sum of All Money Deltas Must Be Zero",
-            );
+
+        if let Err(err) = self.limits.transfer(&req) {
+            let mut status = ErrorStatus::from(err);
+            status.locate_component(components);
+            self.record_error("Transfer", &req, &status);
+            self.send_error(sink, ctx, status);
         } else {
-            match self.store.execute_transfers(&components, req_id as i64) {
-                Ok(_) => {
+            let fingerprint = fingerprint("Transfer", &req);
+            let res = match self.claim_request(req_id, fingerprint.clone()) {
+                Ok(Some(stored)) => Ok(stored),
+                Ok(None) => {
                     let mut reply = TransferResponse::new();
                     reply.set_req_id(req_id);
+                    let reply_bytes = reply.write_to_bytes().unwrap_or_default();
+                    let request = RequestRecord { req_id, fingerprint, reply: reply_bytes };
+                    self.store.set_request(Some(request));
+                    self.bind_deadline(&ctx);
+                    let plan = TransferPlan::new(components);
+                    let res = self.store.execute_transfers(&plan, req_id as i64);
+                    if res.is_ok() {
+                        self.publish_balances(plan.accounts());
+                    }
+                    self.finish_request(req_id, res.map(|_| reply))
+                }
+                Err(status) => {
+                    self.record_error("Transfer", &req, &status);
+                    return self.send_error(sink, ctx, status);
+                }
+            };
+
+            match res {
+                Ok(reply) => {
+                    self.record("Transfer", &req, &reply);
                     let f = sink
                         .success(reply)
-                        .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e));
+                        .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                        .map(|_| ());
                     ctx.spawn(f);
                 }
-                Err(_) => self.send_error(
-                    sink,
-                    ctx,
-                    RpcStatusCode::Aborted,
-                    "Transaction Error.  Possibly Due To Insufficient Funds",
-                ),
+                Err(err) => {
+                    let mut status = self.store_error(err);
+                    status.locate_component(components);
+                    self.record_error("Transfer", &req, &status);
+                    self.send_error(sink, ctx, status)
+                }
             }
         }
     }
@@ -157,20 +618,356 @@ where
         req: ResetRequest,
         sink: UnarySink<ResetResponse>,
     ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
         match self.store.reset() {
             Ok(_) => {
+                // replays must not resurrect state from before the reset
+                self.requests.clear();
+                self.watchers.clear();
                 let reply = ResetResponse::new();
                 let f = sink
                     .success(reply)
-                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e));
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
                 ctx.spawn(f);
             },
-            _ => self.send_error(
-                sink,
-                ctx,
-                RpcStatusCode::Unknown,
-                "Reset Failed",
-            ),
+            Err(err) => self.send_store_error(sink, ctx, err),
         };
     }
+
+    fn list_transactions(
+        &mut self,
+        ctx: RpcContext,
+        req: ListTransactionsRequest,
+        sink: UnarySink<ListTransactionsResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
+        let req_id = req.get_req_id();
+        if let Err(err) = self.limits.list_transactions(&req) {
+            return self.send_invalid(sink, ctx, err);
+        }
+        // validated, so only an empty token fails to parse
+        let after_id = req.get_page_token().parse::<u64>().ok();
+        let page_size = match req.get_page_size() as usize {
+            0 => DEFAULT_PAGE_SIZE,
+            size => cmp::min(size, MAX_PAGE_SIZE),
+        };
+        let filter = TransactionFilter {
+            account_ids: req.get_account_ids().to_vec(),
+            req_ids: req.get_transfer_req_ids().to_vec(),
+            start_time: Some(req.get_start_time()).filter(|&time| time != 0),
+            end_time: Some(req.get_end_time()).filter(|&time| time != 0),
+            after_id,
+            // one extra row tells us whether there is another page
+            limit: page_size + 1,
+        };
+
+        match self.store.list_transactions(&filter) {
+            Ok(mut records) => {
+                let mut reply = ListTransactionsResponse::new();
+                reply.set_req_id(req_id);
+                if records.len() > page_size {
+                    records.truncate(page_size);
+                    reply.set_next_page_token(records[page_size - 1].get_id().to_string());
+                }
+                reply.set_transactions(RepeatedField::from_vec(records));
+                let f = sink
+                    .success(reply)
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
+                ctx.spawn(f);
+            }
+            Err(err) => self.send_store_error(sink, ctx, err),
+        }
+    }
+
+    fn watch_balance(
+        &mut self,
+        ctx: RpcContext,
+        req: WatchBalanceRequest,
+        sink: ServerStreamingSink<GetBalanceResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_stream_error(sink, ctx, shutting_down()),
+        };
+        let req_id = req.get_req_id();
+        if let Err(err) = self.limits.watch_balance(&req) {
+            return self.send_stream_error(sink, ctx, ErrorStatus::from(err));
+        }
+        let mut accounts = req.get_account_ids().to_vec();
+        accounts.sort();
+        accounts.dedup();
+
+        // subscribe before reading balances so a transfer committing in between is not missed
+        let watch = self.watchers.subscribe(req_id, &accounts);
+        let mut snapshot = Vec::new();
+        for account in accounts {
+            let err = match self.store.get_account_balance(account) {
+                Ok(Some(balance)) => {
+                    snapshot.push(balance_response(req_id, account, balance));
+                    continue;
+                }
+                Ok(None) => DataStoreError::AccountNotFound(account),
+                Err(err) => err,
+            };
+            let status = self.store_error(err);
+            return self.send_stream_error(sink, ctx, status);
+        }
+
+        // the stream only ends on reset; a disconnected client fails the forward, which
+        // drops `watch` and unsubscribes it
+        let sink = sink.with(|reply| {
+            future::ok::<_, grpcio::Error>((reply, WriteFlags::default()))
+        });
+        let f = WatchForward::new(sink, snapshot, watch)
+            .map_err(move |e| println!("failed to stream {:?}: {:?}", req, e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn authorize_transfer(
+        &mut self,
+        ctx: RpcContext,
+        req: AuthorizeTransferRequest,
+        sink: UnarySink<AuthorizeTransferResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
+        let req_id = req.get_req_id();
+        let from_account = req.get_from_account_id();
+        let to_account = req.get_to_account_id();
+        let amount = req.get_amount();
+        if let Err(err) = self.limits.authorize_transfer(&req) {
+            return self.send_invalid(sink, ctx, err);
+        }
+
+        let res = match self.claim_request(req_id, fingerprint("AuthorizeTransfer", &req)) {
+            Ok(Some(stored)) => Ok(stored),
+            Ok(None) => {
+                let hold = Hold {
+                    id: req_id,
+                    account_id: from_account,
+                    to_account_id: to_account,
+                    amount,
+                    captured: 0,
+                    status: HoldStatus::Active,
+                    expires_at: now_millis() + duration_millis(self.hold_ttl),
+                };
+                let mut reply = AuthorizeTransferResponse::new();
+                reply.set_req_id(req_id);
+                reply.set_hold_id(hold.id);
+                reply.set_expires_at(hold.expires_at);
+                self.bind_deadline(&ctx);
+                let res = self.store.authorize_transfer(&hold);
+                if res.is_ok() {
+                    self.publish_balances(vec![from_account]);
+                }
+                self.finish_request(req_id, res.map(|_| reply))
+            }
+            Err(status) => return self.send_error(sink, ctx, status),
+        };
+
+        match res {
+            Ok(reply) => {
+                let f = sink
+                    .success(reply)
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
+                ctx.spawn(f);
+            }
+            Err(err) => self.send_store_error(sink, ctx, err),
+        }
+    }
+
+    fn capture_transfer(
+        &mut self,
+        ctx: RpcContext,
+        req: CaptureTransferRequest,
+        sink: UnarySink<CaptureTransferResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
+        let req_id = req.get_req_id();
+        let hold_id = req.get_hold_id();
+        if let Err(err) = self.limits.capture_transfer(&req) {
+            return self.send_invalid(sink, ctx, err);
+        }
+        let amount = Some(req.get_amount()).filter(|&amount| amount != 0);
+
+        let res = match self.claim_request(req_id, fingerprint("CaptureTransfer", &req)) {
+            Ok(Some(stored)) => Ok(stored),
+            Ok(None) => {
+                self.bind_deadline(&ctx);
+                let res = self.store.capture_transfer(hold_id, amount, req_id as i64);
+                if let Ok(ref hold) = res {
+                    self.publish_balances(vec![hold.account_id, hold.to_account_id]);
+                }
+                let res = res.map(|hold| {
+                    let mut reply = CaptureTransferResponse::new();
+                    reply.set_req_id(req_id);
+                    reply.set_hold_id(hold_id);
+                    reply.set_captured_amount(hold.captured);
+                    reply
+                });
+                self.finish_request(req_id, res)
+            }
+            Err(status) => return self.send_error(sink, ctx, status),
+        };
+
+        match res {
+            Ok(reply) => {
+                let f = sink
+                    .success(reply)
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
+                ctx.spawn(f);
+            }
+            Err(err) => self.send_store_error(sink, ctx, err),
+        }
+    }
+
+    fn void_transfer(
+        &mut self,
+        ctx: RpcContext,
+        req: VoidTransferRequest,
+        sink: UnarySink<VoidTransferResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
+        let req_id = req.get_req_id();
+        let hold_id = req.get_hold_id();
+        if let Err(err) = self.limits.void_transfer(&req) {
+            return self.send_invalid(sink, ctx, err);
+        }
+
+        let res = match self.claim_request(req_id, fingerprint("VoidTransfer", &req)) {
+            Ok(Some(stored)) => Ok(stored),
+            Ok(None) => {
+                self.bind_deadline(&ctx);
+                let res = self.store.void_transfer(hold_id);
+                if let Ok(ref hold) = res {
+                    self.publish_balances(vec![hold.account_id]);
+                }
+                let mut reply = VoidTransferResponse::new();
+                reply.set_req_id(req_id);
+                reply.set_hold_id(hold_id);
+                self.finish_request(req_id, res.map(|_| reply))
+            }
+            Err(status) => return self.send_error(sink, ctx, status),
+        };
+
+        match res {
+            Ok(reply) => {
+                let f = sink
+                    .success(reply)
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
+                ctx.spawn(f);
+            }
+            Err(err) => self.send_store_error(sink, ctx, err),
+        }
+    }
+
+    fn reverse_transfer(
+        &mut self,
+        ctx: RpcContext,
+        req: ReverseTransferRequest,
+        sink: UnarySink<ReverseTransferResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
+        let req_id = req.get_req_id();
+        let original_req_id = req.get_original_req_id();
+
+        if let Err(err) = self.limits.reverse_transfer(&req) {
+            return self.send_invalid(sink, ctx, err);
+        }
+
+        let res = match self.claim_request(req_id, fingerprint("ReverseTransfer", &req)) {
+            Ok(Some(stored)) => Ok(stored),
+            Ok(None) => {
+                self.bind_deadline(&ctx);
+                let res = self.store.reverse_transfer(original_req_id as i64, req_id as i64);
+                if let Ok(ref components) = res {
+                    self.publish_balances(components.iter().map(|c| c.get_account_id()));
+                }
+                let mut reply = ReverseTransferResponse::new();
+                reply.set_req_id(req_id);
+                reply.set_original_req_id(original_req_id);
+                self.finish_request(req_id, res.map(|_| reply))
+            }
+            Err(status) => return self.send_error(sink, ctx, status),
+        };
+
+        match res {
+            Ok(reply) => {
+                let f = sink
+                    .success(reply)
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
+                ctx.spawn(f);
+            }
+            Err(err) => self.send_store_error(sink, ctx, err),
+        }
+    }
+
+    fn verify_ledger(
+        &mut self,
+        ctx: RpcContext,
+        req: VerifyLedgerRequest,
+        sink: UnarySink<VerifyLedgerResponse>,
+    ) {
+        let _request = match self.drain.begin() {
+            Some(request) => request,
+            None => return self.send_shutting_down(sink, ctx),
+        };
+        let rebuild = req.get_rebuild();
+        self.bind_deadline(&ctx);
+        match self.store.verify_ledger(rebuild) {
+            Ok(report) => {
+                if rebuild {
+                    self.publish_balances(report.mismatches.iter().map(|m| m.account_id));
+                }
+                let mismatches = report.mismatches.iter().map(|mismatch| {
+                    let mut reply = proto::accounting::LedgerMismatch::new();
+                    reply.set_account_id(mismatch.account_id);
+                    reply.set_stored_balance(mismatch.stored);
+                    reply.set_ledger_balance(mismatch.projected);
+                    reply
+                });
+                let backfilled = report.backfilled.iter().map(|backfill| {
+                    let mut reply = proto::accounting::LedgerBackfill::new();
+                    reply.set_account_id(backfill.account_id);
+                    reply.set_amount(backfill.amount);
+                    reply
+                });
+                let mut reply = VerifyLedgerResponse::new();
+                reply.set_accounts_checked(report.accounts);
+                reply.set_mismatches(RepeatedField::from_vec(mismatches.collect()));
+                reply.set_backfilled(RepeatedField::from_vec(backfilled.collect()));
+                reply.set_rebuilt(rebuild && !report.mismatches.is_empty());
+                let f = sink
+                    .success(reply)
+                    .map_err(move |e| println!("failed to reply {:?}: {:?}", req, e))
+                    .map(|_| ());
+                ctx.spawn(f);
+            }
+            Err(err) => self.send_store_error(sink, ctx, err),
+        }
+    }
 }
@@ -1,11 +1,12 @@
-// This file is generated by rust-protobuf 2.3.0. Do not edit
+// This file is generated by rust-protobuf 2.28.0. Do not edit
 // @generated
 
-// https://github.com/Manishearth/rust-clippy/issues/702
+// https://github.com/rust-lang/rust-clippy/issues/702
 #![allow(unknown_lints)]
-#![allow(clippy)]
+#![allow(clippy::all)]
 
-#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
@@ -14,12 +15,13 @@
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 #![allow(trivial_casts)]
-#![allow(unsafe_code)]
 #![allow(unused_imports)]
 #![allow(unused_results)]
+//! Generated file from `accounting.proto`
 
-use protobuf::Message as Message_imported_for_functions;
-use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
 
 #[derive(PartialEq,Clone,Default)]
 pub struct ResetRequest {
@@ -28,6 +30,12 @@ pub struct ResetRequest {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a ResetRequest {
+    fn default() -> &'a ResetRequest {
+        <ResetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl ResetRequest {
     pub fn new() -> ResetRequest {
         ::std::default::Default::default()
@@ -39,7 +47,7 @@ impl ::protobuf::Message for ResetRequest {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -60,7 +68,7 @@ impl ::protobuf::Message for ResetRequest {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -77,13 +85,13 @@ impl ::protobuf::Message for ResetRequest {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -96,30 +104,20 @@ impl ::protobuf::Message for ResetRequest {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<ResetRequest>(
-                    "ResetRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResetRequest>(
+                "ResetRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static ResetRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ResetRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ResetRequest,
-        };
-        unsafe {
-            instance.get(ResetRequest::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<ResetRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResetRequest::new)
     }
 }
 
@@ -130,14 +128,14 @@ impl ::protobuf::Clear for ResetRequest {
 }
 
 impl ::std::fmt::Debug for ResetRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for ResetRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -148,6 +146,12 @@ pub struct ResetResponse {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a ResetResponse {
+    fn default() -> &'a ResetResponse {
+        <ResetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl ResetResponse {
     pub fn new() -> ResetResponse {
         ::std::default::Default::default()
@@ -159,7 +163,7 @@ impl ::protobuf::Message for ResetResponse {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -180,7 +184,7 @@ impl ::protobuf::Message for ResetResponse {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -197,13 +201,13 @@ impl ::protobuf::Message for ResetResponse {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -216,30 +220,20 @@ impl ::protobuf::Message for ResetResponse {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<ResetResponse>(
-                    "ResetResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResetResponse>(
+                "ResetResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static ResetResponse {
-        static mut instance: ::protobuf::lazy::Lazy<ResetResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ResetResponse,
-        };
-        unsafe {
-            instance.get(ResetResponse::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<ResetResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResetResponse::new)
     }
 }
 
@@ -250,14 +244,14 @@ impl ::protobuf::Clear for ResetResponse {
 }
 
 impl ::std::fmt::Debug for ResetResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for ResetResponse {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -272,6 +266,12 @@ pub struct CreateAccountRequest {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a CreateAccountRequest {
+    fn default() -> &'a CreateAccountRequest {
+        <CreateAccountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl CreateAccountRequest {
     pub fn new() -> CreateAccountRequest {
         ::std::default::Default::default()
@@ -279,6 +279,10 @@ impl CreateAccountRequest {
 
     // fixed64 req_id = 1;
 
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
     pub fn clear_req_id(&mut self) {
         self.req_id = 0;
     }
@@ -288,12 +292,12 @@ impl CreateAccountRequest {
         self.req_id = v;
     }
 
-    pub fn get_req_id(&self) -> u64 {
-        self.req_id
-    }
-
     // fixed32 account_id = 2;
 
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
     pub fn clear_account_id(&mut self) {
         self.account_id = 0;
     }
@@ -303,12 +307,12 @@ impl CreateAccountRequest {
         self.account_id = v;
     }
 
-    pub fn get_account_id(&self) -> u32 {
-        self.account_id
-    }
-
     // sfixed64 balance = 3;
 
+
+    pub fn get_balance(&self) -> i64 {
+        self.balance
+    }
     pub fn clear_balance(&mut self) {
         self.balance = 0;
     }
@@ -317,10 +321,6 @@ impl CreateAccountRequest {
     pub fn set_balance(&mut self, v: i64) {
         self.balance = v;
     }
-
-    pub fn get_balance(&self) -> i64 {
-        self.balance
-    }
 }
 
 impl ::protobuf::Message for CreateAccountRequest {
@@ -328,7 +328,7 @@ impl ::protobuf::Message for CreateAccountRequest {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -379,7 +379,7 @@ impl ::protobuf::Message for CreateAccountRequest {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.req_id != 0 {
             os.write_fixed64(1, self.req_id)?;
         }
@@ -405,13 +405,13 @@ impl ::protobuf::Message for CreateAccountRequest {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -424,66 +424,56 @@ impl ::protobuf::Message for CreateAccountRequest {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
-                    "req_id",
-                    |m: &CreateAccountRequest| { &m.req_id },
-                    |m: &mut CreateAccountRequest| { &mut m.req_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
-                    "account_id",
-                    |m: &CreateAccountRequest| { &m.account_id },
-                    |m: &mut CreateAccountRequest| { &mut m.account_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
-                    "balance",
-                    |m: &CreateAccountRequest| { &m.balance },
-                    |m: &mut CreateAccountRequest| { &mut m.balance },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CreateAccountRequest>(
-                    "CreateAccountRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &CreateAccountRequest| { &m.req_id },
+                |m: &mut CreateAccountRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &CreateAccountRequest| { &m.account_id },
+                |m: &mut CreateAccountRequest| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "balance",
+                |m: &CreateAccountRequest| { &m.balance },
+                |m: &mut CreateAccountRequest| { &mut m.balance },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAccountRequest>(
+                "CreateAccountRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static CreateAccountRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CreateAccountRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CreateAccountRequest,
-        };
-        unsafe {
-            instance.get(CreateAccountRequest::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<CreateAccountRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateAccountRequest::new)
     }
 }
 
 impl ::protobuf::Clear for CreateAccountRequest {
     fn clear(&mut self) {
-        self.clear_req_id();
-        self.clear_account_id();
-        self.clear_balance();
+        self.req_id = 0;
+        self.account_id = 0;
+        self.balance = 0;
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for CreateAccountRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for CreateAccountRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -497,6 +487,12 @@ pub struct CreateAccountResponse {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a CreateAccountResponse {
+    fn default() -> &'a CreateAccountResponse {
+        <CreateAccountResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl CreateAccountResponse {
     pub fn new() -> CreateAccountResponse {
         ::std::default::Default::default()
@@ -504,6 +500,10 @@ impl CreateAccountResponse {
 
     // fixed64 req_id = 1;
 
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
     pub fn clear_req_id(&mut self) {
         self.req_id = 0;
     }
@@ -513,12 +513,12 @@ impl CreateAccountResponse {
         self.req_id = v;
     }
 
-    pub fn get_req_id(&self) -> u64 {
-        self.req_id
-    }
-
     // fixed32 account_id = 2;
 
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
     pub fn clear_account_id(&mut self) {
         self.account_id = 0;
     }
@@ -527,10 +527,6 @@ impl CreateAccountResponse {
     pub fn set_account_id(&mut self, v: u32) {
         self.account_id = v;
     }
-
-    pub fn get_account_id(&self) -> u32 {
-        self.account_id
-    }
 }
 
 impl ::protobuf::Message for CreateAccountResponse {
@@ -538,7 +534,7 @@ impl ::protobuf::Message for CreateAccountResponse {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -579,7 +575,7 @@ impl ::protobuf::Message for CreateAccountResponse {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.req_id != 0 {
             os.write_fixed64(1, self.req_id)?;
         }
@@ -602,13 +598,13 @@ impl ::protobuf::Message for CreateAccountResponse {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -621,60 +617,50 @@ impl ::protobuf::Message for CreateAccountResponse {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
-                    "req_id",
-                    |m: &CreateAccountResponse| { &m.req_id },
-                    |m: &mut CreateAccountResponse| { &mut m.req_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
-                    "account_id",
-                    |m: &CreateAccountResponse| { &m.account_id },
-                    |m: &mut CreateAccountResponse| { &mut m.account_id },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CreateAccountResponse>(
-                    "CreateAccountResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &CreateAccountResponse| { &m.req_id },
+                |m: &mut CreateAccountResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &CreateAccountResponse| { &m.account_id },
+                |m: &mut CreateAccountResponse| { &mut m.account_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAccountResponse>(
+                "CreateAccountResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static CreateAccountResponse {
-        static mut instance: ::protobuf::lazy::Lazy<CreateAccountResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CreateAccountResponse,
-        };
-        unsafe {
-            instance.get(CreateAccountResponse::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<CreateAccountResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateAccountResponse::new)
     }
 }
 
 impl ::protobuf::Clear for CreateAccountResponse {
     fn clear(&mut self) {
-        self.clear_req_id();
-        self.clear_account_id();
+        self.req_id = 0;
+        self.account_id = 0;
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for CreateAccountResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for CreateAccountResponse {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -688,6 +674,12 @@ pub struct GetBalanceRequest {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a GetBalanceRequest {
+    fn default() -> &'a GetBalanceRequest {
+        <GetBalanceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl GetBalanceRequest {
     pub fn new() -> GetBalanceRequest {
         ::std::default::Default::default()
@@ -695,6 +687,10 @@ impl GetBalanceRequest {
 
     // fixed64 req_id = 1;
 
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
     pub fn clear_req_id(&mut self) {
         self.req_id = 0;
     }
@@ -704,12 +700,12 @@ impl GetBalanceRequest {
         self.req_id = v;
     }
 
-    pub fn get_req_id(&self) -> u64 {
-        self.req_id
-    }
-
     // fixed32 account_id = 2;
 
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
     pub fn clear_account_id(&mut self) {
         self.account_id = 0;
     }
@@ -718,10 +714,6 @@ impl GetBalanceRequest {
     pub fn set_account_id(&mut self, v: u32) {
         self.account_id = v;
     }
-
-    pub fn get_account_id(&self) -> u32 {
-        self.account_id
-    }
 }
 
 impl ::protobuf::Message for GetBalanceRequest {
@@ -729,7 +721,7 @@ impl ::protobuf::Message for GetBalanceRequest {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -770,7 +762,7 @@ impl ::protobuf::Message for GetBalanceRequest {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.req_id != 0 {
             os.write_fixed64(1, self.req_id)?;
         }
@@ -793,13 +785,13 @@ impl ::protobuf::Message for GetBalanceRequest {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -812,60 +804,50 @@ impl ::protobuf::Message for GetBalanceRequest {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
-                    "req_id",
-                    |m: &GetBalanceRequest| { &m.req_id },
-                    |m: &mut GetBalanceRequest| { &mut m.req_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
-                    "account_id",
-                    |m: &GetBalanceRequest| { &m.account_id },
-                    |m: &mut GetBalanceRequest| { &mut m.account_id },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<GetBalanceRequest>(
-                    "GetBalanceRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &GetBalanceRequest| { &m.req_id },
+                |m: &mut GetBalanceRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &GetBalanceRequest| { &m.account_id },
+                |m: &mut GetBalanceRequest| { &mut m.account_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetBalanceRequest>(
+                "GetBalanceRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static GetBalanceRequest {
-        static mut instance: ::protobuf::lazy::Lazy<GetBalanceRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GetBalanceRequest,
-        };
-        unsafe {
-            instance.get(GetBalanceRequest::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<GetBalanceRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetBalanceRequest::new)
     }
 }
 
 impl ::protobuf::Clear for GetBalanceRequest {
     fn clear(&mut self) {
-        self.clear_req_id();
-        self.clear_account_id();
+        self.req_id = 0;
+        self.account_id = 0;
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for GetBalanceRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for GetBalanceRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -875,11 +857,18 @@ pub struct GetBalanceResponse {
     pub req_id: u64,
     pub account_id: u32,
     pub balance: i64,
+    pub available_balance: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a GetBalanceResponse {
+    fn default() -> &'a GetBalanceResponse {
+        <GetBalanceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl GetBalanceResponse {
     pub fn new() -> GetBalanceResponse {
         ::std::default::Default::default()
@@ -887,6 +876,10 @@ impl GetBalanceResponse {
 
     // fixed64 req_id = 1;
 
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
     pub fn clear_req_id(&mut self) {
         self.req_id = 0;
     }
@@ -896,12 +889,12 @@ impl GetBalanceResponse {
         self.req_id = v;
     }
 
-    pub fn get_req_id(&self) -> u64 {
-        self.req_id
-    }
-
     // fixed32 account_id = 2;
 
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
     pub fn clear_account_id(&mut self) {
         self.account_id = 0;
     }
@@ -911,12 +904,12 @@ impl GetBalanceResponse {
         self.account_id = v;
     }
 
-    pub fn get_account_id(&self) -> u32 {
-        self.account_id
-    }
-
     // sfixed64 balance = 3;
 
+
+    pub fn get_balance(&self) -> i64 {
+        self.balance
+    }
     pub fn clear_balance(&mut self) {
         self.balance = 0;
     }
@@ -926,8 +919,19 @@ impl GetBalanceResponse {
         self.balance = v;
     }
 
-    pub fn get_balance(&self) -> i64 {
-        self.balance
+    // sfixed64 available_balance = 4;
+
+
+    pub fn get_available_balance(&self) -> i64 {
+        self.available_balance
+    }
+    pub fn clear_available_balance(&mut self) {
+        self.available_balance = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_available_balance(&mut self, v: i64) {
+        self.available_balance = v;
     }
 }
 
@@ -936,7 +940,7 @@ impl ::protobuf::Message for GetBalanceResponse {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -961,6 +965,13 @@ impl ::protobuf::Message for GetBalanceResponse {
                     let tmp = is.read_sfixed64()?;
                     self.balance = tmp;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.available_balance = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -982,12 +993,15 @@ impl ::protobuf::Message for GetBalanceResponse {
         if self.balance != 0 {
             my_size += 9;
         }
+        if self.available_balance != 0 {
+            my_size += 9;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.req_id != 0 {
             os.write_fixed64(1, self.req_id)?;
         }
@@ -997,6 +1011,9 @@ impl ::protobuf::Message for GetBalanceResponse {
         if self.balance != 0 {
             os.write_sfixed64(3, self.balance)?;
         }
+        if self.available_balance != 0 {
+            os.write_sfixed64(4, self.available_balance)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1013,13 +1030,13 @@ impl ::protobuf::Message for GetBalanceResponse {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -1032,66 +1049,62 @@ impl ::protobuf::Message for GetBalanceResponse {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
-                    "req_id",
-                    |m: &GetBalanceResponse| { &m.req_id },
-                    |m: &mut GetBalanceResponse| { &mut m.req_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
-                    "account_id",
-                    |m: &GetBalanceResponse| { &m.account_id },
-                    |m: &mut GetBalanceResponse| { &mut m.account_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
-                    "balance",
-                    |m: &GetBalanceResponse| { &m.balance },
-                    |m: &mut GetBalanceResponse| { &mut m.balance },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<GetBalanceResponse>(
-                    "GetBalanceResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &GetBalanceResponse| { &m.req_id },
+                |m: &mut GetBalanceResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &GetBalanceResponse| { &m.account_id },
+                |m: &mut GetBalanceResponse| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "balance",
+                |m: &GetBalanceResponse| { &m.balance },
+                |m: &mut GetBalanceResponse| { &mut m.balance },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "available_balance",
+                |m: &GetBalanceResponse| { &m.available_balance },
+                |m: &mut GetBalanceResponse| { &mut m.available_balance },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetBalanceResponse>(
+                "GetBalanceResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static GetBalanceResponse {
-        static mut instance: ::protobuf::lazy::Lazy<GetBalanceResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GetBalanceResponse,
-        };
-        unsafe {
-            instance.get(GetBalanceResponse::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<GetBalanceResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetBalanceResponse::new)
     }
 }
 
 impl ::protobuf::Clear for GetBalanceResponse {
     fn clear(&mut self) {
-        self.clear_req_id();
-        self.clear_account_id();
-        self.clear_balance();
+        self.req_id = 0;
+        self.account_id = 0;
+        self.balance = 0;
+        self.available_balance = 0;
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for GetBalanceResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for GetBalanceResponse {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -1105,6 +1118,12 @@ pub struct TransferComponent {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a TransferComponent {
+    fn default() -> &'a TransferComponent {
+        <TransferComponent as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl TransferComponent {
     pub fn new() -> TransferComponent {
         ::std::default::Default::default()
@@ -1112,6 +1131,10 @@ impl TransferComponent {
 
     // fixed32 account_id = 1;
 
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
     pub fn clear_account_id(&mut self) {
         self.account_id = 0;
     }
@@ -1121,12 +1144,12 @@ impl TransferComponent {
         self.account_id = v;
     }
 
-    pub fn get_account_id(&self) -> u32 {
-        self.account_id
-    }
-
     // sfixed64 money_delta = 2;
 
+
+    pub fn get_money_delta(&self) -> i64 {
+        self.money_delta
+    }
     pub fn clear_money_delta(&mut self) {
         self.money_delta = 0;
     }
@@ -1135,10 +1158,6 @@ impl TransferComponent {
     pub fn set_money_delta(&mut self, v: i64) {
         self.money_delta = v;
     }
-
-    pub fn get_money_delta(&self) -> i64 {
-        self.money_delta
-    }
 }
 
 impl ::protobuf::Message for TransferComponent {
@@ -1146,7 +1165,7 @@ impl ::protobuf::Message for TransferComponent {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -1187,7 +1206,7 @@ impl ::protobuf::Message for TransferComponent {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.account_id != 0 {
             os.write_fixed32(1, self.account_id)?;
         }
@@ -1210,13 +1229,13 @@ impl ::protobuf::Message for TransferComponent {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -1229,60 +1248,50 @@ impl ::protobuf::Message for TransferComponent {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
-                    "account_id",
-                    |m: &TransferComponent| { &m.account_id },
-                    |m: &mut TransferComponent| { &mut m.account_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
-                    "money_delta",
-                    |m: &TransferComponent| { &m.money_delta },
-                    |m: &mut TransferComponent| { &mut m.money_delta },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<TransferComponent>(
-                    "TransferComponent",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &TransferComponent| { &m.account_id },
+                |m: &mut TransferComponent| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "money_delta",
+                |m: &TransferComponent| { &m.money_delta },
+                |m: &mut TransferComponent| { &mut m.money_delta },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TransferComponent>(
+                "TransferComponent",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static TransferComponent {
-        static mut instance: ::protobuf::lazy::Lazy<TransferComponent> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const TransferComponent,
-        };
-        unsafe {
-            instance.get(TransferComponent::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<TransferComponent> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TransferComponent::new)
     }
 }
 
 impl ::protobuf::Clear for TransferComponent {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_money_delta();
+        self.account_id = 0;
+        self.money_delta = 0;
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for TransferComponent {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for TransferComponent {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -1296,6 +1305,12 @@ pub struct TransferRequest {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a TransferRequest {
+    fn default() -> &'a TransferRequest {
+        <TransferRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl TransferRequest {
     pub fn new() -> TransferRequest {
         ::std::default::Default::default()
@@ -1303,6 +1318,10 @@ impl TransferRequest {
 
     // fixed64 req_id = 1;
 
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
     pub fn clear_req_id(&mut self) {
         self.req_id = 0;
     }
@@ -1312,12 +1331,12 @@ impl TransferRequest {
         self.req_id = v;
     }
 
-    pub fn get_req_id(&self) -> u64 {
-        self.req_id
-    }
-
     // repeated .accounting.TransferComponent components = 2;
 
+
+    pub fn get_components(&self) -> &[TransferComponent] {
+        &self.components
+    }
     pub fn clear_components(&mut self) {
         self.components.clear();
     }
@@ -1336,10 +1355,6 @@ impl TransferRequest {
     pub fn take_components(&mut self) -> ::protobuf::RepeatedField<TransferComponent> {
         ::std::mem::replace(&mut self.components, ::protobuf::RepeatedField::new())
     }
-
-    pub fn get_components(&self) -> &[TransferComponent] {
-        &self.components
-    }
 }
 
 impl ::protobuf::Message for TransferRequest {
@@ -1352,7 +1367,7 @@ impl ::protobuf::Message for TransferRequest {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -1390,7 +1405,7 @@ impl ::protobuf::Message for TransferRequest {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.req_id != 0 {
             os.write_fixed64(1, self.req_id)?;
         }
@@ -1415,13 +1430,13 @@ impl ::protobuf::Message for TransferRequest {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -1434,60 +1449,50 @@ impl ::protobuf::Message for TransferRequest {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
-                    "req_id",
-                    |m: &TransferRequest| { &m.req_id },
-                    |m: &mut TransferRequest| { &mut m.req_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransferComponent>>(
-                    "components",
-                    |m: &TransferRequest| { &m.components },
-                    |m: &mut TransferRequest| { &mut m.components },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<TransferRequest>(
-                    "TransferRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &TransferRequest| { &m.req_id },
+                |m: &mut TransferRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransferComponent>>(
+                "components",
+                |m: &TransferRequest| { &m.components },
+                |m: &mut TransferRequest| { &mut m.components },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TransferRequest>(
+                "TransferRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static TransferRequest {
-        static mut instance: ::protobuf::lazy::Lazy<TransferRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const TransferRequest,
-        };
-        unsafe {
-            instance.get(TransferRequest::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<TransferRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TransferRequest::new)
     }
 }
 
 impl ::protobuf::Clear for TransferRequest {
     fn clear(&mut self) {
-        self.clear_req_id();
-        self.clear_components();
+        self.req_id = 0;
+        self.components.clear();
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for TransferRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for TransferRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
@@ -1500,6 +1505,12 @@ pub struct TransferResponse {
     pub cached_size: ::protobuf::CachedSize,
 }
 
+impl<'a> ::std::default::Default for &'a TransferResponse {
+    fn default() -> &'a TransferResponse {
+        <TransferResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
 impl TransferResponse {
     pub fn new() -> TransferResponse {
         ::std::default::Default::default()
@@ -1507,6 +1518,10 @@ impl TransferResponse {
 
     // fixed64 req_id = 1;
 
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
     pub fn clear_req_id(&mut self) {
         self.req_id = 0;
     }
@@ -1515,10 +1530,6 @@ impl TransferResponse {
     pub fn set_req_id(&mut self, v: u64) {
         self.req_id = v;
     }
-
-    pub fn get_req_id(&self) -> u64 {
-        self.req_id
-    }
 }
 
 impl ::protobuf::Message for TransferResponse {
@@ -1526,7 +1537,7 @@ impl ::protobuf::Message for TransferResponse {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
@@ -1557,7 +1568,7 @@ impl ::protobuf::Message for TransferResponse {
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
         if self.req_id != 0 {
             os.write_fixed64(1, self.req_id)?;
         }
@@ -1577,13 +1588,13 @@ impl ::protobuf::Message for TransferResponse {
         &mut self.unknown_fields
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
         self
     }
 
@@ -1596,92 +1607,4064 @@ impl ::protobuf::Message for TransferResponse {
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
-                    "req_id",
-                    |m: &TransferResponse| { &m.req_id },
-                    |m: &mut TransferResponse| { &mut m.req_id },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<TransferResponse>(
-                    "TransferResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &TransferResponse| { &m.req_id },
+                |m: &mut TransferResponse| { &mut m.req_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TransferResponse>(
+                "TransferResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 
     fn default_instance() -> &'static TransferResponse {
-        static mut instance: ::protobuf::lazy::Lazy<TransferResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const TransferResponse,
-        };
-        unsafe {
-            instance.get(TransferResponse::new)
-        }
+        static instance: ::protobuf::rt::LazyV2<TransferResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TransferResponse::new)
     }
 }
 
 impl ::protobuf::Clear for TransferResponse {
     fn clear(&mut self) {
-        self.clear_req_id();
+        self.req_id = 0;
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::fmt::Debug for TransferResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 impl ::protobuf::reflect::ProtobufValue for TransferResponse {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
-static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10accounting.proto\x12\naccounting\"\x0e\n\x0cResetRequest\"\x0f\n\r\
-    ResetResponse\"K\n\x14CreateAccountRequest\x12\x0e\n\x06req_id\x18\x01\
-    \x20\x01(\x06\x12\x12\n\naccount_id\x18\x02\x20\x01(\x07\x12\x0f\n\x07ba\
-    lance\x18\x03\x20\x01(\x10\";\n\x15CreateAccountResponse\x12\x0e\n\x06re\
-    q_id\x18\x01\x20\x01(\x06\x12\x12\n\naccount_id\x18\x02\x20\x01(\x07\"7\
-    \n\x11GetBalanceRequest\x12\x0e\n\x06req_id\x18\x01\x20\x01(\x06\x12\x12\
-    \n\naccount_id\x18\x02\x20\x01(\x07\"I\n\x12GetBalanceResponse\x12\x0e\n\
-    \x06req_id\x18\x01\x20\x01(\x06\x12\x12\n\naccount_id\x18\x02\x20\x01(\
-    \x07\x12\x0f\n\x07balance\x18\x03\x20\x01(\x10\"<\n\x11TransferComponent\
-    \x12\x12\n\naccount_id\x18\x01\x20\x01(\x07\x12\x13\n\x0bmoney_delta\x18\
-    \x02\x20\x01(\x10\"T\n\x0fTransferRequest\x12\x0e\n\x06req_id\x18\x01\
-    \x20\x01(\x06\x121\n\ncomponents\x18\x02\x20\x03(\x0b2\x1d.accounting.Tr\
-    ansferComponent\"\"\n\x10TransferResponse\x12\x0e\n\x06req_id\x18\x01\
-    \x20\x01(\x062\xbb\x02\n\x11AccountingService\x12T\n\rCreateAccount\x12\
-    \x20.accounting.CreateAccountRequest\x1a!.accounting.CreateAccountRespon\
-    se\x12K\n\nGetBalance\x12\x1d.accounting.GetBalanceRequest\x1a\x1e.accou\
-    nting.GetBalanceResponse\x12E\n\x08Transfer\x12\x1b.accounting.TransferR\
-    equest\x1a\x1c.accounting.TransferResponse\x12<\n\x05Reset\x12\x18.accou\
-    nting.ResetRequest\x1a\x19.accounting.ResetResponseb\x06proto3\
-";
-
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+#[derive(PartialEq,Clone,Default)]
+pub struct TransactionRecord {
+    // message fields
+    pub id: u64,
+    pub req_id: u64,
+    pub account_id: u32,
+    pub amount: i64,
+    pub transaction_index: u32,
+    pub created_at: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+impl<'a> ::std::default::Default for &'a TransactionRecord {
+    fn default() -> &'a TransactionRecord {
+        <TransactionRecord as ::protobuf::Message>::default_instance()
+    }
 }
 
-pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
+impl TransactionRecord {
+    pub fn new() -> TransactionRecord {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 id = 1;
+
+
+    pub fn get_id(&self) -> u64 {
+        self.id
     }
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    // fixed64 req_id = 2;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed32 account_id = 3;
+
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u32) {
+        self.account_id = v;
+    }
+
+    // sfixed64 amount = 4;
+
+
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+    pub fn clear_amount(&mut self) {
+        self.amount = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_amount(&mut self, v: i64) {
+        self.amount = v;
+    }
+
+    // fixed32 transaction_index = 5;
+
+
+    pub fn get_transaction_index(&self) -> u32 {
+        self.transaction_index
+    }
+    pub fn clear_transaction_index(&mut self) {
+        self.transaction_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transaction_index(&mut self, v: u32) {
+        self.transaction_index = v;
+    }
+
+    // sfixed64 created_at = 6;
+
+
+    pub fn get_created_at(&self) -> i64 {
+        self.created_at
+    }
+    pub fn clear_created_at(&mut self) {
+        self.created_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: i64) {
+        self.created_at = v;
+    }
+}
+
+impl ::protobuf::Message for TransactionRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.account_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.amount = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.transaction_index = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.created_at = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += 9;
+        }
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.account_id != 0 {
+            my_size += 5;
+        }
+        if self.amount != 0 {
+            my_size += 9;
+        }
+        if self.transaction_index != 0 {
+            my_size += 5;
+        }
+        if self.created_at != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_fixed64(1, self.id)?;
+        }
+        if self.req_id != 0 {
+            os.write_fixed64(2, self.req_id)?;
+        }
+        if self.account_id != 0 {
+            os.write_fixed32(3, self.account_id)?;
+        }
+        if self.amount != 0 {
+            os.write_sfixed64(4, self.amount)?;
+        }
+        if self.transaction_index != 0 {
+            os.write_fixed32(5, self.transaction_index)?;
+        }
+        if self.created_at != 0 {
+            os.write_sfixed64(6, self.created_at)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TransactionRecord {
+        TransactionRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "id",
+                |m: &TransactionRecord| { &m.id },
+                |m: &mut TransactionRecord| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &TransactionRecord| { &m.req_id },
+                |m: &mut TransactionRecord| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &TransactionRecord| { &m.account_id },
+                |m: &mut TransactionRecord| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "amount",
+                |m: &TransactionRecord| { &m.amount },
+                |m: &mut TransactionRecord| { &mut m.amount },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "transaction_index",
+                |m: &TransactionRecord| { &m.transaction_index },
+                |m: &mut TransactionRecord| { &mut m.transaction_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "created_at",
+                |m: &TransactionRecord| { &m.created_at },
+                |m: &mut TransactionRecord| { &mut m.created_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TransactionRecord>(
+                "TransactionRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TransactionRecord {
+        static instance: ::protobuf::rt::LazyV2<TransactionRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TransactionRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for TransactionRecord {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.req_id = 0;
+        self.account_id = 0;
+        self.amount = 0;
+        self.transaction_index = 0;
+        self.created_at = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TransactionRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TransactionRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListTransactionsRequest {
+    // message fields
+    pub req_id: u64,
+    pub account_ids: ::std::vec::Vec<u32>,
+    pub transfer_req_ids: ::std::vec::Vec<u64>,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub page_size: u32,
+    pub page_token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListTransactionsRequest {
+    fn default() -> &'a ListTransactionsRequest {
+        <ListTransactionsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListTransactionsRequest {
+    pub fn new() -> ListTransactionsRequest {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // repeated fixed32 account_ids = 2;
+
+
+    pub fn get_account_ids(&self) -> &[u32] {
+        &self.account_ids
+    }
+    pub fn clear_account_ids(&mut self) {
+        self.account_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_ids(&mut self, v: ::std::vec::Vec<u32>) {
+        self.account_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_account_ids(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.account_ids
+    }
+
+    // Take field
+    pub fn take_account_ids(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.account_ids, ::std::vec::Vec::new())
+    }
+
+    // repeated fixed64 transfer_req_ids = 3;
+
+
+    pub fn get_transfer_req_ids(&self) -> &[u64] {
+        &self.transfer_req_ids
+    }
+    pub fn clear_transfer_req_ids(&mut self) {
+        self.transfer_req_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transfer_req_ids(&mut self, v: ::std::vec::Vec<u64>) {
+        self.transfer_req_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_transfer_req_ids(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.transfer_req_ids
+    }
+
+    // Take field
+    pub fn take_transfer_req_ids(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.transfer_req_ids, ::std::vec::Vec::new())
+    }
+
+    // sfixed64 start_time = 4;
+
+
+    pub fn get_start_time(&self) -> i64 {
+        self.start_time
+    }
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: i64) {
+        self.start_time = v;
+    }
+
+    // sfixed64 end_time = 5;
+
+
+    pub fn get_end_time(&self) -> i64 {
+        self.end_time
+    }
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: i64) {
+        self.end_time = v;
+    }
+
+    // fixed32 page_size = 6;
+
+
+    pub fn get_page_size(&self) -> u32 {
+        self.page_size
+    }
+    pub fn clear_page_size(&mut self) {
+        self.page_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_size(&mut self, v: u32) {
+        self.page_size = v;
+    }
+
+    // string page_token = 7;
+
+
+    pub fn get_page_token(&self) -> &str {
+        &self.page_token
+    }
+    pub fn clear_page_token(&mut self) {
+        self.page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_token(&mut self, v: ::std::string::String) {
+        self.page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.page_token
+    }
+
+    // Take field
+    pub fn take_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ListTransactionsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_fixed32_into(wire_type, is, &mut self.account_ids)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_fixed64_into(wire_type, is, &mut self.transfer_req_ids)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.start_time = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.end_time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.page_size = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        my_size += 5 * self.account_ids.len() as u32;
+        my_size += 9 * self.transfer_req_ids.len() as u32;
+        if self.start_time != 0 {
+            my_size += 9;
+        }
+        if self.end_time != 0 {
+            my_size += 9;
+        }
+        if self.page_size != 0 {
+            my_size += 5;
+        }
+        if !self.page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        for v in &self.account_ids {
+            os.write_fixed32(2, *v)?;
+        };
+        for v in &self.transfer_req_ids {
+            os.write_fixed64(3, *v)?;
+        };
+        if self.start_time != 0 {
+            os.write_sfixed64(4, self.start_time)?;
+        }
+        if self.end_time != 0 {
+            os.write_sfixed64(5, self.end_time)?;
+        }
+        if self.page_size != 0 {
+            os.write_fixed32(6, self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            os.write_string(7, &self.page_token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListTransactionsRequest {
+        ListTransactionsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &ListTransactionsRequest| { &m.req_id },
+                |m: &mut ListTransactionsRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_ids",
+                |m: &ListTransactionsRequest| { &m.account_ids },
+                |m: &mut ListTransactionsRequest| { &mut m.account_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "transfer_req_ids",
+                |m: &ListTransactionsRequest| { &m.transfer_req_ids },
+                |m: &mut ListTransactionsRequest| { &mut m.transfer_req_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "start_time",
+                |m: &ListTransactionsRequest| { &m.start_time },
+                |m: &mut ListTransactionsRequest| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "end_time",
+                |m: &ListTransactionsRequest| { &m.end_time },
+                |m: &mut ListTransactionsRequest| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "page_size",
+                |m: &ListTransactionsRequest| { &m.page_size },
+                |m: &mut ListTransactionsRequest| { &mut m.page_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_token",
+                |m: &ListTransactionsRequest| { &m.page_token },
+                |m: &mut ListTransactionsRequest| { &mut m.page_token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ListTransactionsRequest>(
+                "ListTransactionsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ListTransactionsRequest {
+        static instance: ::protobuf::rt::LazyV2<ListTransactionsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ListTransactionsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ListTransactionsRequest {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.account_ids.clear();
+        self.transfer_req_ids.clear();
+        self.start_time = 0;
+        self.end_time = 0;
+        self.page_size = 0;
+        self.page_token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListTransactionsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListTransactionsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListTransactionsResponse {
+    // message fields
+    pub req_id: u64,
+    pub transactions: ::protobuf::RepeatedField<TransactionRecord>,
+    pub next_page_token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListTransactionsResponse {
+    fn default() -> &'a ListTransactionsResponse {
+        <ListTransactionsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListTransactionsResponse {
+    pub fn new() -> ListTransactionsResponse {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // repeated .accounting.TransactionRecord transactions = 2;
+
+
+    pub fn get_transactions(&self) -> &[TransactionRecord] {
+        &self.transactions
+    }
+    pub fn clear_transactions(&mut self) {
+        self.transactions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transactions(&mut self, v: ::protobuf::RepeatedField<TransactionRecord>) {
+        self.transactions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_transactions(&mut self) -> &mut ::protobuf::RepeatedField<TransactionRecord> {
+        &mut self.transactions
+    }
+
+    // Take field
+    pub fn take_transactions(&mut self) -> ::protobuf::RepeatedField<TransactionRecord> {
+        ::std::mem::replace(&mut self.transactions, ::protobuf::RepeatedField::new())
+    }
+
+    // string next_page_token = 3;
+
+
+    pub fn get_next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    pub fn clear_next_page_token(&mut self) {
+        self.next_page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_page_token(&mut self, v: ::std::string::String) {
+        self.next_page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.next_page_token
+    }
+
+    // Take field
+    pub fn take_next_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.next_page_token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ListTransactionsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.transactions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.transactions)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.next_page_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        for value in &self.transactions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.next_page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        for v in &self.transactions {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.next_page_token.is_empty() {
+            os.write_string(3, &self.next_page_token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListTransactionsResponse {
+        ListTransactionsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &ListTransactionsResponse| { &m.req_id },
+                |m: &mut ListTransactionsResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionRecord>>(
+                "transactions",
+                |m: &ListTransactionsResponse| { &m.transactions },
+                |m: &mut ListTransactionsResponse| { &mut m.transactions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "next_page_token",
+                |m: &ListTransactionsResponse| { &m.next_page_token },
+                |m: &mut ListTransactionsResponse| { &mut m.next_page_token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ListTransactionsResponse>(
+                "ListTransactionsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ListTransactionsResponse {
+        static instance: ::protobuf::rt::LazyV2<ListTransactionsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ListTransactionsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ListTransactionsResponse {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.transactions.clear();
+        self.next_page_token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListTransactionsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListTransactionsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WatchBalanceRequest {
+    // message fields
+    pub req_id: u64,
+    pub account_ids: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WatchBalanceRequest {
+    fn default() -> &'a WatchBalanceRequest {
+        <WatchBalanceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WatchBalanceRequest {
+    pub fn new() -> WatchBalanceRequest {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // repeated fixed32 account_ids = 2;
+
+
+    pub fn get_account_ids(&self) -> &[u32] {
+        &self.account_ids
+    }
+    pub fn clear_account_ids(&mut self) {
+        self.account_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_ids(&mut self, v: ::std::vec::Vec<u32>) {
+        self.account_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_account_ids(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.account_ids
+    }
+
+    // Take field
+    pub fn take_account_ids(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.account_ids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for WatchBalanceRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_fixed32_into(wire_type, is, &mut self.account_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        my_size += 5 * self.account_ids.len() as u32;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        for v in &self.account_ids {
+            os.write_fixed32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WatchBalanceRequest {
+        WatchBalanceRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &WatchBalanceRequest| { &m.req_id },
+                |m: &mut WatchBalanceRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_ids",
+                |m: &WatchBalanceRequest| { &m.account_ids },
+                |m: &mut WatchBalanceRequest| { &mut m.account_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WatchBalanceRequest>(
+                "WatchBalanceRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WatchBalanceRequest {
+        static instance: ::protobuf::rt::LazyV2<WatchBalanceRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WatchBalanceRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for WatchBalanceRequest {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.account_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WatchBalanceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WatchBalanceRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AuthorizeTransferRequest {
+    // message fields
+    pub req_id: u64,
+    pub from_account_id: u32,
+    pub to_account_id: u32,
+    pub amount: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AuthorizeTransferRequest {
+    fn default() -> &'a AuthorizeTransferRequest {
+        <AuthorizeTransferRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuthorizeTransferRequest {
+    pub fn new() -> AuthorizeTransferRequest {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed32 from_account_id = 2;
+
+
+    pub fn get_from_account_id(&self) -> u32 {
+        self.from_account_id
+    }
+    pub fn clear_from_account_id(&mut self) {
+        self.from_account_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_account_id(&mut self, v: u32) {
+        self.from_account_id = v;
+    }
+
+    // fixed32 to_account_id = 3;
+
+
+    pub fn get_to_account_id(&self) -> u32 {
+        self.to_account_id
+    }
+    pub fn clear_to_account_id(&mut self) {
+        self.to_account_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_account_id(&mut self, v: u32) {
+        self.to_account_id = v;
+    }
+
+    // sfixed64 amount = 4;
+
+
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+    pub fn clear_amount(&mut self) {
+        self.amount = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_amount(&mut self, v: i64) {
+        self.amount = v;
+    }
+}
+
+impl ::protobuf::Message for AuthorizeTransferRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.from_account_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.to_account_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.amount = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.from_account_id != 0 {
+            my_size += 5;
+        }
+        if self.to_account_id != 0 {
+            my_size += 5;
+        }
+        if self.amount != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.from_account_id != 0 {
+            os.write_fixed32(2, self.from_account_id)?;
+        }
+        if self.to_account_id != 0 {
+            os.write_fixed32(3, self.to_account_id)?;
+        }
+        if self.amount != 0 {
+            os.write_sfixed64(4, self.amount)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AuthorizeTransferRequest {
+        AuthorizeTransferRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &AuthorizeTransferRequest| { &m.req_id },
+                |m: &mut AuthorizeTransferRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "from_account_id",
+                |m: &AuthorizeTransferRequest| { &m.from_account_id },
+                |m: &mut AuthorizeTransferRequest| { &mut m.from_account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "to_account_id",
+                |m: &AuthorizeTransferRequest| { &m.to_account_id },
+                |m: &mut AuthorizeTransferRequest| { &mut m.to_account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "amount",
+                |m: &AuthorizeTransferRequest| { &m.amount },
+                |m: &mut AuthorizeTransferRequest| { &mut m.amount },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AuthorizeTransferRequest>(
+                "AuthorizeTransferRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AuthorizeTransferRequest {
+        static instance: ::protobuf::rt::LazyV2<AuthorizeTransferRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AuthorizeTransferRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for AuthorizeTransferRequest {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.from_account_id = 0;
+        self.to_account_id = 0;
+        self.amount = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AuthorizeTransferRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuthorizeTransferRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AuthorizeTransferResponse {
+    // message fields
+    pub req_id: u64,
+    pub hold_id: u64,
+    pub expires_at: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AuthorizeTransferResponse {
+    fn default() -> &'a AuthorizeTransferResponse {
+        <AuthorizeTransferResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuthorizeTransferResponse {
+    pub fn new() -> AuthorizeTransferResponse {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 hold_id = 2;
+
+
+    pub fn get_hold_id(&self) -> u64 {
+        self.hold_id
+    }
+    pub fn clear_hold_id(&mut self) {
+        self.hold_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hold_id(&mut self, v: u64) {
+        self.hold_id = v;
+    }
+
+    // sfixed64 expires_at = 3;
+
+
+    pub fn get_expires_at(&self) -> i64 {
+        self.expires_at
+    }
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: i64) {
+        self.expires_at = v;
+    }
+}
+
+impl ::protobuf::Message for AuthorizeTransferResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.hold_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.expires_at = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.hold_id != 0 {
+            my_size += 9;
+        }
+        if self.expires_at != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.hold_id != 0 {
+            os.write_fixed64(2, self.hold_id)?;
+        }
+        if self.expires_at != 0 {
+            os.write_sfixed64(3, self.expires_at)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AuthorizeTransferResponse {
+        AuthorizeTransferResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &AuthorizeTransferResponse| { &m.req_id },
+                |m: &mut AuthorizeTransferResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "hold_id",
+                |m: &AuthorizeTransferResponse| { &m.hold_id },
+                |m: &mut AuthorizeTransferResponse| { &mut m.hold_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "expires_at",
+                |m: &AuthorizeTransferResponse| { &m.expires_at },
+                |m: &mut AuthorizeTransferResponse| { &mut m.expires_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AuthorizeTransferResponse>(
+                "AuthorizeTransferResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AuthorizeTransferResponse {
+        static instance: ::protobuf::rt::LazyV2<AuthorizeTransferResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AuthorizeTransferResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for AuthorizeTransferResponse {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.hold_id = 0;
+        self.expires_at = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AuthorizeTransferResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuthorizeTransferResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CaptureTransferRequest {
+    // message fields
+    pub req_id: u64,
+    pub hold_id: u64,
+    pub amount: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CaptureTransferRequest {
+    fn default() -> &'a CaptureTransferRequest {
+        <CaptureTransferRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CaptureTransferRequest {
+    pub fn new() -> CaptureTransferRequest {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 hold_id = 2;
+
+
+    pub fn get_hold_id(&self) -> u64 {
+        self.hold_id
+    }
+    pub fn clear_hold_id(&mut self) {
+        self.hold_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hold_id(&mut self, v: u64) {
+        self.hold_id = v;
+    }
+
+    // sfixed64 amount = 3;
+
+
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+    pub fn clear_amount(&mut self) {
+        self.amount = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_amount(&mut self, v: i64) {
+        self.amount = v;
+    }
+}
+
+impl ::protobuf::Message for CaptureTransferRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.hold_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.amount = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.hold_id != 0 {
+            my_size += 9;
+        }
+        if self.amount != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.hold_id != 0 {
+            os.write_fixed64(2, self.hold_id)?;
+        }
+        if self.amount != 0 {
+            os.write_sfixed64(3, self.amount)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CaptureTransferRequest {
+        CaptureTransferRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &CaptureTransferRequest| { &m.req_id },
+                |m: &mut CaptureTransferRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "hold_id",
+                |m: &CaptureTransferRequest| { &m.hold_id },
+                |m: &mut CaptureTransferRequest| { &mut m.hold_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "amount",
+                |m: &CaptureTransferRequest| { &m.amount },
+                |m: &mut CaptureTransferRequest| { &mut m.amount },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CaptureTransferRequest>(
+                "CaptureTransferRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CaptureTransferRequest {
+        static instance: ::protobuf::rt::LazyV2<CaptureTransferRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CaptureTransferRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CaptureTransferRequest {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.hold_id = 0;
+        self.amount = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CaptureTransferRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CaptureTransferRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CaptureTransferResponse {
+    // message fields
+    pub req_id: u64,
+    pub hold_id: u64,
+    pub captured_amount: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CaptureTransferResponse {
+    fn default() -> &'a CaptureTransferResponse {
+        <CaptureTransferResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CaptureTransferResponse {
+    pub fn new() -> CaptureTransferResponse {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 hold_id = 2;
+
+
+    pub fn get_hold_id(&self) -> u64 {
+        self.hold_id
+    }
+    pub fn clear_hold_id(&mut self) {
+        self.hold_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hold_id(&mut self, v: u64) {
+        self.hold_id = v;
+    }
+
+    // sfixed64 captured_amount = 3;
+
+
+    pub fn get_captured_amount(&self) -> i64 {
+        self.captured_amount
+    }
+    pub fn clear_captured_amount(&mut self) {
+        self.captured_amount = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_captured_amount(&mut self, v: i64) {
+        self.captured_amount = v;
+    }
+}
+
+impl ::protobuf::Message for CaptureTransferResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.hold_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.captured_amount = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.hold_id != 0 {
+            my_size += 9;
+        }
+        if self.captured_amount != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.hold_id != 0 {
+            os.write_fixed64(2, self.hold_id)?;
+        }
+        if self.captured_amount != 0 {
+            os.write_sfixed64(3, self.captured_amount)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CaptureTransferResponse {
+        CaptureTransferResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &CaptureTransferResponse| { &m.req_id },
+                |m: &mut CaptureTransferResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "hold_id",
+                |m: &CaptureTransferResponse| { &m.hold_id },
+                |m: &mut CaptureTransferResponse| { &mut m.hold_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "captured_amount",
+                |m: &CaptureTransferResponse| { &m.captured_amount },
+                |m: &mut CaptureTransferResponse| { &mut m.captured_amount },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CaptureTransferResponse>(
+                "CaptureTransferResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CaptureTransferResponse {
+        static instance: ::protobuf::rt::LazyV2<CaptureTransferResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CaptureTransferResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for CaptureTransferResponse {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.hold_id = 0;
+        self.captured_amount = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CaptureTransferResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CaptureTransferResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoidTransferRequest {
+    // message fields
+    pub req_id: u64,
+    pub hold_id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoidTransferRequest {
+    fn default() -> &'a VoidTransferRequest {
+        <VoidTransferRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoidTransferRequest {
+    pub fn new() -> VoidTransferRequest {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 hold_id = 2;
+
+
+    pub fn get_hold_id(&self) -> u64 {
+        self.hold_id
+    }
+    pub fn clear_hold_id(&mut self) {
+        self.hold_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hold_id(&mut self, v: u64) {
+        self.hold_id = v;
+    }
+}
+
+impl ::protobuf::Message for VoidTransferRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.hold_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.hold_id != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.hold_id != 0 {
+            os.write_fixed64(2, self.hold_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoidTransferRequest {
+        VoidTransferRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &VoidTransferRequest| { &m.req_id },
+                |m: &mut VoidTransferRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "hold_id",
+                |m: &VoidTransferRequest| { &m.hold_id },
+                |m: &mut VoidTransferRequest| { &mut m.hold_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoidTransferRequest>(
+                "VoidTransferRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoidTransferRequest {
+        static instance: ::protobuf::rt::LazyV2<VoidTransferRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoidTransferRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for VoidTransferRequest {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.hold_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoidTransferRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoidTransferRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoidTransferResponse {
+    // message fields
+    pub req_id: u64,
+    pub hold_id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoidTransferResponse {
+    fn default() -> &'a VoidTransferResponse {
+        <VoidTransferResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoidTransferResponse {
+    pub fn new() -> VoidTransferResponse {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 hold_id = 2;
+
+
+    pub fn get_hold_id(&self) -> u64 {
+        self.hold_id
+    }
+    pub fn clear_hold_id(&mut self) {
+        self.hold_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hold_id(&mut self, v: u64) {
+        self.hold_id = v;
+    }
+}
+
+impl ::protobuf::Message for VoidTransferResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.hold_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.hold_id != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.hold_id != 0 {
+            os.write_fixed64(2, self.hold_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoidTransferResponse {
+        VoidTransferResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &VoidTransferResponse| { &m.req_id },
+                |m: &mut VoidTransferResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "hold_id",
+                |m: &VoidTransferResponse| { &m.hold_id },
+                |m: &mut VoidTransferResponse| { &mut m.hold_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoidTransferResponse>(
+                "VoidTransferResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoidTransferResponse {
+        static instance: ::protobuf::rt::LazyV2<VoidTransferResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoidTransferResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for VoidTransferResponse {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.hold_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoidTransferResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoidTransferResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReverseTransferRequest {
+    // message fields
+    pub req_id: u64,
+    pub original_req_id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReverseTransferRequest {
+    fn default() -> &'a ReverseTransferRequest {
+        <ReverseTransferRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReverseTransferRequest {
+    pub fn new() -> ReverseTransferRequest {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 original_req_id = 2;
+
+
+    pub fn get_original_req_id(&self) -> u64 {
+        self.original_req_id
+    }
+    pub fn clear_original_req_id(&mut self) {
+        self.original_req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_original_req_id(&mut self, v: u64) {
+        self.original_req_id = v;
+    }
+}
+
+impl ::protobuf::Message for ReverseTransferRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.original_req_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.original_req_id != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.original_req_id != 0 {
+            os.write_fixed64(2, self.original_req_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReverseTransferRequest {
+        ReverseTransferRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &ReverseTransferRequest| { &m.req_id },
+                |m: &mut ReverseTransferRequest| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "original_req_id",
+                |m: &ReverseTransferRequest| { &m.original_req_id },
+                |m: &mut ReverseTransferRequest| { &mut m.original_req_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReverseTransferRequest>(
+                "ReverseTransferRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReverseTransferRequest {
+        static instance: ::protobuf::rt::LazyV2<ReverseTransferRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReverseTransferRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReverseTransferRequest {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.original_req_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReverseTransferRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReverseTransferRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReverseTransferResponse {
+    // message fields
+    pub req_id: u64,
+    pub original_req_id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReverseTransferResponse {
+    fn default() -> &'a ReverseTransferResponse {
+        <ReverseTransferResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReverseTransferResponse {
+    pub fn new() -> ReverseTransferResponse {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 req_id = 1;
+
+
+    pub fn get_req_id(&self) -> u64 {
+        self.req_id
+    }
+    pub fn clear_req_id(&mut self) {
+        self.req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_req_id(&mut self, v: u64) {
+        self.req_id = v;
+    }
+
+    // fixed64 original_req_id = 2;
+
+
+    pub fn get_original_req_id(&self) -> u64 {
+        self.original_req_id
+    }
+    pub fn clear_original_req_id(&mut self) {
+        self.original_req_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_original_req_id(&mut self, v: u64) {
+        self.original_req_id = v;
+    }
+}
+
+impl ::protobuf::Message for ReverseTransferResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.req_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.original_req_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.req_id != 0 {
+            my_size += 9;
+        }
+        if self.original_req_id != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.req_id != 0 {
+            os.write_fixed64(1, self.req_id)?;
+        }
+        if self.original_req_id != 0 {
+            os.write_fixed64(2, self.original_req_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReverseTransferResponse {
+        ReverseTransferResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "req_id",
+                |m: &ReverseTransferResponse| { &m.req_id },
+                |m: &mut ReverseTransferResponse| { &mut m.req_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "original_req_id",
+                |m: &ReverseTransferResponse| { &m.original_req_id },
+                |m: &mut ReverseTransferResponse| { &mut m.original_req_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReverseTransferResponse>(
+                "ReverseTransferResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReverseTransferResponse {
+        static instance: ::protobuf::rt::LazyV2<ReverseTransferResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReverseTransferResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ReverseTransferResponse {
+    fn clear(&mut self) {
+        self.req_id = 0;
+        self.original_req_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReverseTransferResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReverseTransferResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VerifyLedgerRequest {
+    // message fields
+    pub rebuild: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VerifyLedgerRequest {
+    fn default() -> &'a VerifyLedgerRequest {
+        <VerifyLedgerRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VerifyLedgerRequest {
+    pub fn new() -> VerifyLedgerRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool rebuild = 1;
+
+
+    pub fn get_rebuild(&self) -> bool {
+        self.rebuild
+    }
+    pub fn clear_rebuild(&mut self) {
+        self.rebuild = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rebuild(&mut self, v: bool) {
+        self.rebuild = v;
+    }
+}
+
+impl ::protobuf::Message for VerifyLedgerRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rebuild = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rebuild != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.rebuild != false {
+            os.write_bool(1, self.rebuild)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VerifyLedgerRequest {
+        VerifyLedgerRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "rebuild",
+                |m: &VerifyLedgerRequest| { &m.rebuild },
+                |m: &mut VerifyLedgerRequest| { &mut m.rebuild },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VerifyLedgerRequest>(
+                "VerifyLedgerRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VerifyLedgerRequest {
+        static instance: ::protobuf::rt::LazyV2<VerifyLedgerRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VerifyLedgerRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for VerifyLedgerRequest {
+    fn clear(&mut self) {
+        self.rebuild = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VerifyLedgerRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VerifyLedgerRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LedgerMismatch {
+    // message fields
+    pub account_id: u32,
+    pub stored_balance: i64,
+    pub ledger_balance: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LedgerMismatch {
+    fn default() -> &'a LedgerMismatch {
+        <LedgerMismatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LedgerMismatch {
+    pub fn new() -> LedgerMismatch {
+        ::std::default::Default::default()
+    }
+
+    // fixed32 account_id = 1;
+
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u32) {
+        self.account_id = v;
+    }
+
+    // sfixed64 stored_balance = 2;
+
+
+    pub fn get_stored_balance(&self) -> i64 {
+        self.stored_balance
+    }
+    pub fn clear_stored_balance(&mut self) {
+        self.stored_balance = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stored_balance(&mut self, v: i64) {
+        self.stored_balance = v;
+    }
+
+    // sfixed64 ledger_balance = 3;
+
+
+    pub fn get_ledger_balance(&self) -> i64 {
+        self.ledger_balance
+    }
+    pub fn clear_ledger_balance(&mut self) {
+        self.ledger_balance = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ledger_balance(&mut self, v: i64) {
+        self.ledger_balance = v;
+    }
+}
+
+impl ::protobuf::Message for LedgerMismatch {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.account_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.stored_balance = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.ledger_balance = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.account_id != 0 {
+            my_size += 5;
+        }
+        if self.stored_balance != 0 {
+            my_size += 9;
+        }
+        if self.ledger_balance != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.account_id != 0 {
+            os.write_fixed32(1, self.account_id)?;
+        }
+        if self.stored_balance != 0 {
+            os.write_sfixed64(2, self.stored_balance)?;
+        }
+        if self.ledger_balance != 0 {
+            os.write_sfixed64(3, self.ledger_balance)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LedgerMismatch {
+        LedgerMismatch::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &LedgerMismatch| { &m.account_id },
+                |m: &mut LedgerMismatch| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "stored_balance",
+                |m: &LedgerMismatch| { &m.stored_balance },
+                |m: &mut LedgerMismatch| { &mut m.stored_balance },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "ledger_balance",
+                |m: &LedgerMismatch| { &m.ledger_balance },
+                |m: &mut LedgerMismatch| { &mut m.ledger_balance },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LedgerMismatch>(
+                "LedgerMismatch",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LedgerMismatch {
+        static instance: ::protobuf::rt::LazyV2<LedgerMismatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LedgerMismatch::new)
+    }
+}
+
+impl ::protobuf::Clear for LedgerMismatch {
+    fn clear(&mut self) {
+        self.account_id = 0;
+        self.stored_balance = 0;
+        self.ledger_balance = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LedgerMismatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LedgerMismatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LedgerBackfill {
+    // message fields
+    pub account_id: u32,
+    pub amount: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LedgerBackfill {
+    fn default() -> &'a LedgerBackfill {
+        <LedgerBackfill as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LedgerBackfill {
+    pub fn new() -> LedgerBackfill {
+        ::std::default::Default::default()
+    }
+
+    // fixed32 account_id = 1;
+
+
+    pub fn get_account_id(&self) -> u32 {
+        self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u32) {
+        self.account_id = v;
+    }
+
+    // sfixed64 amount = 2;
+
+
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+    pub fn clear_amount(&mut self) {
+        self.amount = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_amount(&mut self, v: i64) {
+        self.amount = v;
+    }
+}
+
+impl ::protobuf::Message for LedgerBackfill {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed32()?;
+                    self.account_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sfixed64()?;
+                    self.amount = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.account_id != 0 {
+            my_size += 5;
+        }
+        if self.amount != 0 {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.account_id != 0 {
+            os.write_fixed32(1, self.account_id)?;
+        }
+        if self.amount != 0 {
+            os.write_sfixed64(2, self.amount)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LedgerBackfill {
+        LedgerBackfill::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed32>(
+                "account_id",
+                |m: &LedgerBackfill| { &m.account_id },
+                |m: &mut LedgerBackfill| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeSfixed64>(
+                "amount",
+                |m: &LedgerBackfill| { &m.amount },
+                |m: &mut LedgerBackfill| { &mut m.amount },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LedgerBackfill>(
+                "LedgerBackfill",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LedgerBackfill {
+        static instance: ::protobuf::rt::LazyV2<LedgerBackfill> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LedgerBackfill::new)
+    }
+}
+
+impl ::protobuf::Clear for LedgerBackfill {
+    fn clear(&mut self) {
+        self.account_id = 0;
+        self.amount = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LedgerBackfill {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LedgerBackfill {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VerifyLedgerResponse {
+    // message fields
+    pub accounts_checked: u64,
+    pub mismatches: ::protobuf::RepeatedField<LedgerMismatch>,
+    pub rebuilt: bool,
+    pub backfilled: ::protobuf::RepeatedField<LedgerBackfill>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VerifyLedgerResponse {
+    fn default() -> &'a VerifyLedgerResponse {
+        <VerifyLedgerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VerifyLedgerResponse {
+    pub fn new() -> VerifyLedgerResponse {
+        ::std::default::Default::default()
+    }
+
+    // fixed64 accounts_checked = 1;
+
+
+    pub fn get_accounts_checked(&self) -> u64 {
+        self.accounts_checked
+    }
+    pub fn clear_accounts_checked(&mut self) {
+        self.accounts_checked = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accounts_checked(&mut self, v: u64) {
+        self.accounts_checked = v;
+    }
+
+    // repeated .accounting.LedgerMismatch mismatches = 2;
+
+
+    pub fn get_mismatches(&self) -> &[LedgerMismatch] {
+        &self.mismatches
+    }
+    pub fn clear_mismatches(&mut self) {
+        self.mismatches.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mismatches(&mut self, v: ::protobuf::RepeatedField<LedgerMismatch>) {
+        self.mismatches = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mismatches(&mut self) -> &mut ::protobuf::RepeatedField<LedgerMismatch> {
+        &mut self.mismatches
+    }
+
+    // Take field
+    pub fn take_mismatches(&mut self) -> ::protobuf::RepeatedField<LedgerMismatch> {
+        ::std::mem::replace(&mut self.mismatches, ::protobuf::RepeatedField::new())
+    }
+
+    // bool rebuilt = 3;
+
+
+    pub fn get_rebuilt(&self) -> bool {
+        self.rebuilt
+    }
+    pub fn clear_rebuilt(&mut self) {
+        self.rebuilt = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rebuilt(&mut self, v: bool) {
+        self.rebuilt = v;
+    }
+
+    // repeated .accounting.LedgerBackfill backfilled = 4;
+
+
+    pub fn get_backfilled(&self) -> &[LedgerBackfill] {
+        &self.backfilled
+    }
+    pub fn clear_backfilled(&mut self) {
+        self.backfilled.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backfilled(&mut self, v: ::protobuf::RepeatedField<LedgerBackfill>) {
+        self.backfilled = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_backfilled(&mut self) -> &mut ::protobuf::RepeatedField<LedgerBackfill> {
+        &mut self.backfilled
+    }
+
+    // Take field
+    pub fn take_backfilled(&mut self) -> ::protobuf::RepeatedField<LedgerBackfill> {
+        ::std::mem::replace(&mut self.backfilled, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VerifyLedgerResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mismatches {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.backfilled {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_fixed64()?;
+                    self.accounts_checked = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.mismatches)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rebuilt = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.backfilled)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.accounts_checked != 0 {
+            my_size += 9;
+        }
+        for value in &self.mismatches {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.rebuilt != false {
+            my_size += 2;
+        }
+        for value in &self.backfilled {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.accounts_checked != 0 {
+            os.write_fixed64(1, self.accounts_checked)?;
+        }
+        for v in &self.mismatches {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.rebuilt != false {
+            os.write_bool(3, self.rebuilt)?;
+        }
+        for v in &self.backfilled {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VerifyLedgerResponse {
+        VerifyLedgerResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFixed64>(
+                "accounts_checked",
+                |m: &VerifyLedgerResponse| { &m.accounts_checked },
+                |m: &mut VerifyLedgerResponse| { &mut m.accounts_checked },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LedgerMismatch>>(
+                "mismatches",
+                |m: &VerifyLedgerResponse| { &m.mismatches },
+                |m: &mut VerifyLedgerResponse| { &mut m.mismatches },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "rebuilt",
+                |m: &VerifyLedgerResponse| { &m.rebuilt },
+                |m: &mut VerifyLedgerResponse| { &mut m.rebuilt },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LedgerBackfill>>(
+                "backfilled",
+                |m: &VerifyLedgerResponse| { &m.backfilled },
+                |m: &mut VerifyLedgerResponse| { &mut m.backfilled },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VerifyLedgerResponse>(
+                "VerifyLedgerResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VerifyLedgerResponse {
+        static instance: ::protobuf::rt::LazyV2<VerifyLedgerResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VerifyLedgerResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for VerifyLedgerResponse {
+    fn clear(&mut self) {
+        self.accounts_checked = 0;
+        self.mismatches.clear();
+        self.rebuilt = false;
+        self.backfilled.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VerifyLedgerResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VerifyLedgerResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10accounting.proto\x12\naccounting\"\x0e\n\x0cResetRequest\"\x0f\n\r\
+    ResetResponse\"f\n\x14CreateAccountRequest\x12\x15\n\x06req_id\x18\x01\
+    \x20\x01(\x06R\x05reqId\x12\x1d\n\naccount_id\x18\x02\x20\x01(\x07R\tacc\
+    ountId\x12\x18\n\x07balance\x18\x03\x20\x01(\x10R\x07balance\"M\n\x15Cre\
+    ateAccountResponse\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\
+    \x12\x1d\n\naccount_id\x18\x02\x20\x01(\x07R\taccountId\"I\n\x11GetBalan\
+    ceRequest\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12\x1d\n\n\
+    account_id\x18\x02\x20\x01(\x07R\taccountId\"\x91\x01\n\x12GetBalanceRes\
+    ponse\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12\x1d\n\nacco\
+    unt_id\x18\x02\x20\x01(\x07R\taccountId\x12\x18\n\x07balance\x18\x03\x20\
+    \x01(\x10R\x07balance\x12+\n\x11available_balance\x18\x04\x20\x01(\x10R\
+    \x10availableBalance\"S\n\x11TransferComponent\x12\x1d\n\naccount_id\x18\
+    \x01\x20\x01(\x07R\taccountId\x12\x1f\n\x0bmoney_delta\x18\x02\x20\x01(\
+    \x10R\nmoneyDelta\"g\n\x0fTransferRequest\x12\x15\n\x06req_id\x18\x01\
+    \x20\x01(\x06R\x05reqId\x12=\n\ncomponents\x18\x02\x20\x03(\x0b2\x1d.acc\
+    ounting.TransferComponentR\ncomponents\")\n\x10TransferResponse\x12\x15\
+    \n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\"\xbd\x01\n\x11TransactionRe\
+    cord\x12\x0e\n\x02id\x18\x01\x20\x01(\x06R\x02id\x12\x15\n\x06req_id\x18\
+    \x02\x20\x01(\x06R\x05reqId\x12\x1d\n\naccount_id\x18\x03\x20\x01(\x07R\
+    \taccountId\x12\x16\n\x06amount\x18\x04\x20\x01(\x10R\x06amount\x12+\n\
+    \x11transaction_index\x18\x05\x20\x01(\x07R\x10transactionIndex\x12\x1d\
+    \n\ncreated_at\x18\x06\x20\x01(\x10R\tcreatedAt\"\xf1\x01\n\x17ListTrans\
+    actionsRequest\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12\
+    \x1f\n\x0baccount_ids\x18\x02\x20\x03(\x07R\naccountIds\x12(\n\x10transf\
+    er_req_ids\x18\x03\x20\x03(\x06R\x0etransferReqIds\x12\x1d\n\nstart_time\
+    \x18\x04\x20\x01(\x10R\tstartTime\x12\x19\n\x08end_time\x18\x05\x20\x01(\
+    \x10R\x07endTime\x12\x1b\n\tpage_size\x18\x06\x20\x01(\x07R\x08pageSize\
+    \x12\x1d\n\npage_token\x18\x07\x20\x01(\tR\tpageToken\"\x9c\x01\n\x18Lis\
+    tTransactionsResponse\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\
+    \x12A\n\x0ctransactions\x18\x02\x20\x03(\x0b2\x1d.accounting.Transaction\
+    RecordR\x0ctransactions\x12&\n\x0fnext_page_token\x18\x03\x20\x01(\tR\rn\
+    extPageToken\"M\n\x13WatchBalanceRequest\x12\x15\n\x06req_id\x18\x01\x20\
+    \x01(\x06R\x05reqId\x12\x1f\n\x0baccount_ids\x18\x02\x20\x03(\x07R\nacco\
+    untIds\"\x95\x01\n\x18AuthorizeTransferRequest\x12\x15\n\x06req_id\x18\
+    \x01\x20\x01(\x06R\x05reqId\x12&\n\x0ffrom_account_id\x18\x02\x20\x01(\
+    \x07R\rfromAccountId\x12\"\n\rto_account_id\x18\x03\x20\x01(\x07R\x0btoA\
+    ccountId\x12\x16\n\x06amount\x18\x04\x20\x01(\x10R\x06amount\"j\n\x19Aut\
+    horizeTransferResponse\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqI\
+    d\x12\x17\n\x07hold_id\x18\x02\x20\x01(\x06R\x06holdId\x12\x1d\n\nexpire\
+    s_at\x18\x03\x20\x01(\x10R\texpiresAt\"`\n\x16CaptureTransferRequest\x12\
+    \x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12\x17\n\x07hold_id\x18\
+    \x02\x20\x01(\x06R\x06holdId\x12\x16\n\x06amount\x18\x03\x20\x01(\x10R\
+    \x06amount\"r\n\x17CaptureTransferResponse\x12\x15\n\x06req_id\x18\x01\
+    \x20\x01(\x06R\x05reqId\x12\x17\n\x07hold_id\x18\x02\x20\x01(\x06R\x06ho\
+    ldId\x12'\n\x0fcaptured_amount\x18\x03\x20\x01(\x10R\x0ecapturedAmount\"\
+    E\n\x13VoidTransferRequest\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05\
+    reqId\x12\x17\n\x07hold_id\x18\x02\x20\x01(\x06R\x06holdId\"F\n\x14VoidT\
+    ransferResponse\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12\
+    \x17\n\x07hold_id\x18\x02\x20\x01(\x06R\x06holdId\"W\n\x16ReverseTransfe\
+    rRequest\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12&\n\x0for\
+    iginal_req_id\x18\x02\x20\x01(\x06R\roriginalReqId\"X\n\x17ReverseTransf\
+    erResponse\x12\x15\n\x06req_id\x18\x01\x20\x01(\x06R\x05reqId\x12&\n\x0f\
+    original_req_id\x18\x02\x20\x01(\x06R\roriginalReqId\"/\n\x13VerifyLedge\
+    rRequest\x12\x18\n\x07rebuild\x18\x01\x20\x01(\x08R\x07rebuild\"}\n\x0eL\
+    edgerMismatch\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x07R\taccountId\x12\
+    %\n\x0estored_balance\x18\x02\x20\x01(\x10R\rstoredBalance\x12%\n\x0eled\
+    ger_balance\x18\x03\x20\x01(\x10R\rledgerBalance\"G\n\x0eLedgerBackfill\
+    \x12\x1d\n\naccount_id\x18\x01\x20\x01(\x07R\taccountId\x12\x16\n\x06amo\
+    unt\x18\x02\x20\x01(\x10R\x06amount\"\xd3\x01\n\x14VerifyLedgerResponse\
+    \x12)\n\x10accounts_checked\x18\x01\x20\x01(\x06R\x0faccountsChecked\x12\
+    :\n\nmismatches\x18\x02\x20\x03(\x0b2\x1a.accounting.LedgerMismatchR\nmi\
+    smatches\x12\x18\n\x07rebuilt\x18\x03\x20\x01(\x08R\x07rebuilt\x12:\n\nb\
+    ackfilled\x18\x04\x20\x03(\x0b2\x1a.accounting.LedgerBackfillR\nbackfill\
+    ed2\xad\x07\n\x11AccountingService\x12T\n\rCreateAccount\x12\x20.account\
+    ing.CreateAccountRequest\x1a!.accounting.CreateAccountResponse\x12K\n\nG\
+    etBalance\x12\x1d.accounting.GetBalanceRequest\x1a\x1e.accounting.GetBal\
+    anceResponse\x12E\n\x08Transfer\x12\x1b.accounting.TransferRequest\x1a\
+    \x1c.accounting.TransferResponse\x12<\n\x05Reset\x12\x18.accounting.Rese\
+    tRequest\x1a\x19.accounting.ResetResponse\x12]\n\x10ListTransactions\x12\
+    #.accounting.ListTransactionsRequest\x1a$.accounting.ListTransactionsRes\
+    ponse\x12Q\n\x0cWatchBalance\x12\x1f.accounting.WatchBalanceRequest\x1a\
+    \x1e.accounting.GetBalanceResponse0\x01\x12`\n\x11AuthorizeTransfer\x12$\
+    .accounting.AuthorizeTransferRequest\x1a%.accounting.AuthorizeTransferRe\
+    sponse\x12Z\n\x0fCaptureTransfer\x12\".accounting.CaptureTransferRequest\
+    \x1a#.accounting.CaptureTransferResponse\x12Q\n\x0cVoidTransfer\x12\x1f.\
+    accounting.VoidTransferRequest\x1a\x20.accounting.VoidTransferResponse\
+    \x12Z\n\x0fReverseTransfer\x12\".accounting.ReverseTransferRequest\x1a#.\
+    accounting.ReverseTransferResponse\x12Q\n\x0cVerifyLedger\x12\x1f.accoun\
+    ting.VerifyLedgerRequest\x1a\x20.accounting.VerifyLedgerResponseJ\xaf3\n\
+    \x07\x12\x05\0\0\xb3\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\
+    \x02\x12\x03\x02\0\x13\n\n\n\x02\x06\0\x12\x04\x04\0\x10\x01\n\n\n\x03\
+    \x06\0\x01\x12\x03\x04\x08\x19\n\x0b\n\x04\x06\0\x02\0\x12\x03\x05\x04L\
+    \n\x0c\n\x05\x06\0\x02\0\x01\x12\x03\x05\x08\x15\n\x0c\n\x05\x06\0\x02\0\
+    \x02\x12\x03\x05\x16*\n\x0c\n\x05\x06\0\x02\0\x03\x12\x03\x055J\n\x0b\n\
+    \x04\x06\0\x02\x01\x12\x03\x06\x04C\n\x0c\n\x05\x06\0\x02\x01\x01\x12\
+    \x03\x06\x08\x12\n\x0c\n\x05\x06\0\x02\x01\x02\x12\x03\x06\x13$\n\x0c\n\
+    \x05\x06\0\x02\x01\x03\x12\x03\x06/A\n\x0b\n\x04\x06\0\x02\x02\x12\x03\
+    \x07\x04=\n\x0c\n\x05\x06\0\x02\x02\x01\x12\x03\x07\x08\x10\n\x0c\n\x05\
+    \x06\0\x02\x02\x02\x12\x03\x07\x11\x20\n\x0c\n\x05\x06\0\x02\x02\x03\x12\
+    \x03\x07+;\n\x0b\n\x04\x06\0\x02\x03\x12\x03\x08\x044\n\x0c\n\x05\x06\0\
+    \x02\x03\x01\x12\x03\x08\x08\r\n\x0c\n\x05\x06\0\x02\x03\x02\x12\x03\x08\
+    \x0e\x1a\n\x0c\n\x05\x06\0\x02\x03\x03\x12\x03\x08%2\n\x0b\n\x04\x06\0\
+    \x02\x04\x12\x03\t\x04U\n\x0c\n\x05\x06\0\x02\x04\x01\x12\x03\t\x08\x18\
+    \n\x0c\n\x05\x06\0\x02\x04\x02\x12\x03\t\x190\n\x0c\n\x05\x06\0\x02\x04\
+    \x03\x12\x03\t;S\n\x0b\n\x04\x06\0\x02\x05\x12\x03\n\x04N\n\x0c\n\x05\
+    \x06\0\x02\x05\x01\x12\x03\n\x08\x14\n\x0c\n\x05\x06\0\x02\x05\x02\x12\
+    \x03\n\x15(\n\x0c\n\x05\x06\0\x02\x05\x06\x12\x03\n39\n\x0c\n\x05\x06\0\
+    \x02\x05\x03\x12\x03\n:L\n\x0b\n\x04\x06\0\x02\x06\x12\x03\x0b\x04X\n\
+    \x0c\n\x05\x06\0\x02\x06\x01\x12\x03\x0b\x08\x19\n\x0c\n\x05\x06\0\x02\
+    \x06\x02\x12\x03\x0b\x1a2\n\x0c\n\x05\x06\0\x02\x06\x03\x12\x03\x0b=V\n\
+    \x0b\n\x04\x06\0\x02\x07\x12\x03\x0c\x04R\n\x0c\n\x05\x06\0\x02\x07\x01\
+    \x12\x03\x0c\x08\x17\n\x0c\n\x05\x06\0\x02\x07\x02\x12\x03\x0c\x18.\n\
+    \x0c\n\x05\x06\0\x02\x07\x03\x12\x03\x0c9P\n\x0b\n\x04\x06\0\x02\x08\x12\
+    \x03\r\x04I\n\x0c\n\x05\x06\0\x02\x08\x01\x12\x03\r\x08\x14\n\x0c\n\x05\
+    \x06\0\x02\x08\x02\x12\x03\r\x15(\n\x0c\n\x05\x06\0\x02\x08\x03\x12\x03\
+    \r3G\n\x0b\n\x04\x06\0\x02\t\x12\x03\x0e\x04R\n\x0c\n\x05\x06\0\x02\t\
+    \x01\x12\x03\x0e\x08\x17\n\x0c\n\x05\x06\0\x02\t\x02\x12\x03\x0e\x18.\n\
+    \x0c\n\x05\x06\0\x02\t\x03\x12\x03\x0e9P\n\x0b\n\x04\x06\0\x02\n\x12\x03\
+    \x0f\x04I\n\x0c\n\x05\x06\0\x02\n\x01\x12\x03\x0f\x08\x14\n\x0c\n\x05\
+    \x06\0\x02\n\x02\x12\x03\x0f\x15(\n\x0c\n\x05\x06\0\x02\n\x03\x12\x03\
+    \x0f3G\n\t\n\x02\x04\0\x12\x03\x12\0\x17\n\n\n\x03\x04\0\x01\x12\x03\x12\
+    \x08\x14\n\t\n\x02\x04\x01\x12\x03\x13\0\x18\n\n\n\x03\x04\x01\x01\x12\
+    \x03\x13\x08\x15\n@\n\x02\x04\x02\x12\x04\x16\0\x1a\x01\x1a4\x20creates\
+    \x20new\x20account;\x20the\x20account_id\x20must\x20be\x20unique\n\n\n\n\
+    \x03\x04\x02\x01\x12\x03\x16\x08\x1c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x17\x02\x15\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x17\x02\t\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x17\n\x10\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x17\x13\x14\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x18\x02\x19\n\x0c\n\
+    \x05\x04\x02\x02\x01\x05\x12\x03\x18\x02\t\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x18\n\x14\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x18\x17\
+    \x18\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x19\x02\x17\n\x0c\n\x05\x04\x02\
+    \x02\x02\x05\x12\x03\x19\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\
+    \x19\x0b\x12\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x19\x15\x16\n\n\n\
+    \x02\x04\x03\x12\x04\x1c\0\x1f\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1c\x08\
+    \x1d\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x1d\x02\x15\n\x0c\n\x05\x04\x03\
+    \x02\0\x05\x12\x03\x1d\x02\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1d\n\
+    \x10\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x1d\x13\x14\n\x0b\n\x04\x04\
+    \x03\x02\x01\x12\x03\x1e\x02\x19\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\
+    \x1e\x02\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x1e\n\x14\n\x0c\n\x05\
+    \x04\x03\x02\x01\x03\x12\x03\x1e\x17\x18\n\n\n\x02\x04\x04\x12\x04!\0$\
+    \x01\n\n\n\x03\x04\x04\x01\x12\x03!\x08\x19\n\x0b\n\x04\x04\x04\x02\0\
+    \x12\x03\"\x02\x15\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\"\x02\t\n\x0c\n\
+    \x05\x04\x04\x02\0\x01\x12\x03\"\n\x10\n\x0c\n\x05\x04\x04\x02\0\x03\x12\
+    \x03\"\x13\x14\n\x0b\n\x04\x04\x04\x02\x01\x12\x03#\x02\x19\n\x0c\n\x05\
+    \x04\x04\x02\x01\x05\x12\x03#\x02\t\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\
+    \x03#\n\x14\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03#\x17\x18\nd\n\x02\
+    \x04\x05\x12\x04'\0,\x01\x1aX\x20balance\x20is\x20the\x20ledger\x20balan\
+    ce;\x20available_balance\x20excludes\x20funds\x20held\x20by\x20authoriza\
+    tions\n\n\n\n\x03\x04\x05\x01\x12\x03'\x08\x1a\n\x0b\n\x04\x04\x05\x02\0\
+    \x12\x03(\x02\x15\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03(\x02\t\n\x0c\n\
+    \x05\x04\x05\x02\0\x01\x12\x03(\n\x10\n\x0c\n\x05\x04\x05\x02\0\x03\x12\
+    \x03(\x13\x14\n\x0b\n\x04\x04\x05\x02\x01\x12\x03)\x02\x19\n\x0c\n\x05\
+    \x04\x05\x02\x01\x05\x12\x03)\x02\t\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\
+    \x03)\n\x14\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03)\x17\x18\n\x0b\n\x04\
+    \x04\x05\x02\x02\x12\x03*\x02\x17\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\
+    \x03*\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03*\x0b\x12\n\x0c\n\x05\
+    \x04\x05\x02\x02\x03\x12\x03*\x15\x16\n\x0b\n\x04\x04\x05\x02\x03\x12\
+    \x03+\x02!\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03+\x02\n\n\x0c\n\x05\
+    \x04\x05\x02\x03\x01\x12\x03+\x0b\x1c\n\x0c\n\x05\x04\x05\x02\x03\x03\
+    \x12\x03+\x1f\x20\n\n\n\x02\x04\x06\x12\x04.\01\x01\n\n\n\x03\x04\x06\
+    \x01\x12\x03.\x08\x19\n\x0b\n\x04\x04\x06\x02\0\x12\x03/\x02\x19\n\x0c\n\
+    \x05\x04\x06\x02\0\x05\x12\x03/\x02\t\n\x0c\n\x05\x04\x06\x02\0\x01\x12\
+    \x03/\n\x14\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03/\x17\x18\n\x0b\n\x04\
+    \x04\x06\x02\x01\x12\x030\x02\x1b\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\
+    \x030\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x030\x0b\x16\n\x0c\n\x05\
+    \x04\x06\x02\x01\x03\x12\x030\x19\x1a\nF\n\x02\x04\x07\x12\x044\07\x01\
+    \x1a:\x20within\x20one\x20transfer\x20sum\x20of\x20all\x20money\x20delta\
+    s\x20must\x20be\x20zero\n\n\n\n\x03\x04\x07\x01\x12\x034\x08\x17\n\x0b\n\
+    \x04\x04\x07\x02\0\x12\x035\x02\x15\n\x0c\n\x05\x04\x07\x02\0\x05\x12\
+    \x035\x02\t\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x035\n\x10\n\x0c\n\x05\x04\
+    \x07\x02\0\x03\x12\x035\x13\x14\n\x0b\n\x04\x04\x07\x02\x01\x12\x036\x02\
+    ,\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x036\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x01\x06\x12\x036\x0b\x1c\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x036\x1d'\
+    \n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x036*+\n\n\n\x02\x04\x08\x12\x049\0\
+    ;\x01\n\n\n\x03\x04\x08\x01\x12\x039\x08\x18\n\x0b\n\x04\x04\x08\x02\0\
+    \x12\x03:\x02\x15\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03:\x02\t\n\x0c\n\
+    \x05\x04\x08\x02\0\x01\x12\x03:\n\x10\n\x0c\n\x05\x04\x08\x02\0\x03\x12\
+    \x03:\x13\x14\nI\n\x02\x04\t\x12\x04>\0F\x01\x1a=\x20one\x20leg\x20of\
+    \x20a\x20transfer\x20as\x20recorded\x20in\x20the\x20transactions\x20tabl\
+    e\n\n\n\n\x03\x04\t\x01\x12\x03>\x08\x19\n\x0b\n\x04\x04\t\x02\0\x12\x03\
+    ?\x02\x11\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03?\x02\t\n\x0c\n\x05\x04\t\
+    \x02\0\x01\x12\x03?\n\x0c\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03?\x0f\x10\n\
+    \x0b\n\x04\x04\t\x02\x01\x12\x03@\x02\x15\n\x0c\n\x05\x04\t\x02\x01\x05\
+    \x12\x03@\x02\t\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03@\n\x10\n\x0c\n\x05\
+    \x04\t\x02\x01\x03\x12\x03@\x13\x14\n\x0b\n\x04\x04\t\x02\x02\x12\x03A\
+    \x02\x19\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03A\x02\t\n\x0c\n\x05\x04\t\
+    \x02\x02\x01\x12\x03A\n\x14\n\x0c\n\x05\x04\t\x02\x02\x03\x12\x03A\x17\
+    \x18\n\x0b\n\x04\x04\t\x02\x03\x12\x03B\x02\x16\n\x0c\n\x05\x04\t\x02\
+    \x03\x05\x12\x03B\x02\n\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03B\x0b\x11\n\
+    \x0c\n\x05\x04\t\x02\x03\x03\x12\x03B\x14\x15\n\x0b\n\x04\x04\t\x02\x04\
+    \x12\x03C\x02\x20\n\x0c\n\x05\x04\t\x02\x04\x05\x12\x03C\x02\t\n\x0c\n\
+    \x05\x04\t\x02\x04\x01\x12\x03C\n\x1b\n\x0c\n\x05\x04\t\x02\x04\x03\x12\
+    \x03C\x1e\x1f\n0\n\x04\x04\t\x02\x05\x12\x03E\x02\x1a\x1a#\x20millisecon\
+    ds\x20since\x20the\x20unix\x20epoch\n\n\x0c\n\x05\x04\t\x02\x05\x05\x12\
+    \x03E\x02\n\n\x0c\n\x05\x04\t\x02\x05\x01\x12\x03E\x0b\x15\n\x0c\n\x05\
+    \x04\t\x02\x05\x03\x12\x03E\x18\x19\n`\n\x02\x04\n\x12\x04I\0T\x01\x1aT\
+    \x20empty\x20filters\x20match\x20every\x20transaction;\x20times\x20are\
+    \x20milliseconds\x20since\x20the\x20unix\x20epoch\n\n\n\n\x03\x04\n\x01\
+    \x12\x03I\x08\x1f\n\x0b\n\x04\x04\n\x02\0\x12\x03J\x02\x15\n\x0c\n\x05\
+    \x04\n\x02\0\x05\x12\x03J\x02\t\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03J\n\
+    \x10\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03J\x13\x14\n\x0b\n\x04\x04\n\x02\
+    \x01\x12\x03K\x02#\n\x0c\n\x05\x04\n\x02\x01\x04\x12\x03K\x02\n\n\x0c\n\
+    \x05\x04\n\x02\x01\x05\x12\x03K\x0b\x12\n\x0c\n\x05\x04\n\x02\x01\x01\
+    \x12\x03K\x13\x1e\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03K!\"\n\x0b\n\x04\
+    \x04\n\x02\x02\x12\x03L\x02(\n\x0c\n\x05\x04\n\x02\x02\x04\x12\x03L\x02\
+    \n\n\x0c\n\x05\x04\n\x02\x02\x05\x12\x03L\x0b\x12\n\x0c\n\x05\x04\n\x02\
+    \x02\x01\x12\x03L\x13#\n\x0c\n\x05\x04\n\x02\x02\x03\x12\x03L&'\n.\n\x04\
+    \x04\n\x02\x03\x12\x03N\x02\x1a\x1a!\x20inclusive,\x200\x20for\x20no\x20\
+    lower\x20bound\n\n\x0c\n\x05\x04\n\x02\x03\x05\x12\x03N\x02\n\n\x0c\n\
+    \x05\x04\n\x02\x03\x01\x12\x03N\x0b\x15\n\x0c\n\x05\x04\n\x02\x03\x03\
+    \x12\x03N\x18\x19\n.\n\x04\x04\n\x02\x04\x12\x03P\x02\x18\x1a!\x20exclus\
+    ive,\x200\x20for\x20no\x20upper\x20bound\n\n\x0c\n\x05\x04\n\x02\x04\x05\
+    \x12\x03P\x02\n\n\x0c\n\x05\x04\n\x02\x04\x01\x12\x03P\x0b\x13\n\x0c\n\
+    \x05\x04\n\x02\x04\x03\x12\x03P\x16\x17\n\x0b\n\x04\x04\n\x02\x05\x12\
+    \x03Q\x02\x18\n\x0c\n\x05\x04\n\x02\x05\x05\x12\x03Q\x02\t\n\x0c\n\x05\
+    \x04\n\x02\x05\x01\x12\x03Q\n\x13\n\x0c\n\x05\x04\n\x02\x05\x03\x12\x03Q\
+    \x16\x17\n5\n\x04\x04\n\x02\x06\x12\x03S\x02\x18\x1a(\x20next_page_token\
+    \x20from\x20the\x20previous\x20page\n\n\x0c\n\x05\x04\n\x02\x06\x05\x12\
+    \x03S\x02\x08\n\x0c\n\x05\x04\n\x02\x06\x01\x12\x03S\t\x13\n\x0c\n\x05\
+    \x04\n\x02\x06\x03\x12\x03S\x16\x17\n\n\n\x02\x04\x0b\x12\x04V\0[\x01\n\
+    \n\n\x03\x04\x0b\x01\x12\x03V\x08\x20\n\x0b\n\x04\x04\x0b\x02\0\x12\x03W\
+    \x02\x15\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03W\x02\t\n\x0c\n\x05\x04\
+    \x0b\x02\0\x01\x12\x03W\n\x10\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03W\x13\
+    \x14\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03X\x02.\n\x0c\n\x05\x04\x0b\x02\
+    \x01\x04\x12\x03X\x02\n\n\x0c\n\x05\x04\x0b\x02\x01\x06\x12\x03X\x0b\x1c\
+    \n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03X\x1d)\n\x0c\n\x05\x04\x0b\x02\
+    \x01\x03\x12\x03X,-\n8\n\x04\x04\x0b\x02\x02\x12\x03Z\x02\x1d\x1a+\x20em\
+    pty\x20when\x20there\x20are\x20no\x20more\x20transactions\n\n\x0c\n\x05\
+    \x04\x0b\x02\x02\x05\x12\x03Z\x02\x08\n\x0c\n\x05\x04\x0b\x02\x02\x01\
+    \x12\x03Z\t\x18\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03Z\x1b\x1c\n\x87\
+    \x01\n\x02\x04\x0c\x12\x04_\0b\x01\x1a{\x20streams\x20the\x20current\x20\
+    balance\x20of\x20each\x20account,\x20then\x20a\x20new\x20balance\x20when\
+    ever\x20a\x20transfer\n\x20touching\x20one\x20of\x20the\x20accounts\x20c\
+    ommits\n\n\n\n\x03\x04\x0c\x01\x12\x03_\x08\x1b\n\x0b\n\x04\x04\x0c\x02\
+    \0\x12\x03`\x02\x15\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03`\x02\t\n\x0c\n\
+    \x05\x04\x0c\x02\0\x01\x12\x03`\n\x10\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\
+    \x03`\x13\x14\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03a\x02#\n\x0c\n\x05\x04\
+    \x0c\x02\x01\x04\x12\x03a\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03a\
+    \x0b\x12\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03a\x13\x1e\n\x0c\n\x05\
+    \x04\x0c\x02\x01\x03\x12\x03a!\"\n\xa7\x01\n\x02\x04\r\x12\x04f\0k\x01\
+    \x1a\x9a\x01\x20holds\x20amount\x20of\x20the\x20from\x20account's\x20ava\
+    ilable\x20balance\x20until\x20it\x20is\x20captured,\x20voided\x20or\n\
+    \x20expires;\x20the\x20hold\x20is\x20identified\x20by\x20the\x20req_id\
+    \x20of\x20the\x20authorization\n\n\n\n\x03\x04\r\x01\x12\x03f\x08\x20\n\
+    \x0b\n\x04\x04\r\x02\0\x12\x03g\x02\x15\n\x0c\n\x05\x04\r\x02\0\x05\x12\
+    \x03g\x02\t\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03g\n\x10\n\x0c\n\x05\x04\r\
+    \x02\0\x03\x12\x03g\x13\x14\n\x0b\n\x04\x04\r\x02\x01\x12\x03h\x02\x1e\n\
+    \x0c\n\x05\x04\r\x02\x01\x05\x12\x03h\x02\t\n\x0c\n\x05\x04\r\x02\x01\
+    \x01\x12\x03h\n\x19\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03h\x1c\x1d\n\x0b\
+    \n\x04\x04\r\x02\x02\x12\x03i\x02\x1c\n\x0c\n\x05\x04\r\x02\x02\x05\x12\
+    \x03i\x02\t\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03i\n\x17\n\x0c\n\x05\x04\
+    \r\x02\x02\x03\x12\x03i\x1a\x1b\n\x0b\n\x04\x04\r\x02\x03\x12\x03j\x02\
+    \x16\n\x0c\n\x05\x04\r\x02\x03\x05\x12\x03j\x02\n\n\x0c\n\x05\x04\r\x02\
+    \x03\x01\x12\x03j\x0b\x11\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03j\x14\x15\
+    \n\n\n\x02\x04\x0e\x12\x04m\0r\x01\n\n\n\x03\x04\x0e\x01\x12\x03m\x08!\n\
+    \x0b\n\x04\x04\x0e\x02\0\x12\x03n\x02\x15\n\x0c\n\x05\x04\x0e\x02\0\x05\
+    \x12\x03n\x02\t\n\x0c\n\x05\x04\x0e\x02\0\x01\x12\x03n\n\x10\n\x0c\n\x05\
+    \x04\x0e\x02\0\x03\x12\x03n\x13\x14\n\x0b\n\x04\x04\x0e\x02\x01\x12\x03o\
+    \x02\x16\n\x0c\n\x05\x04\x0e\x02\x01\x05\x12\x03o\x02\t\n\x0c\n\x05\x04\
+    \x0e\x02\x01\x01\x12\x03o\n\x11\n\x0c\n\x05\x04\x0e\x02\x01\x03\x12\x03o\
+    \x14\x15\n0\n\x04\x04\x0e\x02\x02\x12\x03q\x02\x1a\x1a#\x20milliseconds\
+    \x20since\x20the\x20unix\x20epoch\n\n\x0c\n\x05\x04\x0e\x02\x02\x05\x12\
+    \x03q\x02\n\n\x0c\n\x05\x04\x0e\x02\x02\x01\x12\x03q\x0b\x15\n\x0c\n\x05\
+    \x04\x0e\x02\x02\x03\x12\x03q\x18\x19\nl\n\x02\x04\x0f\x12\x04v\0{\x01\
+    \x1a`\x20moves\x20amount\x20from\x20the\x20held\x20account\x20to\x20the\
+    \x20receiving\x20account\x20and\x20releases\x20the\x20rest\x20of\n\x20th\
+    e\x20hold\n\n\n\n\x03\x04\x0f\x01\x12\x03v\x08\x1e\n\x0b\n\x04\x04\x0f\
+    \x02\0\x12\x03w\x02\x15\n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03w\x02\t\n\
+    \x0c\n\x05\x04\x0f\x02\0\x01\x12\x03w\n\x10\n\x0c\n\x05\x04\x0f\x02\0\
+    \x03\x12\x03w\x13\x14\n\x0b\n\x04\x04\x0f\x02\x01\x12\x03x\x02\x16\n\x0c\
+    \n\x05\x04\x0f\x02\x01\x05\x12\x03x\x02\t\n\x0c\n\x05\x04\x0f\x02\x01\
+    \x01\x12\x03x\n\x11\n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03x\x14\x15\n.\
+    \n\x04\x04\x0f\x02\x02\x12\x03z\x02\x16\x1a!\x200\x20captures\x20the\x20\
+    full\x20held\x20amount\n\n\x0c\n\x05\x04\x0f\x02\x02\x05\x12\x03z\x02\n\
+    \n\x0c\n\x05\x04\x0f\x02\x02\x01\x12\x03z\x0b\x11\n\x0c\n\x05\x04\x0f\
+    \x02\x02\x03\x12\x03z\x14\x15\n\x0b\n\x02\x04\x10\x12\x05}\0\x81\x01\x01\
+    \n\n\n\x03\x04\x10\x01\x12\x03}\x08\x1f\n\x0b\n\x04\x04\x10\x02\0\x12\
+    \x03~\x02\x15\n\x0c\n\x05\x04\x10\x02\0\x05\x12\x03~\x02\t\n\x0c\n\x05\
+    \x04\x10\x02\0\x01\x12\x03~\n\x10\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03~\
+    \x13\x14\n\x0b\n\x04\x04\x10\x02\x01\x12\x03\x7f\x02\x16\n\x0c\n\x05\x04\
+    \x10\x02\x01\x05\x12\x03\x7f\x02\t\n\x0c\n\x05\x04\x10\x02\x01\x01\x12\
+    \x03\x7f\n\x11\n\x0c\n\x05\x04\x10\x02\x01\x03\x12\x03\x7f\x14\x15\n\x0c\
+    \n\x04\x04\x10\x02\x02\x12\x04\x80\x01\x02\x1f\n\r\n\x05\x04\x10\x02\x02\
+    \x05\x12\x04\x80\x01\x02\n\n\r\n\x05\x04\x10\x02\x02\x01\x12\x04\x80\x01\
+    \x0b\x1a\n\r\n\x05\x04\x10\x02\x02\x03\x12\x04\x80\x01\x1d\x1e\n:\n\x02\
+    \x04\x11\x12\x06\x84\x01\0\x87\x01\x01\x1a,\x20releases\x20the\x20hold\
+    \x20without\x20moving\x20any\x20money\n\n\x0b\n\x03\x04\x11\x01\x12\x04\
+    \x84\x01\x08\x1b\n\x0c\n\x04\x04\x11\x02\0\x12\x04\x85\x01\x02\x15\n\r\n\
+    \x05\x04\x11\x02\0\x05\x12\x04\x85\x01\x02\t\n\r\n\x05\x04\x11\x02\0\x01\
+    \x12\x04\x85\x01\n\x10\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\x85\x01\x13\
+    \x14\n\x0c\n\x04\x04\x11\x02\x01\x12\x04\x86\x01\x02\x16\n\r\n\x05\x04\
+    \x11\x02\x01\x05\x12\x04\x86\x01\x02\t\n\r\n\x05\x04\x11\x02\x01\x01\x12\
+    \x04\x86\x01\n\x11\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\x86\x01\x14\x15\
+    \n\x0c\n\x02\x04\x12\x12\x06\x89\x01\0\x8c\x01\x01\n\x0b\n\x03\x04\x12\
+    \x01\x12\x04\x89\x01\x08\x1c\n\x0c\n\x04\x04\x12\x02\0\x12\x04\x8a\x01\
+    \x02\x15\n\r\n\x05\x04\x12\x02\0\x05\x12\x04\x8a\x01\x02\t\n\r\n\x05\x04\
+    \x12\x02\0\x01\x12\x04\x8a\x01\n\x10\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\
+    \x8a\x01\x13\x14\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\x8b\x01\x02\x16\n\r\
+    \n\x05\x04\x12\x02\x01\x05\x12\x04\x8b\x01\x02\t\n\r\n\x05\x04\x12\x02\
+    \x01\x01\x12\x04\x8b\x01\n\x11\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\x8b\
+    \x01\x14\x15\nu\n\x02\x04\x13\x12\x06\x90\x01\0\x93\x01\x01\x1ag\x20appl\
+    ies\x20the\x20negation\x20of\x20every\x20leg\x20recorded\x20for\x20origi\
+    nal_req_id;\x20a\x20transfer\x20can\x20only\x20be\n\x20reversed\x20once\
+    \n\n\x0b\n\x03\x04\x13\x01\x12\x04\x90\x01\x08\x1e\n\x0c\n\x04\x04\x13\
+    \x02\0\x12\x04\x91\x01\x02\x15\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\x91\
+    \x01\x02\t\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\x91\x01\n\x10\n\r\n\x05\
+    \x04\x13\x02\0\x03\x12\x04\x91\x01\x13\x14\n\x0c\n\x04\x04\x13\x02\x01\
+    \x12\x04\x92\x01\x02\x1e\n\r\n\x05\x04\x13\x02\x01\x05\x12\x04\x92\x01\
+    \x02\t\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\x92\x01\n\x19\n\r\n\x05\x04\
+    \x13\x02\x01\x03\x12\x04\x92\x01\x1c\x1d\n\x0c\n\x02\x04\x14\x12\x06\x95\
+    \x01\0\x98\x01\x01\n\x0b\n\x03\x04\x14\x01\x12\x04\x95\x01\x08\x1f\n\x0c\
+    \n\x04\x04\x14\x02\0\x12\x04\x96\x01\x02\x15\n\r\n\x05\x04\x14\x02\0\x05\
+    \x12\x04\x96\x01\x02\t\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\x96\x01\n\x10\
+    \n\r\n\x05\x04\x14\x02\0\x03\x12\x04\x96\x01\x13\x14\n\x0c\n\x04\x04\x14\
+    \x02\x01\x12\x04\x97\x01\x02\x1e\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\
+    \x97\x01\x02\t\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\x97\x01\n\x19\n\r\n\
+    \x05\x04\x14\x02\x01\x03\x12\x04\x97\x01\x1c\x1d\n\xb4\x01\n\x02\x04\x15\
+    \x12\x06\x9c\x01\0\x9e\x01\x01\x1a\xa5\x01\x20recomputes\x20every\x20bal\
+    ance\x20from\x20the\x20ledger\x20entries,\x20the\x20opening\x20balance\
+    \x20included;\x20with\n\x20rebuild\x20the\x20stored\x20balances\x20that\
+    \x20disagree\x20are\x20replaced\x20by\x20the\x20recomputed\x20ones\n\n\
+    \x0b\n\x03\x04\x15\x01\x12\x04\x9c\x01\x08\x1b\n\x0c\n\x04\x04\x15\x02\0\
+    \x12\x04\x9d\x01\x02\x13\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\x9d\x01\x02\
+    \x06\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\x9d\x01\x07\x0e\n\r\n\x05\x04\
+    \x15\x02\0\x03\x12\x04\x9d\x01\x11\x12\n\x0c\n\x02\x04\x16\x12\x06\xa0\
+    \x01\0\xa4\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\xa0\x01\x08\x16\n\x0c\
+    \n\x04\x04\x16\x02\0\x12\x04\xa1\x01\x02\x19\n\r\n\x05\x04\x16\x02\0\x05\
+    \x12\x04\xa1\x01\x02\t\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\xa1\x01\n\x14\
+    \n\r\n\x05\x04\x16\x02\0\x03\x12\x04\xa1\x01\x17\x18\n\x0c\n\x04\x04\x16\
+    \x02\x01\x12\x04\xa2\x01\x02\x1e\n\r\n\x05\x04\x16\x02\x01\x05\x12\x04\
+    \xa2\x01\x02\n\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\xa2\x01\x0b\x19\n\r\
+    \n\x05\x04\x16\x02\x01\x03\x12\x04\xa2\x01\x1c\x1d\n\x0c\n\x04\x04\x16\
+    \x02\x02\x12\x04\xa3\x01\x02\x1e\n\r\n\x05\x04\x16\x02\x02\x05\x12\x04\
+    \xa3\x01\x02\n\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\xa3\x01\x0b\x19\n\r\
+    \n\x05\x04\x16\x02\x02\x03\x12\x04\xa3\x01\x1c\x1d\n\xac\x01\n\x02\x04\
+    \x17\x12\x06\xa8\x01\0\xab\x01\x01\x1a\x9d\x01\x20an\x20opening\x20balan\
+    ce\x20a\x20migration\x20inferred\x20from\x20the\x20balance\x20of\x20an\
+    \x20account\x20created\x20before\n\x20they\x20were\x20recorded,\x20which\
+    \x20takes\x20up\x20any\x20drift\x20the\x20account\x20had\x20by\x20then\n\
+    \n\x0b\n\x03\x04\x17\x01\x12\x04\xa8\x01\x08\x16\n\x0c\n\x04\x04\x17\x02\
+    \0\x12\x04\xa9\x01\x02\x19\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xa9\x01\
+    \x02\t\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xa9\x01\n\x14\n\r\n\x05\x04\
+    \x17\x02\0\x03\x12\x04\xa9\x01\x17\x18\n\x0c\n\x04\x04\x17\x02\x01\x12\
+    \x04\xaa\x01\x02\x16\n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xaa\x01\x02\n\
+    \n\r\n\x05\x04\x17\x02\x01\x01\x12\x04\xaa\x01\x0b\x11\n\r\n\x05\x04\x17\
+    \x02\x01\x03\x12\x04\xaa\x01\x14\x15\n\x0c\n\x02\x04\x18\x12\x06\xad\x01\
+    \0\xb3\x01\x01\n\x0b\n\x03\x04\x18\x01\x12\x04\xad\x01\x08\x1c\n\x0c\n\
+    \x04\x04\x18\x02\0\x12\x04\xae\x01\x02\x1f\n\r\n\x05\x04\x18\x02\0\x05\
+    \x12\x04\xae\x01\x02\t\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xae\x01\n\x1a\
+    \n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xae\x01\x1d\x1e\n;\n\x04\x04\x18\
+    \x02\x01\x12\x04\xb0\x01\x02)\x1a-\x20the\x20disagreements\x20found,\x20\
+    before\x20any\x20rebuild\n\n\r\n\x05\x04\x18\x02\x01\x04\x12\x04\xb0\x01\
+    \x02\n\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\xb0\x01\x0b\x19\n\r\n\x05\
+    \x04\x18\x02\x01\x01\x12\x04\xb0\x01\x1a$\n\r\n\x05\x04\x18\x02\x01\x03\
+    \x12\x04\xb0\x01'(\n\x0c\n\x04\x04\x18\x02\x02\x12\x04\xb1\x01\x02\x13\n\
+    \r\n\x05\x04\x18\x02\x02\x05\x12\x04\xb1\x01\x02\x06\n\r\n\x05\x04\x18\
+    \x02\x02\x01\x12\x04\xb1\x01\x07\x0e\n\r\n\x05\x04\x18\x02\x02\x03\x12\
+    \x04\xb1\x01\x11\x12\n\x0c\n\x04\x04\x18\x02\x03\x12\x04\xb2\x01\x02)\n\
+    \r\n\x05\x04\x18\x02\x03\x04\x12\x04\xb2\x01\x02\n\n\r\n\x05\x04\x18\x02\
+    \x03\x06\x12\x04\xb2\x01\x0b\x19\n\r\n\x05\x04\x18\x02\x03\x01\x12\x04\
+    \xb2\x01\x1a$\n\r\n\x05\x04\x18\x02\x03\x03\x12\x04\xb2\x01'(b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
@@ -3,9 +3,7 @@
 
 // https://github.com/Manishearth/rust-clippy/issues/702
 #![allow(unknown_lints)]
-#![allow(clippy)]
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(clippy::all)]
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
@@ -46,6 +44,55 @@ const METHOD_ACCOUNTING_SERVICE_RESET: ::grpcio::Method<super::accounting::Reset
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_ACCOUNTING_SERVICE_LIST_TRANSACTIONS: ::grpcio::Method<super::accounting::ListTransactionsRequest, super::accounting::ListTransactionsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/accounting.AccountingService/ListTransactions",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ACCOUNTING_SERVICE_WATCH_BALANCE: ::grpcio::Method<super::accounting::WatchBalanceRequest, super::accounting::GetBalanceResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/accounting.AccountingService/WatchBalance",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ACCOUNTING_SERVICE_AUTHORIZE_TRANSFER: ::grpcio::Method<super::accounting::AuthorizeTransferRequest, super::accounting::AuthorizeTransferResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/accounting.AccountingService/AuthorizeTransfer",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ACCOUNTING_SERVICE_CAPTURE_TRANSFER: ::grpcio::Method<super::accounting::CaptureTransferRequest, super::accounting::CaptureTransferResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/accounting.AccountingService/CaptureTransfer",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ACCOUNTING_SERVICE_VOID_TRANSFER: ::grpcio::Method<super::accounting::VoidTransferRequest, super::accounting::VoidTransferResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/accounting.AccountingService/VoidTransfer",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ACCOUNTING_SERVICE_REVERSE_TRANSFER: ::grpcio::Method<super::accounting::ReverseTransferRequest, super::accounting::ReverseTransferResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/accounting.AccountingService/ReverseTransfer",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ACCOUNTING_SERVICE_VERIFY_LEDGER: ::grpcio::Method<super::accounting::VerifyLedgerRequest, super::accounting::VerifyLedgerResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/accounting.AccountingService/VerifyLedger",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct AccountingServiceClient {
     client: ::grpcio::Client,
@@ -121,7 +168,111 @@ impl AccountingServiceClient {
     pub fn reset_async(&self, req: &super::accounting::ResetRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::ResetResponse>> {
         self.reset_async_opt(req, ::grpcio::CallOption::default())
     }
-    pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
+
+    pub fn list_transactions_opt(&self, req: &super::accounting::ListTransactionsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::accounting::ListTransactionsResponse> {
+        self.client.unary_call(&METHOD_ACCOUNTING_SERVICE_LIST_TRANSACTIONS, req, opt)
+    }
+
+    pub fn list_transactions(&self, req: &super::accounting::ListTransactionsRequest) -> ::grpcio::Result<super::accounting::ListTransactionsResponse> {
+        self.list_transactions_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn list_transactions_async_opt(&self, req: &super::accounting::ListTransactionsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::ListTransactionsResponse>> {
+        self.client.unary_call_async(&METHOD_ACCOUNTING_SERVICE_LIST_TRANSACTIONS, req, opt)
+    }
+
+    pub fn list_transactions_async(&self, req: &super::accounting::ListTransactionsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::ListTransactionsResponse>> {
+        self.list_transactions_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn watch_balance_opt(&self, req: &super::accounting::WatchBalanceRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::accounting::GetBalanceResponse>> {
+        self.client.server_streaming(&METHOD_ACCOUNTING_SERVICE_WATCH_BALANCE, req, opt)
+    }
+
+    pub fn watch_balance(&self, req: &super::accounting::WatchBalanceRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::accounting::GetBalanceResponse>> {
+        self.watch_balance_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn authorize_transfer_opt(&self, req: &super::accounting::AuthorizeTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::accounting::AuthorizeTransferResponse> {
+        self.client.unary_call(&METHOD_ACCOUNTING_SERVICE_AUTHORIZE_TRANSFER, req, opt)
+    }
+
+    pub fn authorize_transfer(&self, req: &super::accounting::AuthorizeTransferRequest) -> ::grpcio::Result<super::accounting::AuthorizeTransferResponse> {
+        self.authorize_transfer_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn authorize_transfer_async_opt(&self, req: &super::accounting::AuthorizeTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::AuthorizeTransferResponse>> {
+        self.client.unary_call_async(&METHOD_ACCOUNTING_SERVICE_AUTHORIZE_TRANSFER, req, opt)
+    }
+
+    pub fn authorize_transfer_async(&self, req: &super::accounting::AuthorizeTransferRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::AuthorizeTransferResponse>> {
+        self.authorize_transfer_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn capture_transfer_opt(&self, req: &super::accounting::CaptureTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::accounting::CaptureTransferResponse> {
+        self.client.unary_call(&METHOD_ACCOUNTING_SERVICE_CAPTURE_TRANSFER, req, opt)
+    }
+
+    pub fn capture_transfer(&self, req: &super::accounting::CaptureTransferRequest) -> ::grpcio::Result<super::accounting::CaptureTransferResponse> {
+        self.capture_transfer_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn capture_transfer_async_opt(&self, req: &super::accounting::CaptureTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::CaptureTransferResponse>> {
+        self.client.unary_call_async(&METHOD_ACCOUNTING_SERVICE_CAPTURE_TRANSFER, req, opt)
+    }
+
+    pub fn capture_transfer_async(&self, req: &super::accounting::CaptureTransferRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::CaptureTransferResponse>> {
+        self.capture_transfer_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn void_transfer_opt(&self, req: &super::accounting::VoidTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::accounting::VoidTransferResponse> {
+        self.client.unary_call(&METHOD_ACCOUNTING_SERVICE_VOID_TRANSFER, req, opt)
+    }
+
+    pub fn void_transfer(&self, req: &super::accounting::VoidTransferRequest) -> ::grpcio::Result<super::accounting::VoidTransferResponse> {
+        self.void_transfer_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn void_transfer_async_opt(&self, req: &super::accounting::VoidTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::VoidTransferResponse>> {
+        self.client.unary_call_async(&METHOD_ACCOUNTING_SERVICE_VOID_TRANSFER, req, opt)
+    }
+
+    pub fn void_transfer_async(&self, req: &super::accounting::VoidTransferRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::VoidTransferResponse>> {
+        self.void_transfer_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn reverse_transfer_opt(&self, req: &super::accounting::ReverseTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::accounting::ReverseTransferResponse> {
+        self.client.unary_call(&METHOD_ACCOUNTING_SERVICE_REVERSE_TRANSFER, req, opt)
+    }
+
+    pub fn reverse_transfer(&self, req: &super::accounting::ReverseTransferRequest) -> ::grpcio::Result<super::accounting::ReverseTransferResponse> {
+        self.reverse_transfer_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn reverse_transfer_async_opt(&self, req: &super::accounting::ReverseTransferRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::ReverseTransferResponse>> {
+        self.client.unary_call_async(&METHOD_ACCOUNTING_SERVICE_REVERSE_TRANSFER, req, opt)
+    }
+
+    pub fn reverse_transfer_async(&self, req: &super::accounting::ReverseTransferRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::ReverseTransferResponse>> {
+        self.reverse_transfer_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn verify_ledger_opt(&self, req: &super::accounting::VerifyLedgerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::accounting::VerifyLedgerResponse> {
+        self.client.unary_call(&METHOD_ACCOUNTING_SERVICE_VERIFY_LEDGER, req, opt)
+    }
+
+    pub fn verify_ledger(&self, req: &super::accounting::VerifyLedgerRequest) -> ::grpcio::Result<super::accounting::VerifyLedgerResponse> {
+        self.verify_ledger_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn verify_ledger_async_opt(&self, req: &super::accounting::VerifyLedgerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::VerifyLedgerResponse>> {
+        self.client.unary_call_async(&METHOD_ACCOUNTING_SERVICE_VERIFY_LEDGER, req, opt)
+    }
+
+    pub fn verify_ledger_async(&self, req: &super::accounting::VerifyLedgerRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::accounting::VerifyLedgerResponse>> {
+        self.verify_ledger_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Output = ()> + Send + 'static {
         self.client.spawn(f)
     }
 }
@@ -131,6 +282,13 @@ pub trait AccountingService {
     fn get_balance(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::GetBalanceRequest, sink: ::grpcio::UnarySink<super::accounting::GetBalanceResponse>);
     fn transfer(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::TransferRequest, sink: ::grpcio::UnarySink<super::accounting::TransferResponse>);
     fn reset(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::ResetRequest, sink: ::grpcio::UnarySink<super::accounting::ResetResponse>);
+    fn list_transactions(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::ListTransactionsRequest, sink: ::grpcio::UnarySink<super::accounting::ListTransactionsResponse>);
+    fn watch_balance(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::WatchBalanceRequest, sink: ::grpcio::ServerStreamingSink<super::accounting::GetBalanceResponse>);
+    fn authorize_transfer(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::AuthorizeTransferRequest, sink: ::grpcio::UnarySink<super::accounting::AuthorizeTransferResponse>);
+    fn capture_transfer(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::CaptureTransferRequest, sink: ::grpcio::UnarySink<super::accounting::CaptureTransferResponse>);
+    fn void_transfer(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::VoidTransferRequest, sink: ::grpcio::UnarySink<super::accounting::VoidTransferResponse>);
+    fn reverse_transfer(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::ReverseTransferRequest, sink: ::grpcio::UnarySink<super::accounting::ReverseTransferResponse>);
+    fn verify_ledger(&mut self, ctx: ::grpcio::RpcContext, req: super::accounting::VerifyLedgerRequest, sink: ::grpcio::UnarySink<super::accounting::VerifyLedgerResponse>);
 }
 
 pub fn create_accounting_service<S: AccountingService + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -151,5 +309,33 @@ pub fn create_accounting_service<S: AccountingService + Send + Clone + 'static>(
     builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_RESET, move |ctx, req, resp| {
         instance.reset(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_LIST_TRANSACTIONS, move |ctx, req, resp| {
+        instance.list_transactions(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_ACCOUNTING_SERVICE_WATCH_BALANCE, move |ctx, req, resp| {
+        instance.watch_balance(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_AUTHORIZE_TRANSFER, move |ctx, req, resp| {
+        instance.authorize_transfer(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_CAPTURE_TRANSFER, move |ctx, req, resp| {
+        instance.capture_transfer(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_VOID_TRANSFER, move |ctx, req, resp| {
+        instance.void_transfer(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_REVERSE_TRANSFER, move |ctx, req, resp| {
+        instance.reverse_transfer(ctx, req, resp)
+    });
+    let mut instance = s;
+    builder = builder.add_unary_handler(&METHOD_ACCOUNTING_SERVICE_VERIFY_LEDGER, move |ctx, req, resp| {
+        instance.verify_ledger(ctx, req, resp)
+    });
     builder.build()
 }
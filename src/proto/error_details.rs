@@ -0,0 +1,860 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `google/rpc/error_details.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RetryInfo {
+    // message fields
+    pub retry_delay: ::protobuf::SingularPtrField<::protobuf::well_known_types::Duration>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RetryInfo {
+    fn default() -> &'a RetryInfo {
+        <RetryInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RetryInfo {
+    pub fn new() -> RetryInfo {
+        ::std::default::Default::default()
+    }
+
+    // .google.protobuf.Duration retry_delay = 1;
+
+
+    pub fn get_retry_delay(&self) -> &::protobuf::well_known_types::Duration {
+        self.retry_delay.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Duration as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_retry_delay(&mut self) {
+        self.retry_delay.clear();
+    }
+
+    pub fn has_retry_delay(&self) -> bool {
+        self.retry_delay.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retry_delay(&mut self, v: ::protobuf::well_known_types::Duration) {
+        self.retry_delay = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_retry_delay(&mut self) -> &mut ::protobuf::well_known_types::Duration {
+        if self.retry_delay.is_none() {
+            self.retry_delay.set_default();
+        }
+        self.retry_delay.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_retry_delay(&mut self) -> ::protobuf::well_known_types::Duration {
+        self.retry_delay.take().unwrap_or_else(|| ::protobuf::well_known_types::Duration::new())
+    }
+}
+
+impl ::protobuf::Message for RetryInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.retry_delay {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.retry_delay)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.retry_delay.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.retry_delay.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RetryInfo {
+        RetryInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Duration>>(
+                "retry_delay",
+                |m: &RetryInfo| { &m.retry_delay },
+                |m: &mut RetryInfo| { &mut m.retry_delay },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RetryInfo>(
+                "RetryInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RetryInfo {
+        static instance: ::protobuf::rt::LazyV2<RetryInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RetryInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for RetryInfo {
+    fn clear(&mut self) {
+        self.retry_delay.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RetryInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetryInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ErrorInfo {
+    // message fields
+    pub reason: ::std::string::String,
+    pub domain: ::std::string::String,
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorInfo {
+    fn default() -> &'a ErrorInfo {
+        <ErrorInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ErrorInfo {
+    pub fn new() -> ErrorInfo {
+        ::std::default::Default::default()
+    }
+
+    // string reason = 1;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+
+    // string domain = 2;
+
+
+    pub fn get_domain(&self) -> &str {
+        &self.domain
+    }
+    pub fn clear_domain(&mut self) {
+        self.domain.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_domain(&mut self, v: ::std::string::String) {
+        self.domain = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_domain(&mut self) -> &mut ::std::string::String {
+        &mut self.domain
+    }
+
+    // Take field
+    pub fn take_domain(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.domain, ::std::string::String::new())
+    }
+
+    // repeated .google.rpc.ErrorInfo.MetadataEntry metadata = 3;
+
+
+    pub fn get_metadata(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.metadata
+    }
+    pub fn clear_metadata(&mut self) {
+        self.metadata.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metadata(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.metadata = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_metadata(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.metadata
+    }
+
+    // Take field
+    pub fn take_metadata(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.metadata, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for ErrorInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.domain)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.metadata)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reason);
+        }
+        if !self.domain.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.domain);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.metadata);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.reason.is_empty() {
+            os.write_string(1, &self.reason)?;
+        }
+        if !self.domain.is_empty() {
+            os.write_string(2, &self.domain)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.metadata, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ErrorInfo {
+        ErrorInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &ErrorInfo| { &m.reason },
+                |m: &mut ErrorInfo| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "domain",
+                |m: &ErrorInfo| { &m.domain },
+                |m: &mut ErrorInfo| { &mut m.domain },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "metadata",
+                |m: &ErrorInfo| { &m.metadata },
+                |m: &mut ErrorInfo| { &mut m.metadata },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ErrorInfo>(
+                "ErrorInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ErrorInfo {
+        static instance: ::protobuf::rt::LazyV2<ErrorInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ErrorInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for ErrorInfo {
+    fn clear(&mut self) {
+        self.reason.clear();
+        self.domain.clear();
+        self.metadata.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ErrorInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BadRequest {
+    // message fields
+    pub field_violations: ::protobuf::RepeatedField<BadRequest_FieldViolation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BadRequest {
+    fn default() -> &'a BadRequest {
+        <BadRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BadRequest {
+    pub fn new() -> BadRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .google.rpc.BadRequest.FieldViolation field_violations = 1;
+
+
+    pub fn get_field_violations(&self) -> &[BadRequest_FieldViolation] {
+        &self.field_violations
+    }
+    pub fn clear_field_violations(&mut self) {
+        self.field_violations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_violations(&mut self, v: ::protobuf::RepeatedField<BadRequest_FieldViolation>) {
+        self.field_violations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_field_violations(&mut self) -> &mut ::protobuf::RepeatedField<BadRequest_FieldViolation> {
+        &mut self.field_violations
+    }
+
+    // Take field
+    pub fn take_field_violations(&mut self) -> ::protobuf::RepeatedField<BadRequest_FieldViolation> {
+        ::std::mem::replace(&mut self.field_violations, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BadRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.field_violations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.field_violations)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.field_violations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.field_violations {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BadRequest {
+        BadRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BadRequest_FieldViolation>>(
+                "field_violations",
+                |m: &BadRequest| { &m.field_violations },
+                |m: &mut BadRequest| { &mut m.field_violations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BadRequest>(
+                "BadRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BadRequest {
+        static instance: ::protobuf::rt::LazyV2<BadRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BadRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for BadRequest {
+    fn clear(&mut self) {
+        self.field_violations.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BadRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BadRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BadRequest_FieldViolation {
+    // message fields
+    pub field: ::std::string::String,
+    pub description: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BadRequest_FieldViolation {
+    fn default() -> &'a BadRequest_FieldViolation {
+        <BadRequest_FieldViolation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BadRequest_FieldViolation {
+    pub fn new() -> BadRequest_FieldViolation {
+        ::std::default::Default::default()
+    }
+
+    // string field = 1;
+
+
+    pub fn get_field(&self) -> &str {
+        &self.field
+    }
+    pub fn clear_field(&mut self) {
+        self.field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field(&mut self, v: ::std::string::String) {
+        self.field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field(&mut self) -> &mut ::std::string::String {
+        &mut self.field
+    }
+
+    // Take field
+    pub fn take_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field, ::std::string::String::new())
+    }
+
+    // string description = 2;
+
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        &mut self.description
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.description, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BadRequest_FieldViolation {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.description)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.description);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field.is_empty() {
+            os.write_string(1, &self.field)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(2, &self.description)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BadRequest_FieldViolation {
+        BadRequest_FieldViolation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field",
+                |m: &BadRequest_FieldViolation| { &m.field },
+                |m: &mut BadRequest_FieldViolation| { &mut m.field },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "description",
+                |m: &BadRequest_FieldViolation| { &m.description },
+                |m: &mut BadRequest_FieldViolation| { &mut m.description },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BadRequest_FieldViolation>(
+                "BadRequest.FieldViolation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BadRequest_FieldViolation {
+        static instance: ::protobuf::rt::LazyV2<BadRequest_FieldViolation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BadRequest_FieldViolation::new)
+    }
+}
+
+impl ::protobuf::Clear for BadRequest_FieldViolation {
+    fn clear(&mut self) {
+        self.field.clear();
+        self.description.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BadRequest_FieldViolation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BadRequest_FieldViolation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1egoogle/rpc/error_details.proto\x12\ngoogle.rpc\x1a\x1egoogle/proto\
+    buf/duration.proto\"G\n\tRetryInfo\x12:\n\x0bretry_delay\x18\x01\x20\x01\
+    (\x0b2\x19.google.protobuf.DurationR\nretryDelay\"\xb9\x01\n\tErrorInfo\
+    \x12\x16\n\x06reason\x18\x01\x20\x01(\tR\x06reason\x12\x16\n\x06domain\
+    \x18\x02\x20\x01(\tR\x06domain\x12?\n\x08metadata\x18\x03\x20\x03(\x0b2#\
+    .google.rpc.ErrorInfo.MetadataEntryR\x08metadata\x1a;\n\rMetadataEntry\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\
+    \x20\x01(\tR\x05value:\x028\x01\"\xa8\x01\n\nBadRequest\x12P\n\x10field_\
+    violations\x18\x01\x20\x03(\x0b2%.google.rpc.BadRequest.FieldViolationR\
+    \x0ffieldViolations\x1aH\n\x0eFieldViolation\x12\x14\n\x05field\x18\x01\
+    \x20\x01(\tR\x05field\x12\x20\n\x0bdescription\x18\x02\x20\x01(\tR\x0bde\
+    scriptionJ\xcc\x05\n\x06\x12\x04\x02\0\x19\x01\n\xa9\x01\n\x01\x0c\x12\
+    \x03\x02\0\x12\x1a\x9e\x01\x20The\x20google.rpc\x20error\x20details\x20t\
+    he\x20server\x20sends,\x20from\x20googleapis\n\x20(https://github.com/go\
+    ogleapis/googleapis),\x20Apache\x202.0.\x20The\x20other\x20detail\x20typ\
+    es\x20are\x20left\x20out.\n\n\x08\n\x01\x02\x12\x03\x04\0\x13\n\t\n\x02\
+    \x03\0\x12\x03\x06\0(\n\n\n\x02\x04\0\x12\x04\x08\0\n\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x08\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\t\x02+\n\x0c\
+    \n\x05\x04\0\x02\0\x06\x12\x03\t\x02\x1a\n\x0c\n\x05\x04\0\x02\0\x01\x12\
+    \x03\t\x1b&\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\t)*\n\n\n\x02\x04\x01\
+    \x12\x04\x0c\0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0c\x08\x11\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\r\x02\x14\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\r\x02\x08\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\t\x0f\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\r\x12\x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\
+    \x0e\x02\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0e\x02\x08\n\x0c\n\
+    \x05\x04\x01\x02\x01\x01\x12\x03\x0e\t\x0f\n\x0c\n\x05\x04\x01\x02\x01\
+    \x03\x12\x03\x0e\x12\x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0f\x02#\n\
+    \x0c\n\x05\x04\x01\x02\x02\x06\x12\x03\x0f\x02\x15\n\x0c\n\x05\x04\x01\
+    \x02\x02\x01\x12\x03\x0f\x16\x1e\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\
+    \x0f!\"\n\n\n\x02\x04\x02\x12\x04\x12\0\x19\x01\n\n\n\x03\x04\x02\x01\
+    \x12\x03\x12\x08\x12\n\x0c\n\x04\x04\x02\x03\0\x12\x04\x13\x02\x16\x03\n\
+    \x0c\n\x05\x04\x02\x03\0\x01\x12\x03\x13\n\x18\n\r\n\x06\x04\x02\x03\0\
+    \x02\0\x12\x03\x14\x04\x15\n\x0e\n\x07\x04\x02\x03\0\x02\0\x05\x12\x03\
+    \x14\x04\n\n\x0e\n\x07\x04\x02\x03\0\x02\0\x01\x12\x03\x14\x0b\x10\n\x0e\
+    \n\x07\x04\x02\x03\0\x02\0\x03\x12\x03\x14\x13\x14\n\r\n\x06\x04\x02\x03\
+    \0\x02\x01\x12\x03\x15\x04\x1b\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x05\x12\
+    \x03\x15\x04\n\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x01\x12\x03\x15\x0b\x16\
+    \n\x0e\n\x07\x04\x02\x03\0\x02\x01\x03\x12\x03\x15\x19\x1a\n\x0b\n\x04\
+    \x04\x02\x02\0\x12\x03\x18\x02/\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\
+    \x18\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x18\x0b\x19\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x18\x1a*\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x18-.b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -1,2 +1,4 @@
 pub mod accounting;
 pub mod accounting_grpc;
+pub mod error_details;
+pub mod status;
@@ -0,0 +1,318 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, LineWriter, Write};
+use std::sync::{Arc, Mutex};
+
+use grpcio::RpcStatusCode;
+use protobuf::RepeatedField;
+use serde_json::{json, Map, Value};
+
+use crate::now_millis;
+use crate::proto::accounting::{
+    CreateAccountRequest, CreateAccountResponse, GetBalanceRequest, GetBalanceResponse,
+    TransferComponent, TransferRequest, TransferResponse,
+};
+
+// Proto3 JSON mapping for the recorded messages: lowerCamelCase names, 64 bit integers as
+// strings. rust-protobuf 2.3 has no JSON support so the mapping is written out by hand.
+pub trait JsonMessage: Sized {
+    fn to_json(&self) -> Value;
+    fn from_json(json: &Value) -> Result<Self, String>;
+}
+
+fn field<'a>(json: &'a Value, name: &str) -> Option<&'a Value> {
+    json.as_object().and_then(|object| object.get(name)).filter(|value| !value.is_null())
+}
+
+// 64 bit integers are strings in proto3 JSON but numbers are accepted as well
+fn int64(json: &Value, name: &str) -> Result<i64, String> {
+    let value = match field(json, name) {
+        Some(value) => value,
+        None => return Ok(0),
+    };
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+        .ok_or_else(|| format!("Field {} Is Not An Integer: {}", name, value))
+}
+
+fn uint64(json: &Value, name: &str) -> Result<u64, String> {
+    let value = match field(json, name) {
+        Some(value) => value,
+        None => return Ok(0),
+    };
+    value
+        .as_u64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+        .ok_or_else(|| format!("Field {} Is Not An Unsigned Integer: {}", name, value))
+}
+
+fn uint32(json: &Value, name: &str) -> Result<u32, String> {
+    let value = uint64(json, name)?;
+    if value > 0xffff_ffff {
+        return Err(format!("Field {} Is Out Of Range: {}", name, value));
+    }
+    Ok(value as u32)
+}
+
+impl JsonMessage for CreateAccountRequest {
+    fn to_json(&self) -> Value {
+        json!({
+            "reqId": self.get_req_id().to_string(),
+            "accountId": self.get_account_id(),
+            "balance": self.get_balance().to_string(),
+        })
+    }
+
+    fn from_json(json: &Value) -> Result<CreateAccountRequest, String> {
+        let mut req = CreateAccountRequest::new();
+        req.set_req_id(uint64(json, "reqId")?);
+        req.set_account_id(uint32(json, "accountId")?);
+        req.set_balance(int64(json, "balance")?);
+        Ok(req)
+    }
+}
+
+impl JsonMessage for CreateAccountResponse {
+    fn to_json(&self) -> Value {
+        json!({
+            "reqId": self.get_req_id().to_string(),
+            "accountId": self.get_account_id(),
+        })
+    }
+
+    fn from_json(json: &Value) -> Result<CreateAccountResponse, String> {
+        let mut reply = CreateAccountResponse::new();
+        reply.set_req_id(uint64(json, "reqId")?);
+        reply.set_account_id(uint32(json, "accountId")?);
+        Ok(reply)
+    }
+}
+
+impl JsonMessage for GetBalanceRequest {
+    fn to_json(&self) -> Value {
+        json!({
+            "reqId": self.get_req_id().to_string(),
+            "accountId": self.get_account_id(),
+        })
+    }
+
+    fn from_json(json: &Value) -> Result<GetBalanceRequest, String> {
+        let mut req = GetBalanceRequest::new();
+        req.set_req_id(uint64(json, "reqId")?);
+        req.set_account_id(uint32(json, "accountId")?);
+        Ok(req)
+    }
+}
+
+impl JsonMessage for GetBalanceResponse {
+    fn to_json(&self) -> Value {
+        json!({
+            "reqId": self.get_req_id().to_string(),
+            "accountId": self.get_account_id(),
+            "balance": self.get_balance().to_string(),
+            "availableBalance": self.get_available_balance().to_string(),
+        })
+    }
+
+    fn from_json(json: &Value) -> Result<GetBalanceResponse, String> {
+        let mut reply = GetBalanceResponse::new();
+        reply.set_req_id(uint64(json, "reqId")?);
+        reply.set_account_id(uint32(json, "accountId")?);
+        reply.set_balance(int64(json, "balance")?);
+        reply.set_available_balance(int64(json, "availableBalance")?);
+        Ok(reply)
+    }
+}
+
+impl JsonMessage for TransferComponent {
+    fn to_json(&self) -> Value {
+        json!({
+            "accountId": self.get_account_id(),
+            "moneyDelta": self.get_money_delta().to_string(),
+        })
+    }
+
+    fn from_json(json: &Value) -> Result<TransferComponent, String> {
+        let mut comp = TransferComponent::new();
+        comp.set_account_id(uint32(json, "accountId")?);
+        comp.set_money_delta(int64(json, "moneyDelta")?);
+        Ok(comp)
+    }
+}
+
+impl JsonMessage for TransferRequest {
+    fn to_json(&self) -> Value {
+        let components: Vec<Value> = self.get_components().iter().map(|c| c.to_json()).collect();
+        json!({
+            "reqId": self.get_req_id().to_string(),
+            "components": components,
+        })
+    }
+
+    fn from_json(json: &Value) -> Result<TransferRequest, String> {
+        let components = match field(json, "components") {
+            Some(Value::Array(components)) => components
+                .iter()
+                .map(TransferComponent::from_json)
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(value) => return Err(format!("Field components Is Not A List: {}", value)),
+            None => Vec::new(),
+        };
+        let mut req = TransferRequest::new();
+        req.set_req_id(uint64(json, "reqId")?);
+        req.set_components(RepeatedField::from_vec(components));
+        Ok(req)
+    }
+}
+
+impl JsonMessage for TransferResponse {
+    fn to_json(&self) -> Value {
+        json!({ "reqId": self.get_req_id().to_string() })
+    }
+
+    fn from_json(json: &Value) -> Result<TransferResponse, String> {
+        let mut reply = TransferResponse::new();
+        reply.set_req_id(uint64(json, "reqId")?);
+        Ok(reply)
+    }
+}
+
+// Status a recorded call failed with
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl RecordedError {
+    pub fn new(code: RpcStatusCode, message: &str) -> RecordedError {
+        RecordedError {
+            code: code.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+// One line of a recording
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedCall {
+    // milliseconds since the unix epoch when the call finished
+    pub at: i64,
+    pub method: String,
+    pub request: Value,
+    pub response: Result<Value, RecordedError>,
+}
+
+impl RecordedCall {
+    pub fn to_line(&self) -> String {
+        let mut line = Map::new();
+        line.insert("at".to_string(), json!(self.at));
+        line.insert("method".to_string(), json!(self.method));
+        line.insert("request".to_string(), self.request.clone());
+        match self.response {
+            Ok(ref response) => line.insert("response".to_string(), response.clone()),
+            Err(ref err) => line.insert(
+                "error".to_string(),
+                json!({"code": err.code, "message": err.message}),
+            ),
+        };
+        Value::Object(line).to_string()
+    }
+
+    pub fn parse_line(line: &str) -> Result<RecordedCall, String> {
+        let json: Value = serde_json::from_str(line).map_err(|e| e.to_string())?;
+        let method = match field(&json, "method").and_then(Value::as_str) {
+            Some(method) => method.to_string(),
+            None => return Err("Missing method".to_string()),
+        };
+        let request = field(&json, "request").cloned().unwrap_or_else(|| json!({}));
+        let response = match (field(&json, "response"), field(&json, "error")) {
+            (Some(response), None) => Ok(response.clone()),
+            (None, Some(err)) => Err(RecordedError {
+                code: int64(err, "code")? as i32,
+                message: field(err, "message").and_then(Value::as_str).unwrap_or("").to_string(),
+            }),
+            _ => return Err("Expected Exactly One Of response Or error".to_string()),
+        };
+        Ok(RecordedCall {
+            at: int64(&json, "at")?,
+            method,
+            request,
+            response,
+        })
+    }
+}
+
+// Appends every recorded call to a JSONL file, one line per call
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    out: Arc<Mutex<LineWriter<File>>>,
+}
+
+impl Recorder {
+    pub fn open(path: &str) -> io::Result<Recorder> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Recorder {
+            out: Arc::new(Mutex::new(LineWriter::new(file))),
+        })
+    }
+
+    pub fn record<Req, Resp>(&self, method: &str, req: &Req, res: Result<&Resp, RecordedError>)
+    where
+        Req: JsonMessage,
+        Resp: JsonMessage,
+    {
+        let call = RecordedCall {
+            at: now_millis(),
+            method: method.to_string(),
+            request: req.to_json(),
+            response: res.map(JsonMessage::to_json),
+        };
+        let mut out = self.out.lock().unwrap();
+        if let Err(e) = writeln!(out, "{}", call.to_line()) {
+            println!("failed to record {}: {:?}", method, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let mut req = TransferRequest::new();
+        req.set_req_id(1 << 63);
+        for &(account, delta) in &[(1, -50), (2, 50)] {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(account);
+            comp.set_money_delta(delta);
+            req.mut_components().push(comp);
+        }
+        let json = req.to_json();
+        assert_eq!(json["reqId"], "9223372036854775808");
+        assert_eq!(json["components"][0]["moneyDelta"], "-50");
+        assert_eq!(TransferRequest::from_json(&json).unwrap(), req);
+
+        // numbers and missing fields are accepted
+        let req = CreateAccountRequest::from_json(&json!({"reqId": 7, "accountId": 3})).unwrap();
+        assert_eq!((req.get_req_id(), req.get_account_id(), req.get_balance()), (7, 3, 0));
+        assert!(GetBalanceRequest::from_json(&json!({"accountId": "x"})).is_err());
+    }
+
+    #[test]
+    fn test_recorded_line() {
+        let mut req = GetBalanceRequest::new();
+        req.set_account_id(5);
+        let call = RecordedCall {
+            at: 1_000,
+            method: "GetBalance".to_string(),
+            request: req.to_json(),
+            response: Err(RecordedError::new(RpcStatusCode::NOT_FOUND, "Account 5 Not Found")),
+        };
+        let line = call.to_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(RecordedCall::parse_line(&line).unwrap(), call);
+        assert!(RecordedCall::parse_line("{\"method\": \"Transfer\"}").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::process;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::{App, Arg};
+use grpcio::{ChannelBuilder, EnvBuilder, RpcStatusCode};
+use serde_json::Value;
+
+use accountant::proto::accounting::{
+    CreateAccountRequest, GetBalanceRequest, ResetRequest, TransferRequest,
+};
+use accountant::proto::accounting_grpc::AccountingServiceClient;
+use accountant::recording::{JsonMessage, RecordedCall, RecordedError};
+
+type Response = Result<Value, RecordedError>;
+
+// Sends the recorded request and converts the outcome to the recorded form
+fn call<Req, Resp, F>(request: &Value, send: F) -> Result<Response, String>
+where
+    Req: JsonMessage,
+    Resp: JsonMessage,
+    F: FnOnce(&Req) -> grpcio::Result<Resp>,
+{
+    let req = Req::from_json(request)?;
+    Ok(match send(&req) {
+        Ok(reply) => Ok(reply.to_json()),
+        // recordings keep only the message, not the details
+        Err(grpcio::Error::RpcFailure(status)) => {
+            Err(RecordedError::new(status.code(), status.message()))
+        }
+        Err(err) => Err(RecordedError::new(RpcStatusCode::UNKNOWN, &err.to_string())),
+    })
+}
+
+fn replay(client: &AccountingServiceClient, recorded: &RecordedCall) -> Result<Response, String> {
+    match recorded.method.as_str() {
+        "CreateAccount" => call(&recorded.request, |req: &CreateAccountRequest| {
+            client.create_account(req)
+        }),
+        "GetBalance" => call(&recorded.request, |req: &GetBalanceRequest| {
+            client.get_balance(req)
+        }),
+        "Transfer" => call(&recorded.request, |req: &TransferRequest| client.transfer(req)),
+        method => Err(format!("Unknown Method {}", method)),
+    }
+}
+
+fn describe(response: &Response) -> String {
+    match response {
+        Ok(response) => response.to_string(),
+        Err(err) => format!("error {} {:?}", err.code, err.message),
+    }
+}
+
+fn main() {
+    let matches = App::new("replay")
+        .about("Re-issues a recorded JSONL request log and diffs the responses")
+        .arg(Arg::with_name("file").required(true).help("Recording made with --record-requests"))
+        .arg(
+            Arg::with_name("server")
+                .long("server")
+                .takes_value(true)
+                .default_value("localhost:3000"),
+        )
+        .arg(Arg::with_name("reset").long("reset").help("Reset the server before replaying"))
+        .arg(
+            Arg::with_name("timing")
+                .long("timing")
+                .help("Wait between calls as long as the recording did"),
+        )
+        .arg(
+            Arg::with_name("speed")
+                .long("speed")
+                .takes_value(true)
+                .default_value("1")
+                .help("Speeds up --timing by this factor"),
+        )
+        .get_matches();
+
+    let path = matches.value_of("file").unwrap();
+    let speed: f64 = match matches.value_of("speed").unwrap().parse() {
+        Ok(speed) if speed > 0.0 => speed,
+        _ => {
+            eprintln!("--speed Must Be A Positive Number");
+            process::exit(2);
+        }
+    };
+    let file = File::open(path).unwrap_or_else(|err| {
+        eprintln!("Could Not Open {}: {}", path, err);
+        process::exit(2);
+    });
+
+    let env = Arc::new(EnvBuilder::new().build());
+    let channel = ChannelBuilder::new(env).connect(matches.value_of("server").unwrap());
+    let client = AccountingServiceClient::new(channel);
+    if matches.is_present("reset") {
+        client.reset(&ResetRequest::new()).expect("Database Reset Failed");
+    }
+
+    let start = Instant::now();
+    let mut first_at = None;
+    let (mut replayed, mut differed) = (0, 0);
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.unwrap_or_else(|err| {
+            eprintln!("Could Not Read {}: {}", path, err);
+            process::exit(2);
+        });
+        if line.trim().is_empty() {
+            continue;
+        }
+        let recorded = RecordedCall::parse_line(&line).unwrap_or_else(|err| {
+            eprintln!("line {}: {}", i + 1, err);
+            process::exit(2);
+        });
+
+        if matches.is_present("timing") {
+            let first_at = *first_at.get_or_insert(recorded.at);
+            let offset = (recorded.at - first_at).max(0) as f64 / speed;
+            let due = start + Duration::from_millis(offset as u64);
+            let now = Instant::now();
+            if due > now {
+                thread::sleep(due - now);
+            }
+        }
+
+        let response = replay(&client, &recorded).unwrap_or_else(|err| {
+            eprintln!("line {}: {}", i + 1, err);
+            process::exit(2);
+        });
+        replayed += 1;
+        if response != recorded.response {
+            differed += 1;
+            println!("line {} {} {}", i + 1, recorded.method, recorded.request);
+            println!("  recorded: {}", describe(&recorded.response));
+            println!("  replayed: {}", describe(&response));
+        }
+    }
+
+    println!("{} calls replayed, {} differed", replayed, differed);
+    if differed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,426 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use futures::{Stream, TryStreamExt};
+use grpcio::{CallOption, Channel, ChannelBuilder, EnvBuilder, RpcStatusCode};
+use rand::Rng;
+
+use crate::proto::accounting::{
+    AuthorizeTransferRequest, AuthorizeTransferResponse, CaptureTransferRequest,
+    CaptureTransferResponse, CreateAccountRequest, GetBalanceRequest, GetBalanceResponse,
+    ListTransactionsRequest, ListTransactionsResponse, ResetRequest, ReverseTransferRequest,
+    TransferComponent, TransferRequest, VerifyLedgerRequest, VoidTransferRequest,
+    WatchBalanceRequest,
+};
+use crate::proto::accounting_grpc::AccountingServiceClient;
+use crate::status::{code_name, ErrorDetails};
+use crate::{AccountBalance, LedgerBackfill, LedgerMismatch, LedgerReport};
+
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    // deadline applied to every attempt of a call
+    pub timeout: Duration,
+    // attempts after the first one, only made for retryable errors
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> ClientConfig {
+        ClientConfig {
+            timeout: Duration::from_secs(10),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+// Message of a failed call and the details the server attached to it
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServerError {
+    pub message: String,
+    pub details: ErrorDetails,
+}
+
+impl<'a> From<&'a str> for ServerError {
+    fn from(message: &'a str) -> ServerError {
+        ServerError {
+            message: message.to_string(),
+            details: ErrorDetails::default(),
+        }
+    }
+}
+
+// Server status of a failed call, with the message and details the server sent
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClientError {
+    InvalidArgument(ServerError),
+    NotFound(ServerError),
+    AlreadyExists(ServerError),
+    FailedPrecondition(ServerError),
+    Aborted(ServerError),
+    OutOfRange(ServerError),
+    Unavailable(ServerError),
+    DeadlineExceeded(ServerError),
+    Internal(ServerError),
+    // any other status code
+    Status(RpcStatusCode, ServerError),
+    // the call failed without a status, e.g. a response could not be decoded
+    Transport(String),
+}
+
+impl ClientError {
+    pub fn from_status(code: RpcStatusCode, message: ServerError) -> ClientError {
+        match code {
+            RpcStatusCode::INVALID_ARGUMENT => ClientError::InvalidArgument(message),
+            RpcStatusCode::NOT_FOUND => ClientError::NotFound(message),
+            RpcStatusCode::ALREADY_EXISTS => ClientError::AlreadyExists(message),
+            RpcStatusCode::FAILED_PRECONDITION => ClientError::FailedPrecondition(message),
+            RpcStatusCode::ABORTED => ClientError::Aborted(message),
+            RpcStatusCode::OUT_OF_RANGE => ClientError::OutOfRange(message),
+            RpcStatusCode::UNAVAILABLE => ClientError::Unavailable(message),
+            RpcStatusCode::DEADLINE_EXCEEDED => ClientError::DeadlineExceeded(message),
+            RpcStatusCode::INTERNAL => ClientError::Internal(message),
+            code => ClientError::Status(code, message),
+        }
+    }
+
+    pub fn code(&self) -> RpcStatusCode {
+        match self {
+            ClientError::InvalidArgument(_) => RpcStatusCode::INVALID_ARGUMENT,
+            ClientError::NotFound(_) => RpcStatusCode::NOT_FOUND,
+            ClientError::AlreadyExists(_) => RpcStatusCode::ALREADY_EXISTS,
+            ClientError::FailedPrecondition(_) => RpcStatusCode::FAILED_PRECONDITION,
+            ClientError::Aborted(_) => RpcStatusCode::ABORTED,
+            ClientError::OutOfRange(_) => RpcStatusCode::OUT_OF_RANGE,
+            ClientError::Unavailable(_) => RpcStatusCode::UNAVAILABLE,
+            ClientError::DeadlineExceeded(_) => RpcStatusCode::DEADLINE_EXCEEDED,
+            ClientError::Internal(_) => RpcStatusCode::INTERNAL,
+            ClientError::Status(code, _) => *code,
+            ClientError::Transport(_) => RpcStatusCode::UNKNOWN,
+        }
+    }
+
+    fn server_error(&self) -> Option<&ServerError> {
+        match self {
+            ClientError::InvalidArgument(err)
+            | ClientError::NotFound(err)
+            | ClientError::AlreadyExists(err)
+            | ClientError::FailedPrecondition(err)
+            | ClientError::Aborted(err)
+            | ClientError::OutOfRange(err)
+            | ClientError::Unavailable(err)
+            | ClientError::DeadlineExceeded(err)
+            | ClientError::Internal(err)
+            | ClientError::Status(_, err) => Some(err),
+            ClientError::Transport(_) => None,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ClientError::Transport(message) => message,
+            err => err.server_error().map_or("", |err| err.message.as_str()),
+        }
+    }
+
+    // ErrorInfo, RetryInfo and BadRequest details, None if the call failed without a status
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        self.server_error().map(|err| &err.details)
+    }
+
+    // ErrorInfo.reason, e.g. INSUFFICIENT_FUNDS
+    pub fn reason(&self) -> Option<&str> {
+        self.details().map(|details| details.reason.as_str()).filter(|r| !r.is_empty())
+    }
+
+    // The request is resent with the same req_id, so the server applies it at most once
+    pub fn is_retryable(&self) -> bool {
+        let code = self.code();
+        code == RpcStatusCode::ABORTED || code == RpcStatusCode::UNAVAILABLE
+    }
+}
+
+impl From<grpcio::Error> for ClientError {
+    fn from(err: grpcio::Error) -> ClientError {
+        match err {
+            grpcio::Error::RpcFailure(status) => {
+                let message = ServerError {
+                    message: status.message().to_string(),
+                    details: ErrorDetails::decode(status.details()),
+                };
+                ClientError::from_status(status.code(), message)
+            }
+            err => ClientError::Transport(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", code_name(self.code()), self.message())
+    }
+}
+
+impl Error for ClientError {}
+
+pub type ClientResult<T> = Result<T, ClientError>;
+
+// Random ids so separate processes and restarts never reuse one, 0 is left unused
+pub fn new_req_id() -> u64 {
+    loop {
+        let req_id = rand::random::<u64>();
+        if req_id != 0 {
+            return req_id;
+        }
+    }
+}
+
+// Typed client for the accounting service. Every call gets a new req_id and is retried on
+// UNAVAILABLE or ABORTED with the same req_id.
+#[derive(Clone)]
+pub struct AccountingClient {
+    client: AccountingServiceClient,
+    config: ClientConfig,
+}
+
+impl AccountingClient {
+    pub fn connect(addr: &str) -> AccountingClient {
+        AccountingClient::connect_with_config(addr, ClientConfig::default())
+    }
+
+    pub fn connect_with_config(addr: &str, config: ClientConfig) -> AccountingClient {
+        let env = Arc::new(EnvBuilder::new().build());
+        let channel = ChannelBuilder::new(env).connect(addr);
+        AccountingClient::new(channel, config)
+    }
+
+    pub fn new(channel: Channel, config: ClientConfig) -> AccountingClient {
+        AccountingClient {
+            client: AccountingServiceClient::new(channel),
+            config,
+        }
+    }
+
+    pub fn create_account(&self, account: u32, balance: i64) -> ClientResult<()> {
+        let mut req = CreateAccountRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_account_id(account);
+        req.set_balance(balance);
+        self.call(&req, AccountingServiceClient::create_account_opt)?;
+        Ok(())
+    }
+
+    pub fn balance(&self, account: u32) -> ClientResult<AccountBalance> {
+        let mut req = GetBalanceRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_account_id(account);
+        let reply = self.call(&req, AccountingServiceClient::get_balance_opt)?;
+        Ok(AccountBalance {
+            ledger: reply.get_balance(),
+            available: reply.get_available_balance(),
+        })
+    }
+
+    // Applies (account, delta) legs atomically and returns the req_id they were recorded under
+    pub fn transfer(&self, legs: &[(u32, i64)]) -> ClientResult<u64> {
+        let mut req = TransferRequest::new();
+        req.set_req_id(new_req_id());
+        for &(account, delta) in legs {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(account);
+            comp.set_money_delta(delta);
+            req.mut_components().push(comp);
+        }
+        let reply = self.call(&req, AccountingServiceClient::transfer_opt)?;
+        Ok(reply.get_req_id())
+    }
+
+    pub fn authorize(
+        &self,
+        from: u32,
+        to: u32,
+        amount: i64,
+    ) -> ClientResult<AuthorizeTransferResponse> {
+        let mut req = AuthorizeTransferRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_from_account_id(from);
+        req.set_to_account_id(to);
+        req.set_amount(amount);
+        self.call(&req, AccountingServiceClient::authorize_transfer_opt)
+    }
+
+    // amount None captures everything the hold reserved
+    pub fn capture(
+        &self,
+        hold_id: u64,
+        amount: Option<i64>,
+    ) -> ClientResult<CaptureTransferResponse> {
+        let mut req = CaptureTransferRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_hold_id(hold_id);
+        req.set_amount(amount.unwrap_or(0));
+        self.call(&req, AccountingServiceClient::capture_transfer_opt)
+    }
+
+    pub fn void(&self, hold_id: u64) -> ClientResult<()> {
+        let mut req = VoidTransferRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_hold_id(hold_id);
+        self.call(&req, AccountingServiceClient::void_transfer_opt)?;
+        Ok(())
+    }
+
+    // Undoes the transfer recorded under original_req_id, returns the reversal's req_id
+    pub fn reverse(&self, original_req_id: u64) -> ClientResult<u64> {
+        let mut req = ReverseTransferRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_original_req_id(original_req_id);
+        let reply = self.call(&req, AccountingServiceClient::reverse_transfer_opt)?;
+        Ok(reply.get_req_id())
+    }
+
+    // Filters and paging are taken from `req`, its req_id is replaced
+    pub fn list_transactions(
+        &self,
+        mut req: ListTransactionsRequest,
+    ) -> ClientResult<ListTransactionsResponse> {
+        req.set_req_id(new_req_id());
+        self.call(&req, AccountingServiceClient::list_transactions_opt)
+    }
+
+    pub fn reset(&self) -> ClientResult<()> {
+        self.call(&ResetRequest::new(), AccountingServiceClient::reset_opt)?;
+        Ok(())
+    }
+
+    // Reports the accounts whose balance isn't the sum of their ledger entries, see
+    // DataStore::verify_ledger. A rebuild is safe to retry: a second one finds nothing.
+    pub fn verify_ledger(&self, rebuild: bool) -> ClientResult<LedgerReport> {
+        let mut req = VerifyLedgerRequest::new();
+        req.set_rebuild(rebuild);
+        let reply = self.call(&req, AccountingServiceClient::verify_ledger_opt)?;
+        let mismatches = reply
+            .get_mismatches()
+            .iter()
+            .map(|mismatch| LedgerMismatch {
+                account_id: mismatch.get_account_id(),
+                stored: mismatch.get_stored_balance(),
+                projected: mismatch.get_ledger_balance(),
+            })
+            .collect();
+        let backfilled = reply
+            .get_backfilled()
+            .iter()
+            .map(|backfill| LedgerBackfill {
+                account_id: backfill.get_account_id(),
+                amount: backfill.get_amount(),
+            })
+            .collect();
+        Ok(LedgerReport { accounts: reply.get_accounts_checked(), mismatches, backfilled })
+    }
+
+    // Streams balance updates of the accounts, starting with their current balances. The
+    // stream is not retried and has no deadline.
+    pub fn watch_balance(
+        &self,
+        accounts: &[u32],
+    ) -> ClientResult<impl Stream<Item = ClientResult<GetBalanceResponse>>> {
+        let mut req = WatchBalanceRequest::new();
+        req.set_req_id(new_req_id());
+        req.set_account_ids(accounts.to_vec());
+        let stream = self.client.watch_balance(&req)?;
+        Ok(stream.map_err(ClientError::from))
+    }
+
+    fn call<Req, Resp, F>(&self, req: &Req, call: F) -> ClientResult<Resp>
+    where
+        F: Fn(&AccountingServiceClient, &Req, CallOption) -> grpcio::Result<Resp>,
+    {
+        let mut backoff = self.config.initial_backoff;
+        let mut retries = 0;
+        loop {
+            let opt = CallOption::default().timeout(self.config.timeout);
+            let err = match call(&self.client, req, opt) {
+                Ok(reply) => return Ok(reply),
+                Err(err) => ClientError::from(err),
+            };
+            if !err.is_retryable() || retries >= self.config.max_retries {
+                return Err(err);
+            }
+            retries += 1;
+            // never sooner than the server's RetryInfo asks for
+            let retry_delay = err.details().and_then(|details| details.retry_delay);
+            thread::sleep(cmp::max(jitter(backoff), retry_delay.unwrap_or_default()));
+            backoff = (backoff * 2).min(self.config.max_backoff);
+        }
+    }
+}
+
+// Somewhere between half and all of the backoff, so clients that failed together spread out
+fn jitter(backoff: Duration) -> Duration {
+    let millis = backoff.as_secs() * 1_000 + u64::from(backoff.subsec_millis());
+    if millis < 2 {
+        return backoff;
+    }
+    Duration::from_millis(rand::thread_rng().gen_range(millis / 2, millis + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use grpcio::RpcStatus;
+
+    use super::*;
+    use crate::status::ErrorStatus;
+    use crate::DataStoreError;
+
+    #[test]
+    fn test_decode_status() {
+        let message = "Account 5 Not Found".to_string();
+        let status = RpcStatus::with_message(RpcStatusCode::NOT_FOUND, message);
+        let err = ClientError::from(grpcio::Error::RpcFailure(status));
+        assert_eq!(err, ClientError::NotFound("Account 5 Not Found".into()));
+        assert_eq!(err.code(), RpcStatusCode::NOT_FOUND);
+        assert_eq!(err.reason(), None);
+        assert!(!err.is_retryable());
+
+        let status = RpcStatus::new(RpcStatusCode::UNAVAILABLE);
+        let err = ClientError::from(grpcio::Error::RpcFailure(status));
+        assert_eq!(err, ClientError::Unavailable(ServerError::default()));
+        assert!(err.is_retryable());
+
+        let err = ClientError::from_status(RpcStatusCode::PERMISSION_DENIED, "no".into());
+        assert_eq!(err.code(), RpcStatusCode::PERMISSION_DENIED);
+        assert_eq!(err.to_string(), "PermissionDenied: no");
+        assert_eq!(ClientError::Transport("closed".to_string()).details(), None);
+    }
+
+    #[test]
+    fn test_decode_details() {
+        let mut status = ErrorStatus::from(DataStoreError::Contention(10));
+        status.details.retry_delay = Some(Duration::from_millis(500));
+        let err = ClientError::from(grpcio::Error::RpcFailure(status.to_rpc_status()));
+        assert_eq!(err.message(), "Too Much Contention After 10 Attempts, Retry Later");
+        assert_eq!(err.reason(), Some("CONTENTION"));
+        let details = err.details().unwrap();
+        assert_eq!(details.get_i64("attempts"), Some(10));
+        assert_eq!(details.retry_delay, Some(Duration::from_millis(500)));
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_jitter() {
+        for _ in 0..100 {
+            let delay = jitter(Duration::from_millis(100));
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+        assert_eq!(jitter(Duration::from_millis(1)), Duration::from_millis(1));
+        assert_ne!(new_req_id(), 0);
+    }
+}
@@ -1,49 +1,194 @@
-use std::io::Read;
+use std::fs;
 use std::sync::Arc;
-use std::{io, thread};
+use std::process;
+use std::thread;
 
-use futures::sync::oneshot;
-use futures::Future;
-use grpcio::{Environment, ServerBuilder, ChannelBuilder};
+use futures::executor;
+use grpcio::{ChannelArgs, Environment, ServerBuilder, ChannelBuilder};
+use signal_hook::iterator::Signals;
+use signal_hook::{SIGINT, SIGTERM};
 
+use accountant::config::{ServerCommand, ServerConfig};
 use accountant::proto::accounting_grpc;
-use accountant::GrpcAccountingService;
-use accountant::db::PostgresDataStore;
+use accountant::recording::Recorder;
+use accountant::{DataStore, GrpcAccountingService, ServiceConfig};
+use accountant::db::migrations;
+use accountant::db::{Durability, FileLedgerDataStore, PostgresDataStore, RetryMetrics};
+#[cfg(feature = "sqlite")]
+use accountant::db::SqliteDataStore;
+
+// Replaces the file in one rename so a collector never reads half of it
+fn write_metrics(path: &str, metrics: &RetryMetrics) {
+    let tmp = format!("{}.tmp", path);
+    let res = fs::write(&tmp, metrics.snapshot().to_prometheus())
+        .and_then(|_| fs::rename(&tmp, path));
+    if let Err(err) = res {
+        println!("failed to write metrics to {}: {}", path, err);
+    }
+}
 
 fn main() {
-    let env = Arc::new(Environment::new(4));
+    let config = ServerConfig::load().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(2);
+    });
+
+    let env = Arc::new(Environment::new(config.grpc_threads));
 
     let channel_args = ChannelBuilder::new(Arc::clone(&env))
-        .stream_initial_window_size(2 * 1024 * 1024)
-        .max_concurrent_stream(1024)
-        .max_send_message_len(32 * 1024 * 1024)
-        .max_receive_message_len(32 * 1024 * 1024)
+        .stream_initial_window_size(config.stream_window_size)
+        .max_concurrent_stream(config.max_concurrent_streams)
+        .max_send_message_len(config.max_send_message_len)
+        .max_receive_message_len(config.max_receive_message_len)
         .build_args();
 
-    let service = accounting_grpc::create_accounting_service(
-        GrpcAccountingService::new(
-            PostgresDataStore::new("postgresql://accountant@localhost:26257/bank"))
-    );
+    #[cfg(feature = "sqlite")]
+    {
+        if let Some(path) = config.sqlite_path() {
+            let store = SqliteDataStore::open(path)
+                .unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                })
+                .with_retry_policy(config.retry_policy());
+            println!("Using SQLite database {}", path);
+            let metrics = store.retry_metrics();
+            serve(&config, env, channel_args, store, metrics);
+            println!("Server stopped");
+            return;
+        }
+    }
+
+    if let Some(path) = config.ledger_path() {
+        let store = FileLedgerDataStore::open(path)
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            })
+            .with_durability(config.ledger_durability())
+            .with_snapshot_every(config.ledger_snapshot_records);
+        println!("Using file ledger {}", path);
+        if let Durability::Interval(interval) = config.ledger_durability() {
+            // appends get synced within the interval even if no commit follows them; once a
+            // sync fails the store refuses writes, and they report it
+            let store = store.clone();
+            thread::spawn(move || {
+                while store.sync().is_ok() {
+                    thread::sleep(interval);
+                }
+            });
+        }
+        serve(&config, env, channel_args, store.clone(), RetryMetrics::new());
+        if let Err(err) = store.sync() {
+            println!("Could not sync the ledger: {}", err);
+        }
+        println!("Server stopped");
+        return;
+    }
+
+    let mut store = PostgresDataStore::with_pool_config(&config.database_url, &config.pool())
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        })
+        .with_retry_policy(config.retry_policy())
+        .with_batched_transfers(config.batch_transfers);
+    // a schema newer than this server knows stops it here
+    let applied = store.migrate().unwrap_or_else(|err| {
+        eprintln!("Could Not Migrate Database: {}", err);
+        process::exit(1);
+    });
+    for migration in applied {
+        println!("Applied migration {} {}", migration.version, migration.name);
+    }
+    println!("Schema at version {}", migrations::latest_version());
+    if config.command == ServerCommand::Migrate {
+        return;
+    }
+    serve(&config, env, channel_args, store.clone(), store.retry_metrics());
+    println!("Closing database pool with {} connections", store.connections());
+    drop(store);
+    println!("Server stopped");
+}
+
+// Runs the service over `store` until SIGTERM or SIGINT and the drain that follows
+fn serve<T>(
+    config: &ServerConfig,
+    env: Arc<Environment>,
+    channel_args: ChannelArgs,
+    store: T,
+    metrics: RetryMetrics,
+) where
+    T: 'static + DataStore + Clone + Send + Sync,
+{
+    if !config.metrics_file.is_empty() {
+        let (path, interval) = (config.metrics_file.clone(), config.metrics_interval());
+        let metrics = metrics.clone();
+        thread::spawn(move || loop {
+            write_metrics(&path, &metrics);
+            thread::sleep(interval);
+        });
+    }
+    let mut service_config = ServiceConfig {
+        idempotency: config.idempotency(),
+        hold_ttl: config.hold_ttl(),
+        limits: config.request_limits(),
+        // clients wait at least as long as the server's own longest backoff
+        retry_delay: config.retry_policy().max_backoff,
+        ..ServiceConfig::default()
+    };
+    if !config.record_requests.is_empty() {
+        let recorder = Recorder::open(&config.record_requests).unwrap_or_else(|err| {
+            eprintln!("Could Not Open {}: {}", config.record_requests, err);
+            process::exit(1);
+        });
+        println!("Recording requests to {}", config.record_requests);
+        service_config.recorder = Some(recorder);
+    }
+    let service = GrpcAccountingService::with_config(store, service_config);
+    let shutdown = service.shutdown_handle();
+    let service = accounting_grpc::create_accounting_service(service);
+
+    // registered before the server starts so an early signal is not missed
+    let signals = Signals::new(&[SIGTERM, SIGINT]).unwrap_or_else(|err| {
+        eprintln!("Could Not Register Signal Handlers: {}", err);
+        process::exit(1);
+    });
 
     let mut server = ServerBuilder::new(env)
         .register_service(service)
-        .bind("0.0.0.0", 3000)
+        .bind(config.host.as_str(), config.port)
         .channel_args(channel_args)
         .build()
-        .unwrap();
+        .unwrap_or_else(|err| {
+            eprintln!("Could Not Start Server: {}", err);
+            process::exit(1);
+        });
 
     server.start();
-    for &(ref host, port) in server.bind_addrs() {
+    for (host, port) in server.bind_addrs() {
         println!("Server listening on {}:{}", host, port);
     }
 
-    let (tx, rx) = oneshot::channel();
-    thread::spawn(move || {
-        println!("Press ENTER to exit...");
-        let _ = io::stdin().read(&mut [0]).unwrap();
-        tx.send(())
-    });
+    if let Some(signal) = signals.forever().next() {
+        println!(
+            "Received signal {}, waiting up to {}s for in-flight requests",
+            signal, config.shutdown_grace_secs
+        );
+    }
 
-    let _ = rx.wait();
-    let _ = server.shutdown().wait();
+    let report = shutdown.shutdown(config.shutdown_grace());
+    println!("Drained {} in-flight requests", report.drained);
+    if report.aborted > 0 {
+        println!("Aborting {} requests still running after the grace period", report.aborted);
+        server.cancel_all_calls();
+    }
+    let _ = executor::block_on(server.shutdown());
+    drop(server);
+
+    let retries = metrics.snapshot();
+    println!(
+        "Ran {} transactions with {} retries, {} gave up on contention",
+        retries.transactions, retries.retries, retries.exhausted
+    );
 }
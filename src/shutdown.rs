@@ -0,0 +1,134 @@
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::watch::BalanceWatchers;
+
+#[derive(Default)]
+struct DrainState {
+    draining: bool,
+    in_flight: usize,
+}
+
+// Counts the RPCs being executed so shutdown can refuse new ones and wait for the rest
+#[derive(Clone, Default)]
+pub struct Drain {
+    state: Arc<(Mutex<DrainState>, Condvar)>,
+}
+
+// Held for the duration of one RPC
+pub struct InFlight {
+    drain: Drain,
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let mut state = self.drain.lock();
+        state.in_flight -= 1;
+        if state.in_flight == 0 {
+            (self.drain.state).1.notify_all();
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DrainReport {
+    // requests that were running when shutdown began and finished within the grace period
+    pub drained: usize,
+    // requests still running when the grace period ran out
+    pub aborted: usize,
+}
+
+impl Drain {
+    pub fn new() -> Drain {
+        Drain::default()
+    }
+
+    // Returns None once shutdown has begun
+    pub fn begin(&self) -> Option<InFlight> {
+        let mut state = self.lock();
+        if state.draining {
+            return None;
+        }
+        state.in_flight += 1;
+        Some(InFlight { drain: self.clone() })
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.lock().in_flight
+    }
+
+    // Stops accepting requests and waits up to `grace` for the running ones to finish
+    pub fn drain(&self, grace: Duration) -> DrainReport {
+        let deadline = Instant::now() + grace;
+        let mut state = self.lock();
+        state.draining = true;
+        let running = state.in_flight;
+
+        while state.in_flight > 0 {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            state = (self.state).1.wait_timeout(state, deadline - now).unwrap().0;
+        }
+        DrainReport {
+            drained: running - state.in_flight,
+            aborted: state.in_flight,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, DrainState> {
+        (self.state).0.lock().unwrap()
+    }
+}
+
+// Lets the server binary shut down a service after it was handed to grpcio
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    pub(crate) drain: Drain,
+    pub(crate) watchers: BalanceWatchers,
+}
+
+impl ShutdownHandle {
+    // Drains unary requests, then ends every WatchBalance stream so they don't hold up
+    // the grpcio shutdown
+    pub fn shutdown(&self, grace: Duration) -> DrainReport {
+        let report = self.drain.drain(grace);
+        self.watchers.clear();
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_refuses_after_drain() {
+        let drain = Drain::new();
+        let report = drain.drain(Duration::from_millis(10));
+        assert_eq!(report, DrainReport { drained: 0, aborted: 0 });
+        assert!(drain.begin().is_none());
+    }
+
+    #[test]
+    fn test_waits_for_in_flight() {
+        let drain = Drain::new();
+        let fast = drain.begin().unwrap();
+        let slow = drain.begin().unwrap();
+        assert_eq!(drain.in_flight(), 2);
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(fast);
+        });
+        let report = drain.drain(Duration::from_millis(200));
+        handle.join().unwrap();
+        assert_eq!(report, DrainReport { drained: 1, aborted: 1 });
+
+        drop(slow);
+        assert_eq!(drain.in_flight(), 0);
+    }
+}
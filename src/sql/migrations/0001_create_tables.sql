@@ -0,0 +1,35 @@
+-- Ids are BIGINT like the parameters the server binds, Postgres refuses an INT8 parameter
+-- for an INT4 column
+CREATE TABLE IF NOT EXISTS accounts (
+    id BIGINT PRIMARY KEY,
+    balance BIGINT,
+    created_at TIMESTAMP DEFAULT now(),
+    creation_request BIGINT,
+    CONSTRAINT balance_check CHECK (balance >= 0)
+);
+
+-- BIGSERIAL is a sequence on Postgres and unique_rowid() on CockroachDB
+CREATE TABLE IF NOT EXISTS transactions (
+    id BIGSERIAL PRIMARY KEY,
+    transaction_index BIGINT,
+    req_id BIGINT,
+    account_id BIGINT,
+    amount BIGINT,
+    created_at TIMESTAMP DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS holds (
+    id BIGINT PRIMARY KEY,
+    account_id BIGINT,
+    to_account_id BIGINT,
+    amount BIGINT,
+    captured BIGINT DEFAULT 0,
+    status TEXT DEFAULT 'active',
+    expires_at BIGINT
+);
+
+CREATE TABLE IF NOT EXISTS reversals (
+    original_req_id BIGINT PRIMARY KEY,
+    reversal_req_id BIGINT,
+    created_at TIMESTAMP DEFAULT now()
+);
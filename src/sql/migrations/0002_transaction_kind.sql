@@ -0,0 +1,2 @@
+-- Every row so far records a leg of a transfer
+ALTER TABLE transactions ADD COLUMN IF NOT EXISTS kind TEXT NOT NULL DEFAULT 'transfer';
@@ -0,0 +1,11 @@
+-- Records the opening balance of each account as its first ledger entry: the part of the
+-- balance its transfer rows don't account for. That part also takes up any drift from before
+-- now, so these rows get a kind of their own that VerifyLedger reports. A separate step from
+-- 0002 since CockroachDB can't use a column in the transaction that adds it.
+INSERT INTO transactions (transaction_index, req_id, account_id, amount, created_at, kind)
+SELECT 0, accounts.creation_request, accounts.id,
+    (accounts.balance - COALESCE(legs.total, 0))::INT8, accounts.created_at, 'opening_backfill'
+FROM accounts LEFT JOIN (
+    SELECT account_id, SUM(amount) AS total FROM transactions GROUP BY account_id
+) AS legs ON legs.account_id = accounts.id
+WHERE accounts.balance != COALESCE(legs.total, 0);
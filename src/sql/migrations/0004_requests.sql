@@ -0,0 +1,9 @@
+-- The CreateAccount and Transfer requests applied so far, written in the same transaction as
+-- their changes. A retry of one is answered with the stored reply, a different request under
+-- the same req_id is refused.
+CREATE TABLE IF NOT EXISTS requests (
+    req_id BIGINT PRIMARY KEY,
+    fingerprint BYTEA,
+    reply BYTEA,
+    created_at TIMESTAMP DEFAULT now()
+);
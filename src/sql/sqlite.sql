@@ -0,0 +1,48 @@
+-- The SqliteDataStore schema. It has the tables and columns of the Postgres migrations in
+-- migrations/, with these differences:
+--   timestamps are INTEGER milliseconds since the unix epoch, SQLite has no TIMESTAMP type
+--   transaction ids are AUTOINCREMENT so they only grow, like a BIGSERIAL
+--   kind is part of transactions from the start, where Postgres adds it in 0002
+--   transactions are indexed on req_id, which reversals look legs up by
+CREATE TABLE IF NOT EXISTS accounts (
+    id INTEGER PRIMARY KEY,
+    balance INTEGER,
+    created_at INTEGER,
+    creation_request INTEGER,
+    CONSTRAINT balance_check CHECK (balance >= 0)
+);
+
+CREATE TABLE IF NOT EXISTS transactions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    transaction_index INTEGER,
+    req_id INTEGER,
+    account_id INTEGER,
+    amount INTEGER,
+    created_at INTEGER,
+    kind TEXT DEFAULT 'transfer'
+);
+
+CREATE INDEX IF NOT EXISTS transactions_req_id ON transactions (req_id);
+
+CREATE TABLE IF NOT EXISTS holds (
+    id INTEGER PRIMARY KEY,
+    account_id INTEGER,
+    to_account_id INTEGER,
+    amount INTEGER,
+    captured INTEGER DEFAULT 0,
+    status TEXT DEFAULT 'active',
+    expires_at INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS reversals (
+    original_req_id INTEGER PRIMARY KEY,
+    reversal_req_id INTEGER,
+    created_at INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS requests (
+    req_id INTEGER PRIMARY KEY,
+    fingerprint BLOB,
+    reply BLOB,
+    created_at INTEGER
+);
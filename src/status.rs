@@ -0,0 +1,396 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use grpcio::{RpcStatus, RpcStatusCode};
+use protobuf::well_known_types::Any;
+use protobuf::Message;
+use serde_json::{json, Value};
+
+use crate::error::DataStoreError;
+use crate::error_status_code;
+use crate::proto::accounting::TransferComponent;
+use crate::proto::error_details::{BadRequest, BadRequest_FieldViolation, ErrorInfo, RetryInfo};
+use crate::proto::status::Status;
+use crate::transfer::AmountError;
+use crate::validation::{FieldViolation, ValidationError};
+
+// ErrorInfo.domain of every error the server sends
+pub const DOMAIN: &str = "accountant";
+
+const ERROR_INFO: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+const RETRY_INFO: &str = "type.googleapis.com/google.rpc.RetryInfo";
+const BAD_REQUEST: &str = "type.googleapis.com/google.rpc.BadRequest";
+
+// Machine readable details of a failed call, as the google.rpc ErrorInfo, RetryInfo and
+// BadRequest messages would carry them
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ErrorDetails {
+    // ErrorInfo.reason, e.g. INSUFFICIENT_FUNDS
+    pub reason: String,
+    // ErrorInfo.metadata, e.g. account_id, component_index and available_balance
+    pub metadata: BTreeMap<String, String>,
+    // RetryInfo.retry_delay, how long to wait before sending the request again
+    pub retry_delay: Option<Duration>,
+    // BadRequest.field_violations
+    pub field_violations: Vec<FieldViolation>,
+}
+
+impl ErrorDetails {
+    pub fn new(reason: &str) -> ErrorDetails {
+        ErrorDetails {
+            reason: reason.to_string(),
+            ..ErrorDetails::default()
+        }
+    }
+
+    pub fn with<V: ToString>(mut self, key: &str, value: V) -> ErrorDetails {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set<V: ToString>(&mut self, key: &str, value: V) {
+        self.metadata.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+
+    pub fn account_id(&self) -> Option<u32> {
+        self.get("account_id").and_then(|value| value.parse().ok())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == ErrorDetails::default()
+    }
+
+    // The details list of a google.rpc.Status in the proto3 JSON mapping
+    pub fn to_json(&self) -> Value {
+        let mut details = Vec::new();
+        if !self.reason.is_empty() {
+            details.push(json!({
+                "@type": ERROR_INFO,
+                "reason": self.reason,
+                "domain": DOMAIN,
+                "metadata": self.metadata,
+            }));
+        }
+        if let Some(delay) = self.retry_delay {
+            details.push(json!({"@type": RETRY_INFO, "retryDelay": format_duration(delay)}));
+        }
+        if !self.field_violations.is_empty() {
+            let violations: Vec<Value> = self
+                .field_violations
+                .iter()
+                .map(|v| json!({"field": v.field, "description": v.description}))
+                .collect();
+            details.push(json!({"@type": BAD_REQUEST, "fieldViolations": violations}));
+        }
+        Value::Array(details)
+    }
+
+    // The google.rpc.Status the server sends in the grpc-status-details-bin trailer
+    pub fn to_status(&self, code: RpcStatusCode, message: &str) -> Status {
+        let mut status = Status::new();
+        status.set_code(code.into());
+        status.set_message(message.to_string());
+        if !self.reason.is_empty() {
+            let mut info = ErrorInfo::new();
+            info.set_reason(self.reason.clone());
+            info.set_domain(DOMAIN.to_string());
+            info.set_metadata(self.metadata.clone().into_iter().collect());
+            status.mut_details().push(pack(&info));
+        }
+        if let Some(delay) = self.retry_delay {
+            let mut retry_delay = protobuf::well_known_types::Duration::new();
+            retry_delay.set_seconds(delay.as_secs() as i64);
+            retry_delay.set_nanos(delay.subsec_nanos() as i32);
+            let mut info = RetryInfo::new();
+            info.set_retry_delay(retry_delay);
+            status.mut_details().push(pack(&info));
+        }
+        if !self.field_violations.is_empty() {
+            let mut bad_request = BadRequest::new();
+            for v in &self.field_violations {
+                let mut violation = BadRequest_FieldViolation::new();
+                violation.set_field(v.field.clone());
+                violation.set_description(v.description.clone());
+                bad_request.mut_field_violations().push(violation);
+            }
+            status.mut_details().push(pack(&bad_request));
+        }
+        status
+    }
+
+    // Detail types other than the three above are skipped
+    pub fn from_status(status: &Status) -> ErrorDetails {
+        let mut details = ErrorDetails::default();
+        for detail in status.get_details() {
+            if let Ok(Some(info)) = detail.unpack::<ErrorInfo>() {
+                details.reason = info.get_reason().to_string();
+                details.metadata = info.get_metadata().clone().into_iter().collect();
+            } else if let Ok(Some(info)) = detail.unpack::<RetryInfo>() {
+                let delay = info.get_retry_delay();
+                details.retry_delay =
+                    Some(Duration::new(delay.get_seconds() as u64, delay.get_nanos() as u32));
+            } else if let Ok(Some(bad_request)) = detail.unpack::<BadRequest>() {
+                details.field_violations = bad_request
+                    .get_field_violations()
+                    .iter()
+                    .map(|v| FieldViolation {
+                        field: v.get_field().to_string(),
+                        description: v.get_description().to_string(),
+                    })
+                    .collect();
+            }
+        }
+        details
+    }
+
+    // Decodes the grpc-status-details-bin trailer, empty or unreadable details decode to none
+    pub fn decode(bytes: &[u8]) -> ErrorDetails {
+        Status::parse_from_bytes(bytes)
+            .map(|status| ErrorDetails::from_status(&status))
+            .unwrap_or_default()
+    }
+}
+
+fn pack<M: Message>(message: &M) -> Any {
+    Any::pack(message).expect("Failed To Encode Error Details")
+}
+
+// Name of a status code as `client` and ClientError print it, e.g. NotFound
+pub fn code_name(code: RpcStatusCode) -> &'static str {
+    match code {
+        RpcStatusCode::OK => "Ok",
+        RpcStatusCode::CANCELLED => "Cancelled",
+        RpcStatusCode::UNKNOWN => "Unknown",
+        RpcStatusCode::INVALID_ARGUMENT => "InvalidArgument",
+        RpcStatusCode::DEADLINE_EXCEEDED => "DeadlineExceeded",
+        RpcStatusCode::NOT_FOUND => "NotFound",
+        RpcStatusCode::ALREADY_EXISTS => "AlreadyExists",
+        RpcStatusCode::PERMISSION_DENIED => "PermissionDenied",
+        RpcStatusCode::RESOURCE_EXHAUSTED => "ResourceExhausted",
+        RpcStatusCode::FAILED_PRECONDITION => "FailedPrecondition",
+        RpcStatusCode::ABORTED => "Aborted",
+        RpcStatusCode::OUT_OF_RANGE => "OutOfRange",
+        RpcStatusCode::UNIMPLEMENTED => "Unimplemented",
+        RpcStatusCode::INTERNAL => "Internal",
+        RpcStatusCode::UNAVAILABLE => "Unavailable",
+        RpcStatusCode::DATA_LOSS => "DataLoss",
+        RpcStatusCode::UNAUTHENTICATED => "Unauthenticated",
+        _ => "Unknown",
+    }
+}
+
+// Durations are seconds with a fractional part and an "s" suffix, e.g. "0.500s"
+fn format_duration(duration: Duration) -> String {
+    format!("{}.{:03}s", duration.as_secs(), duration.subsec_millis())
+}
+
+// A failed call as the server reports it
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorStatus {
+    pub code: RpcStatusCode,
+    pub message: String,
+    pub details: ErrorDetails,
+}
+
+impl ErrorStatus {
+    pub fn new(code: RpcStatusCode, message: String, details: ErrorDetails) -> ErrorStatus {
+        ErrorStatus {
+            code,
+            message,
+            details,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.code == RpcStatusCode::ABORTED || self.code == RpcStatusCode::UNAVAILABLE
+    }
+
+    // Adds the index of the first component the error is about, matching money_delta too
+    // when the details name one
+    pub fn locate_component(&mut self, components: &[TransferComponent]) {
+        let account_id = match self.details.account_id() {
+            Some(account_id) => account_id,
+            None => return,
+        };
+        let delta = self.details.get_i64("money_delta");
+        let index = components.iter().position(|component| {
+            component.get_account_id() == account_id
+                && delta.map_or(true, |delta| component.get_money_delta() == delta)
+        });
+        if let Some(index) = index {
+            self.details.set("component_index", index);
+        }
+    }
+
+    // The details go in the grpc-status-details-bin trailer, clients that don't read it still
+    // get the message
+    pub fn to_rpc_status(&self) -> RpcStatus {
+        if self.details.is_empty() {
+            return RpcStatus::with_message(self.code, self.message.clone());
+        }
+        let status = self.details.to_status(self.code, &self.message);
+        let details = status.write_to_bytes().expect("Failed To Encode Error Details");
+        RpcStatus::with_details(self.code, self.message.clone(), details)
+    }
+
+    pub fn from_rpc_status(status: &RpcStatus) -> ErrorStatus {
+        let details = ErrorDetails::decode(status.details());
+        ErrorStatus::new(status.code(), status.message().to_string(), details)
+    }
+}
+
+impl From<DataStoreError> for ErrorStatus {
+    fn from(err: DataStoreError) -> ErrorStatus {
+        let details = match err {
+            DataStoreError::AccountExists(account) => {
+                ErrorDetails::new("ACCOUNT_EXISTS").with("account_id", account)
+            }
+            DataStoreError::AccountNotFound(account) => {
+                ErrorDetails::new("ACCOUNT_NOT_FOUND").with("account_id", account)
+            }
+            DataStoreError::InsufficientFunds(account) => {
+                ErrorDetails::new("INSUFFICIENT_FUNDS").with("account_id", account)
+            }
+            DataStoreError::BalanceOutOfRange(account) => {
+                ErrorDetails::new("BALANCE_OUT_OF_RANGE").with("account_id", account)
+            }
+            DataStoreError::InvalidBalance(account, balance) => ErrorDetails::new("INVALID_BALANCE")
+                .with("account_id", account)
+                .with("balance", balance),
+            DataStoreError::TransferNotFound(req_id) => {
+                ErrorDetails::new("TRANSFER_NOT_FOUND").with("req_id", req_id)
+            }
+            DataStoreError::AlreadyReversed(req_id) => {
+                ErrorDetails::new("ALREADY_REVERSED").with("req_id", req_id)
+            }
+            DataStoreError::HoldExists(hold) => {
+                ErrorDetails::new("HOLD_EXISTS").with("hold_id", hold)
+            }
+            DataStoreError::HoldNotFound(hold) => {
+                ErrorDetails::new("HOLD_NOT_FOUND").with("hold_id", hold)
+            }
+            DataStoreError::HoldNotActive(hold) => {
+                ErrorDetails::new("HOLD_NOT_ACTIVE").with("hold_id", hold)
+            }
+            DataStoreError::HoldExpired(hold) => {
+                ErrorDetails::new("HOLD_EXPIRED").with("hold_id", hold)
+            }
+            DataStoreError::CaptureExceedsHold(hold, amount) => {
+                ErrorDetails::new("CAPTURE_EXCEEDS_HOLD")
+                    .with("hold_id", hold)
+                    .with("amount", amount)
+            }
+            DataStoreError::RequestReplayed(_) => ErrorDetails::new("REQUEST_REPLAYED"),
+            DataStoreError::RequestIdReused(req_id) => {
+                ErrorDetails::new("REQUEST_ID_REUSED").with("req_id", req_id)
+            }
+            DataStoreError::SerializationConflict => ErrorDetails::new("SERIALIZATION_CONFLICT"),
+            DataStoreError::Contention(attempts) => {
+                ErrorDetails::new("CONTENTION").with("attempts", attempts)
+            }
+            DataStoreError::Unavailable(_) => ErrorDetails::new("DATA_STORE_UNAVAILABLE"),
+            DataStoreError::Internal(_) => ErrorDetails::new("INTERNAL"),
+        };
+        ErrorStatus::new(error_status_code(&err), err.to_string(), details)
+    }
+}
+
+impl From<ValidationError> for ErrorStatus {
+    fn from(err: ValidationError) -> ErrorStatus {
+        let details = match err {
+            ValidationError::Fields(ref violations) => ErrorDetails {
+                field_violations: violations.clone(),
+                ..ErrorDetails::new("INVALID_REQUEST")
+            },
+            ValidationError::Amount(AmountError::LegTooLarge(account, delta, limit)) => {
+                ErrorDetails::new("LEG_TOO_LARGE")
+                    .with("account_id", account)
+                    .with("money_delta", delta)
+                    .with("limit", limit)
+            }
+            ValidationError::Amount(AmountError::NetLegTooLarge(account, limit)) => {
+                ErrorDetails::new("NET_LEG_TOO_LARGE")
+                    .with("account_id", account)
+                    .with("limit", limit)
+            }
+            ValidationError::Amount(AmountError::NonZeroSum) => ErrorDetails::new("NON_ZERO_SUM"),
+            ValidationError::Amount(AmountError::BalanceTooLarge(account, balance, limit)) => {
+                ErrorDetails::new("BALANCE_TOO_LARGE")
+                    .with("account_id", account)
+                    .with("balance", balance)
+                    .with("limit", limit)
+            }
+        };
+        ErrorStatus::new(err.status_code(), err.to_string(), details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut status = ErrorStatus::from(DataStoreError::InsufficientFunds(3));
+        status.details.set("available_balance", 40);
+        status.details.retry_delay = Some(Duration::from_millis(1_500));
+        let rpc_status = status.to_rpc_status();
+        assert_eq!(rpc_status.code(), RpcStatusCode::FAILED_PRECONDITION);
+        assert_eq!(rpc_status.message(), "Insufficient Funds In Account 3");
+
+        let decoded = ErrorStatus::from_rpc_status(&rpc_status);
+        assert_eq!(decoded, status);
+        assert_eq!(decoded.details.reason, "INSUFFICIENT_FUNDS");
+        assert_eq!(decoded.details.account_id(), Some(3));
+        assert_eq!(decoded.details.get_i64("available_balance"), Some(40));
+
+        let status = ErrorStatus::from(ValidationError::Fields(vec![FieldViolation {
+            field: "req_id".to_string(),
+            description: "Must Not Be 0".to_string(),
+        }]));
+        let decoded = ErrorStatus::from_rpc_status(&status.to_rpc_status());
+        assert_eq!(decoded.details.field_violations, status.details.field_violations);
+
+        // statuses from servers that send no details
+        let plain = RpcStatus::with_message(RpcStatusCode::NOT_FOUND, "Account 5 Not Found".into());
+        let decoded = ErrorStatus::from_rpc_status(&plain);
+        assert_eq!(decoded.message, "Account 5 Not Found");
+        assert!(decoded.details.is_empty());
+        assert!(ErrorDetails::decode(b"not a status").is_empty());
+    }
+
+    #[test]
+    fn test_locate_component() {
+        let components: Vec<TransferComponent> = [(1, -5), (2, 3), (2, 7)]
+            .iter()
+            .map(|&(account_id, delta)| {
+                let mut component = TransferComponent::new();
+                component.set_account_id(account_id);
+                component.set_money_delta(delta);
+                component
+            })
+            .collect();
+        let mut status = ErrorStatus::from(DataStoreError::AccountNotFound(2));
+        status.locate_component(&components);
+        assert_eq!(status.details.get("component_index"), Some("1"));
+
+        let err = ValidationError::from(AmountError::LegTooLarge(2, 7, 5));
+        let mut status = ErrorStatus::from(err);
+        status.locate_component(&components);
+        assert_eq!(status.details.get("component_index"), Some("2"));
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(format_duration(Duration::from_millis(500)), "0.500s");
+        assert_eq!(format_duration(Duration::from_secs(3)), "3.000s");
+    }
+}
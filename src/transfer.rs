@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use grpcio::RpcStatusCode;
+
+use crate::proto::accounting::TransferComponent;
+
+const NO_LIMIT: i64 = i64::MAX;
+
+// Bounds on the amounts a request may move and the balances it may leave behind
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AmountLimits {
+    // largest money_delta of a component, and of an account's net leg, in either direction
+    pub max_leg_amount: i64,
+    pub max_balance: i64,
+}
+
+impl Default for AmountLimits {
+    fn default() -> AmountLimits {
+        AmountLimits {
+            max_leg_amount: NO_LIMIT,
+            max_balance: NO_LIMIT,
+        }
+    }
+}
+
+impl AmountLimits {
+    // Rejects components and net legs beyond max_leg_amount with INVALID_ARGUMENT, then a
+    // non-zero sum with FAILED_PRECONDITION. Sums are taken as i128, which no request can
+    // overflow, so huge deltas cannot wrap around to zero.
+    pub fn check_transfer(&self, components: &[TransferComponent]) -> Result<(), AmountError> {
+        let mut total: i128 = 0;
+        let mut net: BTreeMap<u32, i128> = BTreeMap::new();
+        for component in components {
+            let (account_id, delta) = (component.get_account_id(), component.get_money_delta());
+            self.check_leg(account_id, delta)?;
+            total += i128::from(delta);
+            *net.entry(account_id).or_insert(0) += i128::from(delta);
+        }
+        for (&account_id, &delta) in &net {
+            if delta.abs() > i128::from(self.max_leg_amount) {
+                return Err(AmountError::NetLegTooLarge(account_id, self.max_leg_amount));
+            }
+        }
+        if total != 0 {
+            return Err(AmountError::NonZeroSum);
+        }
+        Ok(())
+    }
+
+    pub fn check_balance(&self, account_id: u32, balance: i64) -> Result<(), AmountError> {
+        if balance > self.max_balance {
+            return Err(AmountError::BalanceTooLarge(account_id, balance, self.max_balance));
+        }
+        Ok(())
+    }
+
+    pub fn check_leg(&self, account_id: u32, delta: i64) -> Result<(), AmountError> {
+        // i64::MIN has no absolute value
+        match delta.checked_abs() {
+            Some(amount) if amount <= self.max_leg_amount => Ok(()),
+            _ => Err(AmountError::LegTooLarge(account_id, delta, self.max_leg_amount)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmountError {
+    // (account, money_delta, limit)
+    LegTooLarge(u32, i64, i64),
+    // the components for one account add up to more than the limit, (account, limit)
+    NetLegTooLarge(u32, i64),
+    NonZeroSum,
+    // (account, balance, limit)
+    BalanceTooLarge(u32, i64, i64),
+}
+
+impl AmountError {
+    pub fn status_code(&self) -> RpcStatusCode {
+        match self {
+            AmountError::LegTooLarge(_, _, _) | AmountError::NetLegTooLarge(_, _) => {
+                RpcStatusCode::INVALID_ARGUMENT
+            }
+            AmountError::NonZeroSum => RpcStatusCode::FAILED_PRECONDITION,
+            AmountError::BalanceTooLarge(_, _, _) => RpcStatusCode::OUT_OF_RANGE,
+        }
+    }
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AmountError::LegTooLarge(account, delta, limit) => write!(
+                f,
+                "Money Delta {} For Account {} Exceeds The Limit Of {}",
+                delta, account, limit
+            ),
+            AmountError::NetLegTooLarge(account, limit) => write!(
+                f,
+                "Money Deltas For Account {} Add Up To More Than The Limit Of {}",
+                account, limit
+            ),
+            AmountError::NonZeroSum => write!(f, "Sum of All Money Deltas Must Be Zero"),
+            AmountError::BalanceTooLarge(account, balance, limit) => write!(
+                f,
+                "Balance {} For Account {} Exceeds The Limit Of {}",
+                balance, account, limit
+            ),
+        }
+    }
+}
+
+// Net change to one account's balance
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransferLeg {
+    pub account_id: u32,
+    pub delta: i64,
+}
+
+// A component as the client sent it, recorded in the transactions table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JournalEntry {
+    // position of the component in the request
+    pub transaction_index: u32,
+    pub account_id: u32,
+    pub amount: i64,
+}
+
+// A transfer reduced to one leg per account in account id order, so concurrent transfers
+// touch rows in the same order no matter how their clients listed the components
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransferPlan {
+    legs: Vec<TransferLeg>,
+    entries: Vec<JournalEntry>,
+    unchanged: Vec<u32>,
+}
+
+impl TransferPlan {
+    // Zero-delta components are dropped, and so are legs that merge to zero, whose accounts
+    // are only checked to exist. Merging saturates, AmountLimits::check_transfer rejects such
+    // legs.
+    pub fn new(components: &[TransferComponent]) -> TransferPlan {
+        let mut merged: BTreeMap<u32, i64> = BTreeMap::new();
+        let mut entries = Vec::with_capacity(components.len());
+        for (i, component) in components.iter().enumerate() {
+            let (account_id, amount) = (component.get_account_id(), component.get_money_delta());
+            if amount == 0 {
+                continue;
+            }
+            let delta = merged.entry(account_id).or_insert(0);
+            *delta = delta.saturating_add(amount);
+            entries.push(JournalEntry {
+                transaction_index: i as u32,
+                account_id,
+                amount,
+            });
+        }
+        let (legs, unchanged): (Vec<TransferLeg>, Vec<TransferLeg>) = merged
+            .into_iter()
+            .map(|(account_id, delta)| TransferLeg { account_id, delta })
+            .partition(|leg| leg.delta != 0);
+        TransferPlan {
+            legs,
+            entries,
+            unchanged: unchanged.iter().map(|leg| leg.account_id).collect(),
+        }
+    }
+
+    // One leg per account, ordered by account id
+    pub fn legs(&self) -> &[TransferLeg] {
+        &self.legs
+    }
+
+    // The non-zero components with their index in the original request
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    // Accounts whose components cancel out, in account id order. Their balances don't
+    // change but their entries are recorded, so they must exist.
+    pub fn unchanged(&self) -> &[u32] {
+        &self.unchanged
+    }
+
+    pub fn accounts(&self) -> Vec<u32> {
+        self.legs.iter().map(|leg| leg.account_id).collect()
+    }
+
+    // Accounts whose balance goes down
+    pub fn debited(&self) -> Vec<u32> {
+        self.legs.iter().filter(|leg| leg.delta < 0).map(|leg| leg.account_id).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn components(transfers: &[(u32, i64)]) -> Vec<TransferComponent> {
+        transfers
+            .iter()
+            .map(|&(account_id, amount)| {
+                let mut comp = TransferComponent::new();
+                comp.set_account_id(account_id);
+                comp.set_money_delta(amount);
+                comp
+            })
+            .collect()
+    }
+
+    fn plan(transfers: &[(u32, i64)]) -> TransferPlan {
+        TransferPlan::new(&components(transfers))
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        let plan = plan(&[(3, 10), (1, -25), (2, 0), (3, 5), (2, 10), (4, 5), (4, -5)]);
+        let legs: Vec<(u32, i64)> = plan.legs().iter().map(|l| (l.account_id, l.delta)).collect();
+        assert_eq!(legs, vec![(1, -25), (2, 10), (3, 15)]);
+        assert_eq!(plan.debited(), vec![1]);
+        assert_eq!(plan.unchanged(), &[4]);
+
+        // the zero-delta component at index 2 is not recorded
+        let indexes: Vec<u32> = plan.entries().iter().map(|e| e.transaction_index).collect();
+        assert_eq!(indexes, vec![0, 1, 3, 4, 5, 6]);
+        let entry = JournalEntry { transaction_index: 3, account_id: 3, amount: 5 };
+        assert_eq!(plan.entries()[2], entry);
+    }
+
+    #[test]
+    fn test_same_plan_for_any_order() {
+        assert_eq!(plan(&[(1, -5), (2, 5)]).legs(), plan(&[(2, 5), (1, -5)]).legs());
+        assert!(plan(&[(1, 0)]).legs().is_empty());
+        assert!(plan(&[(1, 0)]).unchanged().is_empty());
+    }
+
+    #[test]
+    fn test_check_transfer() {
+        let max = AmountLimits::default().max_leg_amount;
+        let limits = AmountLimits { max_leg_amount: 1_000, max_balance: 5_000 };
+        assert_eq!(limits.check_transfer(&components(&[(1, -1_000), (2, 1_000)])), Ok(()));
+        assert_eq!(
+            limits.check_transfer(&components(&[(1, -1_001), (2, 1_001)])),
+            Err(AmountError::LegTooLarge(1, -1_001, 1_000))
+        );
+        // each component is in range but the net leg of account 2 is not
+        assert_eq!(
+            limits.check_transfer(&components(&[(1, -1_000), (2, 600), (3, -200), (2, 600)])),
+            Err(AmountError::NetLegTooLarge(2, 1_000))
+        );
+        assert_eq!(
+            limits.check_transfer(&components(&[(1, -5), (2, 4)])),
+            Err(AmountError::NonZeroSum)
+        );
+
+        // wraps to zero with plain addition
+        let unlimited = AmountLimits::default();
+        let overflow = components(&[(1, max), (2, max), (3, 2)]);
+        assert_eq!(unlimited.check_transfer(&overflow), Err(AmountError::NonZeroSum));
+        let overflow = components(&[(1, max), (1, max), (2, -max), (3, -max)]);
+        assert_eq!(unlimited.check_transfer(&overflow), Err(AmountError::NetLegTooLarge(1, max)));
+        let min = components(&[(1, -max - 1), (2, max), (3, 1)]);
+        assert_eq!(unlimited.check_transfer(&min), Err(AmountError::LegTooLarge(1, -max - 1, max)));
+
+        assert_eq!(limits.check_balance(1, 5_000), Ok(()));
+        let err = limits.check_balance(1, 5_001).unwrap_err();
+        assert_eq!(err.status_code(), RpcStatusCode::OUT_OF_RANGE);
+        assert_eq!(err.to_string(), "Balance 5001 For Account 1 Exceeds The Limit Of 5000");
+    }
+
+    // i64 extremes and values around them, where unchecked arithmetic goes wrong
+    fn extreme() -> impl Strategy<Value = i64> {
+        prop_oneof![
+            Just(i64::MAX),
+            Just(i64::MIN),
+            Just(i64::MAX / 2 + 1),
+            -1_000i64..1_000,
+            proptest::num::i64::ANY,
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn prop_check_transfer_matches_exact_sum(
+            legs in proptest::collection::vec((0u32..4, extreme()), 0..8),
+            max_leg_amount in prop_oneof![Just(i64::MAX), 1i64..1_000_000],
+        ) {
+            let limits = AmountLimits { max_leg_amount, max_balance: 1 };
+            let res = limits.check_transfer(&components(&legs));
+
+            // the sum and the net legs taken without overflow
+            let total: i128 = legs.iter().map(|&(_, delta)| i128::from(delta)).sum();
+            let mut net: BTreeMap<u32, i128> = BTreeMap::new();
+            for &(account, delta) in &legs {
+                *net.entry(account).or_insert(0) += i128::from(delta);
+            }
+            let max = i128::from(max_leg_amount);
+            let in_range = legs.iter().all(|&(_, delta)| i128::from(delta).abs() <= max)
+                && net.values().all(|delta| delta.abs() <= max);
+
+            prop_assert_eq!(res.is_ok(), in_range && total == 0);
+            if in_range && total != 0 {
+                prop_assert_eq!(res, Err(AmountError::NonZeroSum));
+            }
+        }
+
+        #[test]
+        fn prop_check_balance(balance in extreme(), max_balance in 1i64..i64::MAX) {
+            let limits = AmountLimits { max_leg_amount: 1, max_balance };
+            prop_assert_eq!(limits.check_balance(7, balance).is_ok(), balance <= max_balance);
+        }
+    }
+}
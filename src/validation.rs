@@ -0,0 +1,312 @@
+use std::fmt;
+
+use grpcio::RpcStatusCode;
+
+use crate::proto::accounting::{
+    AuthorizeTransferRequest, CaptureTransferRequest, CreateAccountRequest, GetBalanceRequest,
+    ListTransactionsRequest, ReverseTransferRequest, TransferRequest, VoidTransferRequest,
+    WatchBalanceRequest,
+};
+use crate::transfer::{AmountError, AmountLimits};
+
+// Account ids no request may use, 0 is what an unset account_id decodes to
+pub const RESERVED_ACCOUNTS: &[u32] = &[0];
+
+// Per-request limits checked before a request reaches the data store
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequestLimits {
+    // components of one transfer
+    pub max_components: usize,
+    // account ids or transfer ids in one ListTransactions or WatchBalance request
+    pub max_ids: usize,
+    pub amounts: AmountLimits,
+}
+
+impl Default for RequestLimits {
+    fn default() -> RequestLimits {
+        RequestLimits {
+            max_components: 1_000,
+            max_ids: 1_000,
+            amounts: AmountLimits::default(),
+        }
+    }
+}
+
+// One malformed field, e.g. ("components[2].account_id", "Account 0 Is Reserved")
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldViolation {
+    pub field: String,
+    pub description: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    // every malformed field of the request, INVALID_ARGUMENT
+    Fields(Vec<FieldViolation>),
+    // well formed amounts that are over a limit or don't add up
+    Amount(AmountError),
+}
+
+impl ValidationError {
+    pub fn status_code(&self) -> RpcStatusCode {
+        match self {
+            ValidationError::Fields(_) => RpcStatusCode::INVALID_ARGUMENT,
+            ValidationError::Amount(err) => err.status_code(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::Fields(violations) => {
+                let fields: Vec<String> = violations
+                    .iter()
+                    .map(|v| format!("{}: {}", v.field, v.description))
+                    .collect();
+                write!(f, "Invalid Request: {}", fields.join("; "))
+            }
+            ValidationError::Amount(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<AmountError> for ValidationError {
+    fn from(err: AmountError) -> ValidationError {
+        ValidationError::Amount(err)
+    }
+}
+
+pub type Validation = Result<(), ValidationError>;
+
+// Collects violations so a request reports every bad field at once
+#[derive(Default)]
+struct Violations(Vec<FieldViolation>);
+
+impl Violations {
+    fn add<F: Into<String>, D: Into<String>>(&mut self, field: F, description: D) {
+        self.0.push(FieldViolation {
+            field: field.into(),
+            description: description.into(),
+        });
+    }
+
+    // req_ids key the idempotency cache, so 0 would make unrelated requests collide
+    fn req_id(&mut self, field: &str, req_id: u64) {
+        if req_id == 0 {
+            self.add(field, "Must Not Be 0");
+        }
+    }
+
+    fn account<F: Into<String>>(&mut self, field: F, account: u32) {
+        if RESERVED_ACCOUNTS.contains(&account) {
+            self.add(field, format!("Account {} Is Reserved", account));
+        }
+    }
+
+    fn ids(&mut self, field: &str, count: usize, max: usize) {
+        if count > max {
+            self.add(field, format!("At Most {} Ids Are Allowed, Got {}", max, count));
+        }
+    }
+
+    fn finish(self) -> Validation {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::Fields(self.0))
+        }
+    }
+}
+
+impl RequestLimits {
+    pub fn create_account(&self, req: &CreateAccountRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.account("account_id", req.get_account_id());
+        if req.get_balance() < 0 {
+            violations.add("balance", "Must Not Be Negative");
+        }
+        violations.finish()?;
+        Ok(self.amounts.check_balance(req.get_account_id(), req.get_balance())?)
+    }
+
+    pub fn get_balance(&self, req: &GetBalanceRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.account("account_id", req.get_account_id());
+        violations.finish()
+    }
+
+    pub fn transfer(&self, req: &TransferRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        let components = req.get_components();
+        if components.is_empty() {
+            violations.add("components", "Must Not Be Empty");
+        } else if components.len() > self.max_components {
+            let description = format!(
+                "At Most {} Components Are Allowed, Got {}",
+                self.max_components,
+                components.len()
+            );
+            violations.add("components", description);
+        } else {
+            for (i, component) in components.iter().enumerate() {
+                let field = format!("components[{}].account_id", i);
+                violations.account(field, component.get_account_id());
+            }
+        }
+        violations.finish()?;
+        Ok(self.amounts.check_transfer(components)?)
+    }
+
+    pub fn list_transactions(&self, req: &ListTransactionsRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.ids("account_ids", req.get_account_ids().len(), self.max_ids);
+        violations.ids("transfer_req_ids", req.get_transfer_req_ids().len(), self.max_ids);
+        let token = req.get_page_token();
+        if !token.is_empty() && token.parse::<u64>().is_err() {
+            violations.add("page_token", "Invalid Page Token");
+        }
+        let (start, end) = (req.get_start_time(), req.get_end_time());
+        if start != 0 && end != 0 && end <= start {
+            violations.add("end_time", "Must Be After start_time");
+        }
+        violations.finish()
+    }
+
+    pub fn watch_balance(&self, req: &WatchBalanceRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        let accounts = req.get_account_ids();
+        if accounts.is_empty() {
+            violations.add("account_ids", "At Least One Account Id Is Required");
+        }
+        violations.ids("account_ids", accounts.len(), self.max_ids);
+        for (i, &account) in accounts.iter().enumerate().take(self.max_ids) {
+            violations.account(format!("account_ids[{}]", i), account);
+        }
+        violations.finish()
+    }
+
+    pub fn authorize_transfer(&self, req: &AuthorizeTransferRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.account("from_account_id", req.get_from_account_id());
+        violations.account("to_account_id", req.get_to_account_id());
+        if req.get_from_account_id() == req.get_to_account_id() {
+            violations.add("to_account_id", "Must Differ From from_account_id");
+        }
+        if req.get_amount() <= 0 {
+            violations.add("amount", "Must Be Positive");
+        }
+        violations.finish()?;
+        Ok(self.amounts.check_leg(req.get_from_account_id(), req.get_amount())?)
+    }
+
+    pub fn capture_transfer(&self, req: &CaptureTransferRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.req_id("hold_id", req.get_hold_id());
+        if req.get_amount() < 0 {
+            violations.add("amount", "Must Not Be Negative");
+        }
+        violations.finish()
+    }
+
+    pub fn void_transfer(&self, req: &VoidTransferRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.req_id("hold_id", req.get_hold_id());
+        violations.finish()
+    }
+
+    pub fn reverse_transfer(&self, req: &ReverseTransferRequest) -> Validation {
+        let mut violations = Violations::default();
+        violations.req_id("req_id", req.get_req_id());
+        violations.req_id("original_req_id", req.get_original_req_id());
+        if req.get_req_id() == req.get_original_req_id() {
+            violations.add("original_req_id", "A Reversal Needs Its Own Request Id");
+        }
+        violations.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::accounting::TransferComponent;
+
+    fn fields(res: Validation) -> Vec<String> {
+        match res {
+            Err(ValidationError::Fields(violations)) => {
+                violations.into_iter().map(|v| v.field).collect()
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    fn transfer(req_id: u64, legs: &[(u32, i64)]) -> TransferRequest {
+        let mut req = TransferRequest::new();
+        req.set_req_id(req_id);
+        for &(account, delta) in legs {
+            let mut comp = TransferComponent::new();
+            comp.set_account_id(account);
+            comp.set_money_delta(delta);
+            req.mut_components().push(comp);
+        }
+        req
+    }
+
+    #[test]
+    fn test_transfer() {
+        let limits = RequestLimits {
+            max_components: 3,
+            ..RequestLimits::default()
+        };
+        assert_eq!(limits.transfer(&transfer(1, &[(1, -5), (2, 5)])), Ok(()));
+        assert_eq!(fields(limits.transfer(&transfer(0, &[]))), vec!["req_id", "components"]);
+        assert_eq!(
+            fields(limits.transfer(&transfer(1, &[(1, -5), (0, 5)]))),
+            vec!["components[1].account_id"]
+        );
+        assert_eq!(
+            fields(limits.transfer(&transfer(1, &[(1, -3), (2, 1), (3, 1), (4, 1)]))),
+            vec!["components"]
+        );
+
+        // fields are checked before amounts
+        let err = limits.transfer(&transfer(1, &[(1, -5), (2, 4)])).unwrap_err();
+        assert_eq!(err, ValidationError::Amount(AmountError::NonZeroSum));
+        assert_eq!(err.status_code(), RpcStatusCode::FAILED_PRECONDITION);
+    }
+
+    #[test]
+    fn test_every_violation_is_reported() {
+        let limits = RequestLimits::default();
+        let mut req = CreateAccountRequest::new();
+        req.set_balance(-1);
+        let err = limits.create_account(&req).unwrap_err();
+        assert_eq!(err.status_code(), RpcStatusCode::INVALID_ARGUMENT);
+        assert_eq!(
+            err.to_string(),
+            "Invalid Request: req_id: Must Not Be 0; account_id: Account 0 Is Reserved; \
+             balance: Must Not Be Negative"
+        );
+
+        let mut req = AuthorizeTransferRequest::new();
+        req.set_req_id(1);
+        req.set_from_account_id(2);
+        req.set_to_account_id(2);
+        assert_eq!(fields(limits.authorize_transfer(&req)), vec!["to_account_id", "amount"]);
+
+        let mut req = ListTransactionsRequest::new();
+        req.set_req_id(1);
+        req.set_page_token("next".to_string());
+        req.set_start_time(10);
+        req.set_end_time(5);
+        assert_eq!(fields(limits.list_transactions(&req)), vec!["page_token", "end_time"]);
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+
+use crate::proto::accounting::GetBalanceResponse;
+use crate::{balance_response, AccountBalance};
+
+// A WatchBalance stream's interest in one account
+struct Watcher {
+    req_id: u64,
+    sender: UnboundedSender<GetBalanceResponse>,
+    // sequence number of the last balance sent, so an older read that finishes later is dropped
+    sent: u64,
+}
+
+#[derive(Default)]
+struct WatchState {
+    next_id: u64,
+    // handed to each publish of an account before it reads the balance
+    sequence: u64,
+    // account id -> watcher id -> watcher
+    watchers: HashMap<u32, HashMap<u64, Watcher>>,
+}
+
+impl WatchState {
+    fn remove(&mut self, id: u64, accounts: &[u32]) {
+        for account in accounts {
+            let empty = match self.watchers.get_mut(account) {
+                Some(watchers) => {
+                    watchers.remove(&id);
+                    watchers.is_empty()
+                }
+                None => false,
+            };
+            if empty {
+                self.watchers.remove(account);
+            }
+        }
+    }
+}
+
+// Fans committed balances out to the WatchBalance streams interested in them
+#[derive(Clone, Default)]
+pub struct BalanceWatchers {
+    state: Arc<Mutex<WatchState>>,
+}
+
+impl BalanceWatchers {
+    pub fn new() -> BalanceWatchers {
+        BalanceWatchers::default()
+    }
+
+    pub fn subscribe(&self, req_id: u64, accounts: &[u32]) -> BalanceWatch {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut accounts = accounts.to_vec();
+        accounts.sort();
+        accounts.dedup();
+
+        let mut state = self.lock();
+        let id = state.next_id;
+        state.next_id += 1;
+        // balances read before this may be older than the snapshot the stream starts with
+        let sent = state.sequence;
+        for &account in &accounts {
+            let watcher = Watcher { req_id, sender: sender.clone(), sent };
+            state.watchers.entry(account).or_default().insert(id, watcher);
+        }
+        BalanceWatch {
+            id,
+            accounts,
+            receiver,
+            watchers: self.clone(),
+        }
+    }
+
+    pub fn is_watched(&self, account: u32) -> bool {
+        self.lock().watchers.contains_key(&account)
+    }
+
+    // Runs `read` for every watched account and sends each balance it returns. `read` runs
+    // without the lock, so concurrent publishers can finish in any order; each balance
+    // carries a sequence number taken before it was read, and a watcher only gets balances
+    // newer than the last one it was sent.
+    pub fn publish<F>(&self, accounts: &[u32], mut read: F)
+    where
+        F: FnMut(u32) -> Option<AccountBalance>,
+    {
+        let mut reads = Vec::new();
+        {
+            let mut state = self.lock();
+            for &account in accounts {
+                if state.watchers.contains_key(&account) {
+                    state.sequence += 1;
+                    reads.push((account, state.sequence));
+                }
+            }
+        }
+
+        for (account, sequence) in reads {
+            let balance = match read(account) {
+                Some(balance) => balance,
+                None => continue,
+            };
+            let mut state = self.lock();
+            let empty = match state.watchers.get_mut(&account) {
+                Some(watchers) => {
+                    // a closed stream refuses the send and is dropped
+                    watchers.retain(|_, watcher| {
+                        if watcher.sent >= sequence {
+                            return true;
+                        }
+                        watcher.sent = sequence;
+                        let reply = balance_response(watcher.req_id, account, balance);
+                        watcher.sender.unbounded_send(reply).is_ok()
+                    });
+                    watchers.is_empty()
+                }
+                None => false,
+            };
+            if empty {
+                state.watchers.remove(&account);
+            }
+        }
+    }
+
+    pub fn clear(&self) {
+        // dropping the senders ends every open stream
+        self.lock().watchers.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, WatchState> {
+        self.state.lock().unwrap()
+    }
+}
+
+// Stream of balance events for one watcher, unsubscribed when dropped
+pub struct BalanceWatch {
+    id: u64,
+    accounts: Vec<u32>,
+    receiver: UnboundedReceiver<GetBalanceResponse>,
+    watchers: BalanceWatchers,
+}
+
+impl Stream for BalanceWatch {
+    type Item = GetBalanceResponse;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<GetBalanceResponse>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for BalanceWatch {
+    fn drop(&mut self) {
+        self.watchers.lock().remove(self.id, &self.accounts);
+    }
+}
+
+// Sends a snapshot and then every update of a watch to a client's sink, ending with either.
+// Every poll also flushes the sink, which watches the call itself: it fails
+// once the client has cancelled or gone away, which fails this future and drops the watch.
+pub struct WatchForward<S> {
+    sink: S,
+    watch: BalanceWatch,
+    pending: VecDeque<GetBalanceResponse>,
+}
+
+impl<S> WatchForward<S>
+where
+    S: Sink<GetBalanceResponse> + Unpin,
+{
+    pub fn new(sink: S, snapshot: Vec<GetBalanceResponse>, watch: BalanceWatch) -> Self {
+        WatchForward {
+            sink,
+            watch,
+            pending: snapshot.into(),
+        }
+    }
+}
+
+impl<S> Future for WatchForward<S>
+where
+    S: Sink<GetBalanceResponse> + Unpin,
+{
+    type Output = Result<(), S::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), S::Error>> {
+        let this = &mut *self;
+        loop {
+            if !this.pending.is_empty() {
+                // the flush below wakes the task once the sink has room
+                if this.sink.poll_ready_unpin(cx)?.is_pending() {
+                    break;
+                }
+                let reply = this.pending.pop_front().unwrap();
+                this.sink.start_send_unpin(reply)?;
+                continue;
+            }
+            match this.watch.poll_next_unpin(cx) {
+                Poll::Ready(Some(reply)) => this.pending.push_back(reply),
+                // cleared on reset or shutdown
+                Poll::Ready(None) => return this.sink.poll_close_unpin(cx),
+                Poll::Pending => break,
+            }
+        }
+        if let Poll::Ready(Err(err)) = this.sink.poll_flush_unpin(cx) {
+            return Poll::Ready(Err(err));
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor;
+    use futures::task::noop_waker_ref;
+    use futures::FutureExt;
+
+    // Records what is sent until it is closed like a cancelled call, which grpc's sinks
+    // report to the next poll_ready or poll_flush
+    #[derive(Clone, Default)]
+    struct ClientSink {
+        sent: Arc<Mutex<Vec<(u32, i64)>>>,
+        closed: Arc<Mutex<bool>>,
+    }
+
+    impl ClientSink {
+        fn poll_open(&self) -> Poll<Result<(), ()>> {
+            if *self.closed.lock().unwrap() {
+                return Poll::Ready(Err(()));
+            }
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl Sink<GetBalanceResponse> for ClientSink {
+        type Error = ();
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context) -> Poll<Result<(), ()>> {
+            self.poll_open()
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: GetBalanceResponse) -> Result<(), ()> {
+            self.sent.lock().unwrap().push((item.get_account_id(), item.get_balance()));
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<Result<(), ()>> {
+            self.poll_open()
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context) -> Poll<Result<(), ()>> {
+            self.poll_open()
+        }
+    }
+
+    fn next(watch: &mut BalanceWatch) -> (u64, u32, i64) {
+        let event = executor::block_on(watch.next()).unwrap();
+        (event.get_req_id(), event.get_account_id(), event.get_balance())
+    }
+
+    #[test]
+    fn test_publish() {
+        let watchers = BalanceWatchers::new();
+        let mut first = watchers.subscribe(7, &[1, 2]);
+        let mut second = watchers.subscribe(8, &[2]);
+
+        watchers.publish(&[1, 2, 3], |account| {
+            let ledger = i64::from(account) * 10;
+            Some(AccountBalance { ledger, available: ledger })
+        });
+        assert_eq!(next(&mut first), (7, 1, 10));
+        assert_eq!(next(&mut first), (7, 2, 20));
+        assert_eq!(next(&mut second), (8, 2, 20));
+        assert!(!watchers.is_watched(3));
+    }
+
+    #[test]
+    fn test_publish_drops_older_reads() {
+        let watchers = BalanceWatchers::new();
+        let mut watch = watchers.subscribe(7, &[1]);
+
+        // a second publisher reads and sends while the first is still reading
+        watchers.publish(&[1], |_| {
+            watchers.publish(&[1], |_| Some(AccountBalance { ledger: 2, available: 2 }));
+            Some(AccountBalance { ledger: 1, available: 1 })
+        });
+        watchers.publish(&[1], |_| Some(AccountBalance { ledger: 3, available: 3 }));
+        assert_eq!(next(&mut watch), (7, 1, 2));
+        assert_eq!(next(&mut watch), (7, 1, 3));
+    }
+
+    #[test]
+    fn test_forward() {
+        let watchers = BalanceWatchers::new();
+        let sink = ClientSink::default();
+        let watch = watchers.subscribe(7, &[1]);
+        let snapshot = vec![balance_response(7, 1, AccountBalance { ledger: 5, available: 5 })];
+        let mut forward = WatchForward::new(sink.clone(), snapshot, watch);
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        assert_eq!(forward.poll_unpin(&mut cx), Poll::Pending);
+        watchers.publish(&[1], |_| Some(AccountBalance { ledger: 9, available: 9 }));
+        assert_eq!(forward.poll_unpin(&mut cx), Poll::Pending);
+        assert_eq!(*sink.sent.lock().unwrap(), vec![(1, 5), (1, 9)]);
+
+        // a wake with nothing new sends nothing
+        assert_eq!(forward.poll_unpin(&mut cx), Poll::Pending);
+        assert_eq!(*sink.sent.lock().unwrap(), vec![(1, 5), (1, 9)]);
+
+        // a cancelled call fails the forward without waiting for the next publish
+        *sink.closed.lock().unwrap() = true;
+        assert_eq!(forward.poll_unpin(&mut cx), Poll::Ready(Err(())));
+        drop(forward);
+        assert!(!watchers.is_watched(1));
+        assert_eq!(*sink.sent.lock().unwrap(), vec![(1, 5), (1, 9)]);
+    }
+
+    #[test]
+    fn test_unsubscribe_on_drop() {
+        let watchers = BalanceWatchers::new();
+        let watch = watchers.subscribe(1, &[1]);
+        assert!(watchers.is_watched(1));
+        drop(watch);
+        assert!(!watchers.is_watched(1));
+    }
+}